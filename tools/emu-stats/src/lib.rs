@@ -0,0 +1,110 @@
+//! Parsers of the `ziskemu -X` and `cargo-zisk stats` output shared by the host tools.
+//!
+//! Lines are split into tokens on everything but alphanumerics and underscores, so that
+//! `steps=1234` and `steps: 1234` read alike. A profiled region is reported on a line that names
+//! its tag as a whole token and carries its steps as the token right after `steps`. An opcode of
+//! the emulator statistics, and an AIR of the instance statistics, is reported on a line that
+//! names it as a whole token followed by its count.
+
+/// Tokens of an emulator output line, split on everything but alphanumerics and underscores
+pub fn tokens(line: &str) -> Vec<&str> {
+    line.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// The number right after the first `name` token
+pub fn number_after(tokens: &[&str], name: &str) -> Option<u64> {
+    let pos = tokens.iter().position(|t| t.eq_ignore_ascii_case(name))?;
+    tokens.get(pos + 1)?.parse().ok()
+}
+
+/// The number right after the first `steps` token
+pub fn steps_after(tokens: &[&str]) -> Option<u64> {
+    number_after(tokens, "steps")
+}
+
+/// Steps of the profile report of `tag` on `line`, if it is one
+pub fn profile_steps(line: &str, tag: &str) -> Option<u64> {
+    let tokens = tokens(line);
+    if !tokens.contains(&tag) {
+        return None;
+    }
+    steps_after(&tokens)
+}
+
+/// Total steps of a run: the number right after `steps` on the first line that has one
+pub fn total_steps(output: &str) -> Option<u64> {
+    output.lines().find_map(|line| steps_after(&tokens(line)))
+}
+
+/// Calls of `opcode` in the emulator statistics: the first number of the line that starts with it
+pub fn opcode_calls(output: &str, opcode: &str) -> Option<u64> {
+    output.lines().find_map(|line| {
+        let tokens = tokens(line);
+        if tokens.first() != Some(&opcode) {
+            return None;
+        }
+        tokens[1..].iter().find_map(|t| t.parse().ok())
+    })
+}
+
+/// Instances of `air` in the instance statistics: the first number after its name, on the first
+/// line that names it
+pub fn air_instances(output: &str, air: &str) -> Option<u64> {
+    output.lines().find_map(|line| {
+        let tokens = tokens(line);
+        let pos = tokens.iter().position(|t| *t == air)?;
+        tokens[pos + 1..].iter().find_map(|t| t.parse().ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_steps_reads_the_number_after_steps() {
+        assert_eq!(profile_steps("checked_add steps=412", "checked_add"), Some(412));
+        assert_eq!(profile_steps("  checked_add: steps: 412 calls: 3", "checked_add"), Some(412));
+        assert_eq!(profile_steps("PROFILE ecrecover steps 98765", "ecrecover"), Some(98765));
+    }
+
+    #[test]
+    fn profile_steps_needs_the_whole_tag() {
+        assert_eq!(profile_steps("checked_add steps=412", "checked"), None);
+        assert_eq!(profile_steps("checked_add steps=412", "add"), None);
+        assert_eq!(profile_steps("wrapping_add steps=412", "checked_add"), None);
+    }
+
+    #[test]
+    fn profile_steps_ignores_numbers_not_right_after_steps() {
+        assert_eq!(profile_steps("keccak: 0.66 sec (2000 steps/op) (5241 ops)", "keccak"), None);
+        assert_eq!(profile_steps("checked_add 412 steps", "checked_add"), None);
+        assert_eq!(profile_steps("checked_add steps=", "checked_add"), None);
+    }
+
+    #[test]
+    fn total_steps_reads_the_first_steps_line() {
+        let output = "process_rom() steps=1234567 duration=1.2 tp=1.0 Msteps/s\nsteps=1\n";
+        assert_eq!(total_steps(output), Some(1234567));
+        assert_eq!(total_steps("no steps here\n"), None);
+    }
+
+    #[test]
+    fn opcode_calls_needs_the_opcode_first() {
+        let output = "    add: 120 steps=120\n    keccak: 5241 (25 steps/op)\n";
+        assert_eq!(opcode_calls(output, "keccak"), Some(5241));
+        assert_eq!(opcode_calls(output, "sha256"), None);
+        assert_eq!(opcode_calls("calls of keccak: 3\n", "keccak"), None);
+    }
+
+    #[test]
+    fn air_instances_reads_the_number_after_the_air() {
+        let output = "Air [0:1] Main: 3 instances\nAir [0:9] Keccakf: 2 instances\n";
+        assert_eq!(air_instances(output, "Keccakf"), Some(2));
+        assert_eq!(air_instances(output, "Main"), Some(3));
+        assert_eq!(air_instances(output, "Sha256f"), None);
+        assert_eq!(air_instances("| Blake2br | 1 |\n", "Blake2br"), Some(1));
+    }
+}
@@ -0,0 +1,271 @@
+//! Sweeps the benchmark mode of the hash guests across their iteration counts in the emulator.
+//!
+//! For every guest and every count in its `random` counts, it writes a benchmark input (random
+//! states through the syscall only, one call per iteration), runs the guest ELF on it with
+//! `ziskemu -X` for the total steps and the calls of the precompile opcode, and with
+//! `cargo-zisk stats` for the instances of the precompile AIR. The capacity of an instance is not
+//! declared anywhere: the runs of a guest bound it, since `calls` take `ceil(calls / capacity)`
+//! instances, and the guests whose counts sit around it must pin it to a single value.
+
+use clap::Parser;
+use emu_stats::{air_instances, opcode_calls, total_steps};
+use hash_input::guests::{guest, Guest, GUESTS};
+use hash_input::{HashInput, Mode};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{exit, Command},
+};
+
+#[derive(Parser)]
+struct Args {
+    /// Only sweep this guest (keccak, sha256, blake2 or poseidon2)
+    #[arg(long, short)]
+    program: Option<String>,
+
+    /// PRNG seed of the benchmark inputs
+    #[arg(long, short, default_value_t = 0)]
+    seed: u64,
+
+    /// Directory of the guest ELFs, by default zisk-programs/target/elf/riscv64ima-zisk-zkvm-elf/release
+    #[arg(long, short)]
+    elf_dir: Option<PathBuf>,
+
+    /// Emulator binary
+    #[arg(long, default_value = "ziskemu")]
+    ziskemu: PathBuf,
+
+    /// ZisK CLI, run with `stats` for the instances of every run
+    #[arg(long, default_value = "cargo-zisk")]
+    cargo_zisk: PathBuf,
+
+    /// Proving key handed to `cargo-zisk stats`, instead of its default one
+    #[arg(long, short = 'k')]
+    proving_key: Option<PathBuf>,
+
+    /// Write the sweep as CSV to this path
+    #[arg(long, short)]
+    csv: Option<PathBuf>,
+}
+
+struct Measurement {
+    program: &'static str,
+    iterations: u64,
+    steps: Option<u64>,
+    calls: Option<u64>,
+    instances: Option<u64>,
+}
+
+impl Measurement {
+    /// Why the run does not match its count, if it does not
+    fn failure(&self) -> Option<String> {
+        let Some(calls) = self.calls else {
+            return Some("no precompile calls in the emulator statistics".to_string());
+        };
+        if calls != self.iterations {
+            return Some(format!("{calls} precompile calls for {} iterations", self.iterations));
+        }
+        if self.instances.is_none() {
+            return Some("no precompile instances in the instance statistics".to_string());
+        }
+        None
+    }
+}
+
+/// Capacities of an instance that agree with every run, as an inclusive range whose end is
+/// `None` when no run takes more than one instance. `None` when no capacity agrees with them all
+fn capacity_range(runs: &[(u64, u64)]) -> Option<(u64, Option<u64>)> {
+    let (mut min, mut max) = (1, None::<u64>);
+    for &(calls, instances) in runs {
+        if calls == 0 || instances == 0 {
+            if calls != instances {
+                return None;
+            }
+            continue;
+        }
+        // ceil(calls / capacity) == instances, i.e. (instances - 1) * capacity < calls <=
+        // instances * capacity
+        min = min.max(calls.div_ceil(instances));
+        if instances > 1 {
+            let bound = (calls - 1) / (instances - 1);
+            max = Some(max.map_or(bound, |max| max.min(bound)));
+        }
+    }
+    max.is_none_or(|max| min <= max).then_some((min, max))
+}
+
+/// The capacity measured for a guest, and why it fails the sweep if it does
+fn capacity_check(guest: &Guest, measurements: &[Measurement]) -> (String, Option<String>) {
+    let runs: Vec<(u64, u64)> =
+        measurements.iter().filter_map(|m| Some((m.calls?, m.instances?))).collect();
+    match capacity_range(&runs) {
+        None => ("none".to_string(), Some("the instances agree with no capacity".to_string())),
+        Some((min, Some(max))) if min == max => (min.to_string(), None),
+        Some((min, max)) => {
+            let range = format!("{min}..={}", fmt_option(max));
+            let failure = guest.pins_capacity.then(|| {
+                "the counts no longer sit around the capacity, update them in guests.rs".to_string()
+            });
+            (range, failure)
+        }
+    }
+}
+
+fn fmt_option(value: Option<u64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn run_command(command: &mut Command) -> String {
+    let result = command
+        .output()
+        .unwrap_or_else(|e| panic!("Failed to run {:?}: {e}", command.get_program()));
+    if !result.status.success() {
+        eprintln!("{:?} failed", command);
+        eprintln!("{}", String::from_utf8_lossy(&result.stderr));
+    }
+    String::from_utf8_lossy(&result.stdout).into_owned()
+}
+
+fn run(args: &Args, elf_dir: &Path, input_dir: &Path, guest: &Guest) -> Vec<Measurement> {
+    let elf = elf_dir.join(guest.program);
+    assert!(elf.exists(), "Missing ELF {}, build the guests with cargo-zisk first", elf.display());
+
+    let mut measurements = Vec::new();
+    for &iterations in guest.random {
+        let input = input_dir.join(format!("input_{}_bench_{iterations}.bin", guest.prefix));
+        let stdin = zisk_sdk::ZiskStdin::new();
+        stdin.write(&HashInput {
+            mode: Mode::Benchmark,
+            seed: args.seed,
+            first_iteration: 0,
+            iterations,
+        });
+        stdin.save(&input).expect("Failed to write input to file");
+
+        let emulator = run_command(
+            Command::new(&args.ziskemu)
+                .arg("--elf")
+                .arg(&elf)
+                .arg("--inputs")
+                .arg(&input)
+                .arg("-X"),
+        );
+
+        let mut stats = Command::new(&args.cargo_zisk);
+        stats.arg("stats").arg("--elf").arg(&elf).arg("--inputs").arg(&input);
+        if let Some(proving_key) = &args.proving_key {
+            stats.arg("--proving-key").arg(proving_key);
+        }
+        let stats = run_command(&mut stats);
+
+        measurements.push(Measurement {
+            program: guest.program,
+            iterations,
+            steps: total_steps(&emulator),
+            calls: opcode_calls(&emulator, guest.opcode),
+            instances: air_instances(&stats, guest.air),
+        });
+    }
+    measurements
+}
+
+fn write_csv(path: &PathBuf, measurements: &[Measurement]) {
+    let mut out = String::from("program,iterations,steps,calls,instances\n");
+    for m in measurements {
+        out.push_str(&format!(
+            "{},{},{},{},{}\n",
+            m.program,
+            m.iterations,
+            fmt_option(m.steps),
+            fmt_option(m.calls),
+            fmt_option(m.instances)
+        ));
+    }
+    fs::write(path, out).expect("Failed to write CSV");
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let tool_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let current_dir = tool_dir
+        .parent() // → tools/
+        .and_then(|p| p.parent()) // → zisk-testvectors
+        .unwrap();
+
+    if let Some(program) = &args.program {
+        assert!(guest(program).is_some(), "Unknown program {program}");
+    }
+
+    let elf_dir = args.elf_dir.clone().unwrap_or_else(|| {
+        current_dir.join("zisk-programs/target/elf/riscv64ima-zisk-zkvm-elf/release")
+    });
+    let input_dir = std::env::temp_dir().join("hash-bench");
+    fs::create_dir_all(&input_dir).expect("Failed to create input directory");
+
+    let guests: Vec<&Guest> =
+        GUESTS.iter().filter(|g| args.program.as_deref().is_none_or(|p| p == g.program)).collect();
+    let measurements: Vec<Vec<Measurement>> =
+        guests.iter().map(|guest| run(&args, &elf_dir, &input_dir, guest)).collect();
+
+    println!(
+        "{:<10}  {:>10}  {:>14}  {:>10}  {:>9}  CHECK",
+        "PROGRAM", "ITERATIONS", "STEPS", "CALLS", "INSTANCES"
+    );
+    let mut failures = 0;
+    for m in measurements.iter().flatten() {
+        let failure = m.failure();
+        println!(
+            "{:<10}  {:>10}  {:>14}  {:>10}  {:>9}  {}",
+            m.program,
+            m.iterations,
+            fmt_option(m.steps),
+            fmt_option(m.calls),
+            fmt_option(m.instances),
+            failure.as_deref().unwrap_or("ok")
+        );
+        failures += failure.is_some() as usize;
+    }
+
+    println!("\n{:<10}  {:>14}  CHECK", "PROGRAM", "CAPACITY");
+    for (guest, measurements) in guests.iter().zip(&measurements) {
+        let (capacity, failure) = capacity_check(guest, measurements);
+        println!("{:<10}  {:>14}  {}", guest.program, capacity, failure.as_deref().unwrap_or("ok"));
+        failures += failure.is_some() as usize;
+    }
+    let measurements: Vec<Measurement> = measurements.into_iter().flatten().collect();
+
+    if let Some(csv) = &args.csv {
+        write_csv(csv, &measurements);
+        println!("\nWrote {}", csv.display());
+    }
+
+    if failures > 0 {
+        eprintln!("\n{failures} runs or capacities fail the sweep");
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacity_range_is_pinned_by_the_counts_around_it() {
+        let runs = [(1, 1), (60, 1), (5241, 1), (5242, 2), (10483, 3)];
+        assert_eq!(capacity_range(&runs), Some((5241, Some(5241))));
+    }
+
+    #[test]
+    fn capacity_range_is_open_below_two_instances() {
+        assert_eq!(capacity_range(&[(1, 1), (30, 1)]), Some((30, None)));
+        assert_eq!(capacity_range(&[(5241, 1), (10483, 2)]), Some((5242, Some(10482))));
+    }
+
+    #[test]
+    fn capacity_range_rejects_inconsistent_instances() {
+        assert_eq!(capacity_range(&[(5241, 2), (5242, 1)]), None);
+        assert_eq!(capacity_range(&[(10, 1), (11, 3)]), None);
+        assert_eq!(capacity_range(&[(0, 1)]), None);
+    }
+}
@@ -0,0 +1,88 @@
+//! Writes the `inputs/*.bin` files of the keccak, sha256, blake2 and poseidon2 guests.
+//!
+//! Every guest gets a random input per iteration count in its `random` counts, a known-answer
+//! input, an input running both, and a benchmark input with the largest count that skips the
+//! reference check. The guests with a full hash function also get an input of random messages.
+
+use clap::Parser;
+use hash_input::guests::{guest, GUESTS};
+use hash_input::{HashInput, Mode};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Iterations of the `Both` inputs
+const BOTH_ITERATIONS: u64 = 100;
+
+/// Iterations of the `Messages` inputs
+const MESSAGE_ITERATIONS: u64 = 100;
+
+#[derive(Parser)]
+struct Args {
+    /// Only write the inputs of this guest (keccak, sha256, blake2 or poseidon2)
+    #[arg(long, short)]
+    program: Option<String>,
+
+    /// PRNG seed of the random inputs
+    #[arg(long, short, default_value_t = 0)]
+    seed: u64,
+
+    /// Output path for the inputs, written to <output_path>/<program>/
+    #[arg(long, short)]
+    output_path: Option<PathBuf>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let tool_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let current_dir = tool_dir
+        .parent() // → tools/
+        .and_then(|p| p.parent()) // → zisk-testvectors
+        .unwrap();
+
+    if let Some(program) = &args.program {
+        assert!(guest(program).is_some(), "Unknown program {program}");
+    }
+
+    for guest in GUESTS.iter().filter(|g| args.program.as_deref().is_none_or(|p| p == g.program)) {
+        // Default: overwrite the inputs in zisk-programs/<program>/inputs
+        let output_dir = match &args.output_path {
+            Some(output_path) => output_path.join(guest.program),
+            None => current_dir.join("zisk-programs").join(guest.program).join("inputs"),
+        };
+        fs::create_dir_all(&output_dir).expect("Failed to create output directory");
+
+        let input =
+            |mode, iterations| HashInput { mode, seed: args.seed, first_iteration: 0, iterations };
+        let prefix = guest.prefix;
+
+        for &iterations in guest.random {
+            let file_name = format!("input_{prefix}_{iterations}.bin");
+            write_input(&output_dir.join(file_name), &input(Mode::Random, iterations));
+        }
+
+        let file_name = format!("input_{prefix}_kat.bin");
+        write_input(&output_dir.join(file_name), &input(Mode::KnownAnswer, 1));
+
+        let file_name = format!("input_{prefix}_both_{BOTH_ITERATIONS}.bin");
+        write_input(&output_dir.join(file_name), &input(Mode::Both, BOTH_ITERATIONS));
+
+        let iterations = *guest.random.iter().max().unwrap();
+        let file_name = format!("input_{prefix}_bench_{iterations}.bin");
+        write_input(&output_dir.join(file_name), &input(Mode::Benchmark, iterations));
+
+        if let Some(hash) = guest.messages {
+            let file_name = format!("input_{hash}_{MESSAGE_ITERATIONS}.bin");
+            write_input(&output_dir.join(file_name), &input(Mode::Messages, MESSAGE_ITERATIONS));
+        }
+    }
+}
+
+fn write_input(file_path: &Path, input: &HashInput) {
+    let stdin = zisk_sdk::ZiskStdin::new();
+    stdin.write(input);
+    stdin.save(file_path).expect("Failed to write input to file");
+    println!("Wrote {}", file_path.display());
+}
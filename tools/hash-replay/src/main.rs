@@ -0,0 +1,94 @@
+//! Writes the input that replays iterations of a hash guest run.
+//!
+//! The keccak, sha256, blake2 and poseidon2 guests seed the PRNG of every iteration from the input
+//! seed and the iteration index, so the iteration that a failing run reports can be run again on
+//! its own.
+
+use clap::{Parser, ValueEnum};
+use hash_input::{HashInput, Mode};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Program {
+    Keccak,
+    Sha256,
+    Blake2,
+    Poseidon2,
+}
+
+impl Program {
+    fn name(self) -> &'static str {
+        match self {
+            Program::Keccak => "keccak",
+            Program::Sha256 => "sha256",
+            Program::Blake2 => "blake2",
+            Program::Poseidon2 => "poseidon2",
+        }
+    }
+}
+
+#[derive(Parser)]
+struct Args {
+    /// Guest program to replay
+    program: Program,
+
+    /// PRNG seed of the failing run
+    #[arg(long, short)]
+    seed: u64,
+
+    /// Iteration reported by the failing run
+    #[arg(long, short)]
+    iteration: u64,
+
+    /// Number of iterations to run from that one
+    #[arg(long, short = 'n', default_value_t = 1)]
+    iterations: u64,
+
+    /// Replay a random message of the full hash function instead of a random state
+    #[arg(long, short)]
+    messages: bool,
+
+    /// Output file, by default zisk-programs/<program>/inputs/input_replay_<seed>_<iteration>.bin
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let tool_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let current_dir = tool_dir
+        .parent() // → tools/
+        .and_then(|p| p.parent()) // → zisk-testvectors
+        .unwrap();
+
+    let name = args.program.name();
+    assert!(
+        !args.messages || !matches!(args.program, Program::Poseidon2),
+        "poseidon2 has no random messages to replay"
+    );
+    let output = args.output.unwrap_or_else(|| {
+        current_dir
+            .join("zisk-programs")
+            .join(name)
+            .join("inputs")
+            .join(format!("input_replay_{}_{}.bin", args.seed, args.iteration))
+    });
+
+    let input = HashInput {
+        mode: if args.messages { Mode::Messages } else { Mode::Random },
+        seed: args.seed,
+        first_iteration: args.iteration,
+        iterations: args.iterations,
+    };
+
+    let stdin = zisk_sdk::ZiskStdin::new();
+    stdin.write(&input);
+    stdin.save(&output).expect("Failed to write input to file");
+
+    println!("Replay input written to {}", output.display());
+    println!(
+        "ziskemu --elf target/elf/riscv64ima-zisk-zkvm-elf/release/{name} --inputs {}",
+        output.display()
+    );
+}
@@ -0,0 +1,342 @@
+//! Builds a per-call steps table from the output of a guest built with the `profile` feature.
+//!
+//! The revm guest prints a `PROFILE tag=<tag> suite=<suite> vector=<name>` marker right before
+//! every profiled precompile call. A report line names a tag and carries a `steps` count (see
+//! `emu-stats`). A report is paired with the call of its tag that is waiting for one, so the
+//! table does not depend on reports following their marker right away. A tag whose report only
+//! comes after a later call of the same tag is reported in aggregate instead: its reports cannot
+//! be told apart per call, so its calls have no steps and its reports are summed per tag.
+//!
+//! The layout of the emulator reports has not been checked against a captured `ziskemu` log yet.
+
+use clap::Parser;
+use emu_stats::profile_steps;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    process::exit,
+};
+
+const MARKER_PREFIX: &str = "PROFILE tag=";
+
+#[derive(Parser)]
+struct Args {
+    /// Emulator output to parse (use `-` to read from stdin)
+    log: PathBuf,
+
+    /// Write the per-call table as CSV to this path
+    #[arg(long, short)]
+    csv: Option<PathBuf>,
+
+    /// CSV produced by a previous run to compare against
+    #[arg(long, short)]
+    baseline: Option<PathBuf>,
+
+    /// Maximum allowed steps increase over the baseline, in percent
+    #[arg(long, short, default_value_t = 5.0)]
+    threshold: f64,
+}
+
+struct ProfiledCall {
+    suite: String,
+    vector: String,
+    tag: String,
+    steps: Option<u64>,
+}
+
+/// Parses a `PROFILE tag=<tag> suite=<suite> vector=<name>` marker line
+fn parse_marker(line: &str) -> Option<ProfiledCall> {
+    let rest = line.trim().strip_prefix(MARKER_PREFIX)?;
+    let (tag, rest) = rest.split_once(" suite=")?;
+    let (suite, vector) = rest.split_once(" vector=")?;
+    Some(ProfiledCall {
+        suite: suite.to_string(),
+        vector: vector.to_string(),
+        tag: tag.to_string(),
+        steps: None,
+    })
+}
+
+/// Profiled calls of a log, and the summed steps of the tags reported in aggregate
+struct ProfileLog {
+    calls: Vec<ProfiledCall>,
+    aggregated: BTreeMap<String, u64>,
+}
+
+fn parse_log(content: &str) -> ProfileLog {
+    let mut calls: Vec<ProfiledCall> = Vec::new();
+    // Calls of each tag waiting for their report, in order
+    let mut waiting: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    let mut aggregated: BTreeMap<String, u64> = BTreeMap::new();
+    for line in content.lines() {
+        if let Some(call) = parse_marker(line) {
+            waiting.entry(call.tag.clone()).or_default().push(calls.len());
+            calls.push(call);
+            continue;
+        }
+        for (tag, pending) in &mut waiting {
+            let Some(steps) = profile_steps(line, tag) else {
+                continue;
+            };
+            if let Some(total) = aggregated.get_mut(tag) {
+                *total += steps;
+                pending.clear();
+            } else if pending.len() > 1 {
+                // Reported after a later call of the tag: the steps are no longer per call
+                for call in calls.iter_mut().filter(|c| c.tag == *tag) {
+                    call.steps = None;
+                }
+                aggregated.insert(tag.clone(), steps);
+                pending.clear();
+            } else if let Some(index) = pending.pop() {
+                calls[index].steps = Some(steps);
+            }
+        }
+    }
+    ProfileLog { calls, aggregated }
+}
+
+fn key(suite: &str, vector: &str, tag: &str) -> String {
+    format!("{suite}/{vector}/{tag}")
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv_split(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn write_csv(path: &PathBuf, calls: &[ProfiledCall]) {
+    let mut out = String::from("suite,vector,tag,steps\n");
+    for call in calls {
+        out.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_escape(&call.suite),
+            csv_escape(&call.vector),
+            csv_escape(&call.tag),
+            call.steps.map(|s| s.to_string()).unwrap_or_default()
+        ));
+    }
+    fs::write(path, out).expect("Failed to write CSV");
+}
+
+/// Loads a CSV written by `write_csv`, keyed by suite/vector/tag (repeated keys keep the first)
+fn read_baseline(path: &PathBuf) -> HashMap<String, u64> {
+    let content = fs::read_to_string(path).expect("Failed to read baseline");
+    let mut baseline = HashMap::new();
+    for line in content.lines().skip(1) {
+        let fields = csv_split(line);
+        if fields.len() != 4 {
+            continue;
+        }
+        if let Ok(steps) = fields[3].parse::<u64>() {
+            baseline.entry(key(&fields[0], &fields[1], &fields[2])).or_insert(steps);
+        }
+    }
+    baseline
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let content = if args.log.as_os_str() == "-" {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content).expect("Failed to read stdin");
+        content
+    } else {
+        fs::read_to_string(&args.log).expect("Failed to read log")
+    };
+
+    let ProfileLog { calls, aggregated } = parse_log(&content);
+    if calls.is_empty() {
+        eprintln!("No profile markers found; was the guest built with `--features profile`?");
+        exit(1);
+    }
+
+    let baseline = args.baseline.as_ref().map(read_baseline);
+
+    // Per-call table
+    let suite_w = calls.iter().map(|c| c.suite.len()).max().unwrap().max(5);
+    let vector_w = calls.iter().map(|c| c.vector.len()).max().unwrap().clamp(6, 60);
+    let tag_w = calls.iter().map(|c| c.tag.len()).max().unwrap().max(3);
+    println!(
+        "{:<suite_w$}  {:<vector_w$}  {:<tag_w$}  {:>12}  {:>9}",
+        "SUITE", "VECTOR", "TAG", "STEPS", "DELTA"
+    );
+
+    let mut regressions = Vec::new();
+    let mut missing = 0;
+    for call in &calls {
+        let vector: String = call.vector.chars().take(vector_w).collect();
+        let Some(steps) = call.steps else {
+            missing += !aggregated.contains_key(&call.tag) as usize;
+            println!(
+                "{:<suite_w$}  {:<vector_w$}  {:<tag_w$}  {:>12}",
+                call.suite, vector, call.tag, "-"
+            );
+            continue;
+        };
+
+        let delta = baseline
+            .as_ref()
+            .and_then(|b| b.get(&key(&call.suite, &call.vector, &call.tag)))
+            .map(|&base| (steps as f64 - base as f64) * 100.0 / base.max(1) as f64);
+        let delta_str = delta.map(|d| format!("{d:+.2}%")).unwrap_or_default();
+        println!(
+            "{:<suite_w$}  {:<vector_w$}  {:<tag_w$}  {:>12}  {:>9}",
+            call.suite, vector, call.tag, steps, delta_str
+        );
+
+        if delta.is_some_and(|d| d > args.threshold) {
+            regressions.push((call, delta.unwrap()));
+        }
+    }
+
+    // Per-precompile summary
+    let mut by_tag: BTreeMap<&str, Vec<u64>> = BTreeMap::new();
+    for call in &calls {
+        if let Some(steps) = call.steps {
+            by_tag.entry(&call.tag).or_default().push(steps);
+        }
+    }
+    println!();
+    println!(
+        "{:<tag_w$}  {:>7}  {:>14}  {:>12}  {:>12}  {:>12}",
+        "TAG", "CALLS", "TOTAL", "MIN", "MEAN", "MAX"
+    );
+    for (tag, steps) in &by_tag {
+        let total: u64 = steps.iter().sum();
+        println!(
+            "{:<tag_w$}  {:>7}  {:>14}  {:>12}  {:>12}  {:>12}",
+            tag,
+            steps.len(),
+            total,
+            steps.iter().min().unwrap(),
+            total / steps.len() as u64,
+            steps.iter().max().unwrap()
+        );
+    }
+
+    for (tag, total) in &aggregated {
+        let count = calls.iter().filter(|c| c.tag == *tag).count();
+        println!(
+            "{:<tag_w$}  {:>7}  {:>14}  {:>12}  {:>12}  {:>12}",
+            tag, count, total, "-", "-", "-"
+        );
+    }
+
+    if !aggregated.is_empty() {
+        eprintln!(
+            "\nReported after later calls of the same tag, so only summed per tag: {}",
+            aggregated.keys().cloned().collect::<Vec<_>>().join(", ")
+        );
+    }
+    if missing > 0 {
+        eprintln!("\n{missing} profiled calls had no matching steps report");
+    }
+
+    if let Some(ref path) = args.csv {
+        write_csv(path, &calls);
+        println!("\n✓ CSV written to: {}", path.display());
+    }
+
+    if !regressions.is_empty() {
+        eprintln!("\n{} calls regressed more than {}%:", regressions.len(), args.threshold);
+        for (call, delta) in &regressions {
+            eprintln!("  {} / {} / {}: {delta:+.2}%", call.suite, call.vector, call.tag);
+        }
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_log_pairs_markers_with_their_reports() {
+        let log = "\
+PROFILE tag=ecrecover suite=ecrecover_precompile vector=ValidKey
+ecrecover steps=98765
+PROFILE tag=modexp suite=modexp vector=eip_example1
+sha256 steps=11
+modexp steps=4321
+modexp steps=1
+PROFILE tag=ecadd suite=bn254_add vector=chfast1
+";
+        let ProfileLog { calls, aggregated } = parse_log(log);
+        assert!(aggregated.is_empty());
+        let summary: Vec<_> = calls
+            .iter()
+            .map(|c| (c.suite.as_str(), c.vector.as_str(), c.tag.as_str(), c.steps))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("ecrecover_precompile", "ValidKey", "ecrecover", Some(98765)),
+                ("modexp", "eip_example1", "modexp", Some(4321)),
+                ("bn254_add", "chfast1", "ecadd", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_log_pairs_reports_with_the_waiting_call_of_their_tag() {
+        let log = "\
+PROFILE tag=ecrecover suite=ecrecover_precompile vector=ValidKey
+PROFILE tag=modexp suite=modexp vector=eip_example1
+modexp steps=4321
+ecrecover steps=98765
+";
+        let ProfileLog { calls, aggregated } = parse_log(log);
+        assert!(aggregated.is_empty());
+        let steps: Vec<_> = calls.iter().map(|c| (c.tag.as_str(), c.steps)).collect();
+        assert_eq!(steps, [("ecrecover", Some(98765)), ("modexp", Some(4321))]);
+    }
+
+    #[test]
+    fn parse_log_sums_the_tags_reported_after_their_later_calls() {
+        let log = "\
+PROFILE tag=modexp suite=modexp vector=eip_example1
+PROFILE tag=modexp suite=modexp vector=eip_example2
+PROFILE tag=ecadd suite=bn254_add vector=chfast1
+ecadd steps=300
+modexp steps=4321
+modexp steps=1000
+";
+        let ProfileLog { calls, aggregated } = parse_log(log);
+        let steps: Vec<_> = calls.iter().map(|c| (c.tag.as_str(), c.steps)).collect();
+        assert_eq!(steps, [("modexp", None), ("modexp", None), ("ecadd", Some(300))]);
+        assert_eq!(aggregated.into_iter().collect::<Vec<_>>(), [("modexp".to_string(), 5321)]);
+    }
+
+    #[test]
+    fn parse_marker_keeps_spaces_in_vector_names() {
+        let call = parse_marker("PROFILE tag=p256verify suite=p256_verify vector=a b, c").unwrap();
+        assert_eq!((call.tag.as_str(), call.vector.as_str()), ("p256verify", "a b, c"));
+    }
+}
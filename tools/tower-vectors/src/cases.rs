@@ -0,0 +1,103 @@
+use serde::Deserialize;
+
+/// Element of a tower level as its hexadecimal Fp coefficients, in the order of the guests.
+/// Trailing zero coefficients may be omitted, so `[]` is zero and `["0x1"]` is one
+pub type Coeffs = Vec<String>;
+
+#[derive(Deserialize)]
+pub struct Case {
+    /// Comment emitted above the case
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(flatten)]
+    pub op: Op,
+}
+
+/// Operation of a case, whose expected result is computed by the generator
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Op {
+    Mul {
+        a: Coeffs,
+        b: Coeffs,
+    },
+    /// Multiplication by 1 + b₀·vw + b₁·v²w, with `b` holding (b₀, b₁) in Fp2
+    SparseMul {
+        a: Coeffs,
+        b: Coeffs,
+    },
+    Square {
+        a: Coeffs,
+    },
+    Inverse {
+        a: Coeffs,
+    },
+    Conjugate {
+        a: Coeffs,
+    },
+    Frobenius {
+        power: usize,
+        a: Coeffs,
+    },
+    /// Exponentiation by a hexadecimal u64
+    Exp {
+        e: String,
+        a: Coeffs,
+    },
+    /// Compression followed by decompression
+    Compress {
+        a: Coeffs,
+    },
+    /// Decompression of a compressed element
+    Decompress {
+        a: Coeffs,
+    },
+    /// Squaring of a compressed element, followed by decompression
+    SquareCyclo {
+        a: Coeffs,
+    },
+    ExpByX {
+        a: Coeffs,
+    },
+    ExpByXone {
+        a: Coeffs,
+    },
+    ExpByXdiv3 {
+        a: Coeffs,
+    },
+    FinalExp {
+        a: Coeffs,
+    },
+}
+
+impl Op {
+    /// Section comment emitted before the first of a run of cases of the same operation
+    pub fn section(&self) -> String {
+        match self {
+            Op::Mul { .. } => "Multiplication".to_string(),
+            Op::SparseMul { .. } => "Sparse multiplication".to_string(),
+            Op::Square { .. } => "Squaring".to_string(),
+            Op::Inverse { .. } => "Inversion".to_string(),
+            Op::Conjugate { .. } => "Conjugate".to_string(),
+            Op::Frobenius { power, .. } => format!("Frobenius{power}"),
+            Op::Exp { .. } => "Exponentiation".to_string(),
+            Op::Compress { .. } => {
+                "Compression and decompression: It must happen that D(C(a)) = a".to_string()
+            }
+            Op::Decompress { .. } => "Decompression".to_string(),
+            Op::SquareCyclo { .. } => "Squaring: It must happen that D(C(a²)) = a²".to_string(),
+            Op::ExpByX { .. } => "Exponentiation by |x|".to_string(),
+            Op::ExpByXone { .. } => "Exponentiation by |x| + 1".to_string(),
+            Op::ExpByXdiv3 { .. } => "Exponentiation by (|x| + 1) / 3".to_string(),
+            Op::FinalExp { .. } => "Final exponentiation a^{(p¹²-1)/r}".to_string(),
+        }
+    }
+}
+
+/// Pads omitted trailing coefficients with zeros
+pub fn padded(coeffs: &Coeffs, len: usize) -> Coeffs {
+    assert!(coeffs.len() <= len, "Expected at most {len} coefficients, got {}", coeffs.len());
+    let mut coeffs = coeffs.clone();
+    coeffs.resize(len, "0x0".to_string());
+    coeffs
+}
@@ -0,0 +1,86 @@
+use clap::Parser;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+mod cases;
+mod module;
+mod tower;
+
+use cases::Case;
+use module::ModuleBuilder;
+use tower::{Bls12_381, Bn254, Curve};
+
+/// Guest modules generated for each curve, read from `cases/<curve>/<module>.json`
+const MODULES: [&str; 3] = ["fp12", "cyclotomic", "final_exp"];
+
+#[derive(Parser)]
+struct Args {
+    /// Only generate the modules of this curve (bn254 or bls12_381)
+    #[arg(long, short)]
+    curve: Option<String>,
+
+    /// Output path for the modules, written to <output_path>/<curve>/<module>.rs
+    #[arg(long, short)]
+    output_path: Option<PathBuf>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let tool_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let current_dir = tool_dir
+        .parent() // → tools/
+        .and_then(|p| p.parent()) // → zisk-testvectors
+        .unwrap();
+
+    if let Some(curve) = &args.curve {
+        assert!([Bn254::NAME, Bls12_381::NAME].contains(&curve.as_str()), "Unknown curve {curve}");
+    }
+    let selected = |name: &str| args.curve.as_deref().is_none_or(|c| c == name);
+
+    // Default: overwrite the guest modules in zisk-programs/<curve>/src
+    let output_dir = |name: &str| match &args.output_path {
+        Some(output_path) => output_path.join(name),
+        None => current_dir.join("zisk-programs").join(name).join("src"),
+    };
+
+    if selected(Bn254::NAME) {
+        generate::<Bn254>(&tool_dir.join("cases"), &output_dir(Bn254::NAME));
+    }
+    if selected(Bls12_381::NAME) {
+        generate::<Bls12_381>(&tool_dir.join("cases"), &output_dir(Bls12_381::NAME));
+    }
+}
+
+fn generate<C: Curve>(cases_dir: &Path, output_dir: &Path) {
+    fs::create_dir_all(output_dir).expect("Failed to create output directory");
+
+    for module in MODULES {
+        let cases_path = cases_dir.join(C::NAME).join(format!("{module}.json"));
+        let cases = fs::read_to_string(&cases_path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", cases_path.display()));
+        let cases: Vec<Case> = serde_json::from_str(&cases)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", cases_path.display()));
+
+        let mut builder = ModuleBuilder::new(C::NAME, &format!("{module}_tests"));
+        for case in &cases {
+            builder.add_case::<C>(case);
+        }
+
+        let file_path = output_dir.join(format!("{module}.rs"));
+        fs::write(&file_path, builder.build()).expect("Failed to write module");
+
+        // The modules are emitted unformatted, rustfmt gives them the layout of the guests
+        let status = Command::new("rustfmt")
+            .args(["--edition", "2021"])
+            .arg(&file_path)
+            .status()
+            .expect("Failed to run rustfmt");
+        assert!(status.success(), "rustfmt failed on {}", file_path.display());
+
+        println!("✓ {} cases written to {}", cases.len(), file_path.display());
+    }
+}
@@ -0,0 +1,216 @@
+use ark_ff::Field;
+use std::collections::BTreeSet;
+
+use crate::cases::{padded, Case, Op};
+use crate::tower::{
+    compress, decompress, exp, final_exp, fp12_from_hex, fp12_limbs, fp2_list_from_hex,
+    fp2_list_limbs, inverse, sparse_mul, Curve,
+};
+
+/// Guest test module built case by case, in the shape of the hand-written ones
+pub struct ModuleBuilder {
+    curve: &'static str,
+    fn_name: String,
+    imports: BTreeSet<String>,
+    lines: Vec<String>,
+    section: Option<String>,
+}
+
+impl ModuleBuilder {
+    pub fn new(curve: &'static str, fn_name: &str) -> Self {
+        Self {
+            curve,
+            fn_name: fn_name.to_string(),
+            imports: BTreeSet::new(),
+            lines: vec![],
+            section: None,
+        }
+    }
+
+    /// Name of the zisklib function `op` for this curve, recorded as an import
+    fn zisklib(&mut self, op: &str) -> String {
+        let name = format!("{op}_{}", self.curve);
+        self.imports.insert(name.clone());
+        name
+    }
+
+    fn line(&mut self, line: impl Into<String>) {
+        self.lines.push(line.into());
+    }
+
+    fn let_array(&mut self, name: &str, limbs: &[u64]) {
+        let literal = array_literal(limbs);
+        self.line(format!("let {name} = {literal};"));
+    }
+
+    /// Emits the expected result, reusing `input` when the operation leaves it unchanged
+    fn check(&mut self, res: &[u64], input: (&str, &[u64])) {
+        if res == input.1 {
+            self.line(format!("let res_exp = {};", input.0));
+        } else {
+            self.let_array("res_exp", res);
+        }
+        self.line("assert_eq!(res, res_exp);");
+    }
+
+    /// `let res = f(&a); let res_exp = ...; assert_eq!(res, res_exp);`
+    fn unary(&mut self, op: &str, a: &[u64], res: &[u64]) {
+        let f = self.zisklib(op);
+        self.let_array("a", a);
+        self.line(format!("let res = {f}(&a);"));
+        self.check(res, ("a", a));
+    }
+
+    pub fn add_case<C: Curve>(&mut self, case: &Case) {
+        let fp12 = |a: &Vec<String>| fp12_from_hex::<C>(&padded(a, 12));
+        let compressed = |a: &Vec<String>| fp2_list_from_hex::<C, 4>(&padded(a, 8));
+
+        if !self.lines.is_empty() {
+            self.line("");
+        }
+        let section = case.op.section();
+        if self.section.as_ref() != Some(&section) {
+            self.line(format!("// {section}"));
+            self.section = Some(section);
+        }
+        if let Some(comment) = &case.comment {
+            self.line(format!("// {comment}"));
+        }
+
+        match &case.op {
+            Op::Mul { a, b } => {
+                let (a, b) = (fp12(a), fp12(b));
+                let f = self.zisklib("mul_fp12");
+                self.let_array("a", &fp12_limbs::<C>(&a));
+                self.let_array("b", &fp12_limbs::<C>(&b));
+                self.line(format!("let res = {f}(&a, &b);"));
+                self.check(&fp12_limbs::<C>(&(a * b)), ("a", &fp12_limbs::<C>(&a)));
+            }
+            Op::SparseMul { a, b } => {
+                let (a, b) = (fp12(a), fp2_list_from_hex::<C, 2>(&padded(b, 4)));
+                let f = self.zisklib("sparse_mul_fp12");
+                self.let_array("a", &fp12_limbs::<C>(&a));
+                self.let_array("b", &fp2_list_limbs::<C>(&b));
+                self.line(format!("let res = {f}(&a, &b);"));
+                self.check(&fp12_limbs::<C>(&sparse_mul::<C>(&a, &b)), ("a", &fp12_limbs::<C>(&a)));
+            }
+            Op::Square { a } => {
+                let a = fp12(a);
+                self.unary("square_fp12", &fp12_limbs::<C>(&a), &fp12_limbs::<C>(&a.square()));
+            }
+            Op::Inverse { a } => {
+                let a = fp12(a);
+                self.unary("inv_fp12", &fp12_limbs::<C>(&a), &fp12_limbs::<C>(&inverse::<C>(&a)));
+            }
+            Op::Conjugate { a } => {
+                let mut res = fp12(a);
+                res.conjugate_in_place();
+                self.unary("conjugate_fp12", &fp12_limbs::<C>(&fp12(a)), &fp12_limbs::<C>(&res));
+            }
+            Op::Frobenius { power, a } => {
+                let a = fp12(a);
+                let res = a.frobenius_map(*power);
+                let op = format!("frobenius{power}_fp12");
+                self.unary(&op, &fp12_limbs::<C>(&a), &fp12_limbs::<C>(&res));
+            }
+            Op::Exp { e, a } => {
+                let e = u64::from_str_radix(e.trim_start_matches("0x"), 16)
+                    .unwrap_or_else(|_| panic!("Invalid hex exponent: {e}"));
+                let a = fp12(a);
+                let f = self.zisklib("exp_fp12");
+                if e <= u16::MAX as u64 {
+                    self.line(format!("let e = {e}u64;"));
+                } else {
+                    self.line(format!("let e = 0x{e:X};"));
+                }
+                self.let_array("a", &fp12_limbs::<C>(&a));
+                self.line(format!("let res = {f}(e, &a);"));
+                self.check(&fp12_limbs::<C>(&exp::<C>(&a, &[e])), ("a", &fp12_limbs::<C>(&a)));
+            }
+            Op::Compress { a } => {
+                let a = fp12(a);
+                let c = compress::<C>(&a);
+                self.unary("compress_cyclo", &fp12_limbs::<C>(&a), &fp2_list_limbs::<C>(&c));
+                self.line("");
+                let f = self.zisklib("decompress_cyclo");
+                self.line(format!("let res = {f}(&res);"));
+                self.check(&fp12_limbs::<C>(&decompress::<C>(&c)), ("a", &fp12_limbs::<C>(&a)));
+            }
+            Op::Decompress { a } => {
+                let a = compressed(a);
+                let res = decompress::<C>(&a);
+                self.unary("decompress_cyclo", &fp2_list_limbs::<C>(&a), &fp12_limbs::<C>(&res));
+            }
+            Op::SquareCyclo { a } => {
+                let a = compressed(a);
+                let square = decompress::<C>(&a).square();
+                let c = compress::<C>(&square);
+                self.unary("square_cyclo", &fp2_list_limbs::<C>(&a), &fp2_list_limbs::<C>(&c));
+                self.line("");
+                let f = self.zisklib("decompress_cyclo");
+                self.line("let a = res;");
+                self.line(format!("let res = {f}(&a);"));
+                self.let_array("res_exp", &fp12_limbs::<C>(&decompress::<C>(&c)));
+                self.line("assert_eq!(res, res_exp);");
+            }
+            Op::ExpByX { a } => {
+                let a = fp12(a);
+                let res = exp::<C>(&a, &[C::X]);
+                self.unary("exp_by_x_cyclo", &fp12_limbs::<C>(&a), &fp12_limbs::<C>(&res));
+            }
+            Op::ExpByXone { a } => {
+                let a = fp12(a);
+                let res = exp::<C>(&a, &[C::X + 1]);
+                self.unary("exp_by_xone_cyclo", &fp12_limbs::<C>(&a), &fp12_limbs::<C>(&res));
+            }
+            Op::ExpByXdiv3 { a } => {
+                let a = fp12(a);
+                let res = exp::<C>(&a, &[(C::X + 1) / 3]);
+                self.unary("exp_by_xdiv3_cyclo", &fp12_limbs::<C>(&a), &fp12_limbs::<C>(&res));
+            }
+            Op::FinalExp { a } => {
+                let a = fp12(a);
+                let res = final_exp::<C>(&a);
+                self.unary("final_exp", &fp12_limbs::<C>(&a), &fp12_limbs::<C>(&res));
+            }
+        }
+    }
+
+    /// Source of the module, to be formatted with rustfmt
+    pub fn build(&self) -> String {
+        let imports: Vec<&str> = self.imports.iter().map(String::as_str).collect();
+        let mut source = if imports.len() == 1 {
+            format!("use ziskos::zisklib::{};\n\n", imports[0])
+        } else {
+            format!("use ziskos::zisklib::{{{}}};\n\n", imports.join(", "))
+        };
+        source.push_str(&format!("pub fn {}() {{\n", self.fn_name));
+        for line in &self.lines {
+            if line.is_empty() {
+                source.push('\n');
+            } else {
+                source.push_str(&format!("    {line}\n"));
+            }
+        }
+        source.push_str("}\n");
+        source
+    }
+}
+
+/// `[0; N]` for zero, a block setting the first limb when only that one is set, decimal limbs
+/// for small values and hexadecimal limbs otherwise
+fn array_literal(limbs: &[u64]) -> String {
+    let n = limbs.len();
+    if limbs.iter().all(|&l| l == 0) {
+        return format!("[0; {n}]");
+    }
+    if limbs[1..].iter().all(|&l| l == 0) {
+        return format!("{{\nlet mut tmp = [0; {n}];\ntmp[0] = {};\ntmp\n}}", limbs[0]);
+    }
+    if limbs.iter().all(|&l| l <= u16::MAX as u64) {
+        let limbs: Vec<String> = limbs.iter().map(|l| l.to_string()).collect();
+        return format!("[{}]", limbs.join(", "));
+    }
+    let limbs: Vec<String> = limbs.iter().map(|l| format!("0x{l:016X},")).collect();
+    format!("[\n{}\n]", limbs.join("\n"))
+}
@@ -0,0 +1,137 @@
+use ark_ff::{
+    AdditiveGroup, Field, Fp12, Fp12Config, Fp2, Fp2Config, Fp6Config, One, PrimeField, Zero,
+};
+use num_bigint::BigUint;
+
+/// Curve whose tower Fp12 = Fp6[w]/(w² - v), Fp6 = Fp2[v]/(v³ - ξ) is tested by the guests
+pub trait Curve {
+    /// Suffix of the zisklib functions and name of the guest crate
+    const NAME: &'static str;
+    /// Absolute value of the curve parameter x, as used by the cyclotomic exponentiations
+    const X: u64;
+    type Config: Fp12Config;
+    type Fr: PrimeField;
+}
+
+pub struct Bn254;
+
+impl Curve for Bn254 {
+    const NAME: &'static str = "bn254";
+    const X: u64 = <ark_bn254::Config as ark_ec::bn::BnConfig>::X[0];
+    type Config = ark_bn254::Fq12Config;
+    type Fr = ark_bn254::Fr;
+}
+
+pub struct Bls12_381;
+
+impl Curve for Bls12_381 {
+    const NAME: &'static str = "bls12_381";
+    const X: u64 = <ark_bls12_381::Config as ark_ec::bls12::Bls12Config>::X[0];
+    type Config = ark_bls12_381::Fq12Config;
+    type Fr = ark_bls12_381::Fr;
+}
+
+pub type Fp12Of<C> = Fp12<<C as Curve>::Config>;
+pub type Fp6ConfigOf<C> = <<C as Curve>::Config as Fp12Config>::Fp6Config;
+pub type Fp2Of<C> = Fp2<<Fp6ConfigOf<C> as Fp6Config>::Fp2Config>;
+pub type FpOf<C> = <<Fp6ConfigOf<C> as Fp6Config>::Fp2Config as Fp2Config>::Fp;
+
+/// Parses a hexadecimal element of Fp, rejecting values that are not reduced
+pub fn fp_from_hex<C: Curve>(hex: &str) -> FpOf<C> {
+    let digits = hex.strip_prefix("0x").unwrap_or(hex);
+    let value = BigUint::parse_bytes(digits.as_bytes(), 16)
+        .unwrap_or_else(|| panic!("Invalid hex field element: {hex}"));
+    let modulus: BigUint = FpOf::<C>::MODULUS.into();
+    assert!(value < modulus, "Field element {hex} is not reduced modulo p");
+    value.into()
+}
+
+/// Limbs of a sequence of Fp elements, in the little-endian order of the guests
+pub fn to_limbs<C: Curve>(elements: impl IntoIterator<Item = FpOf<C>>) -> Vec<u64> {
+    elements.into_iter().flat_map(|e| e.into_bigint().as_ref().to_vec()).collect()
+}
+
+pub fn fp12_from_hex<C: Curve>(coeffs: &[String]) -> Fp12Of<C> {
+    Fp12Of::<C>::from_base_prime_field_elems(coeffs.iter().map(|c| fp_from_hex::<C>(c)))
+        .expect("An element of Fp12 has 12 coefficients")
+}
+
+pub fn fp12_limbs<C: Curve>(a: &Fp12Of<C>) -> Vec<u64> {
+    to_limbs::<C>(a.to_base_prime_field_elements())
+}
+
+pub fn fp2_from_hex<C: Curve>(coeffs: &[String]) -> Fp2Of<C> {
+    assert_eq!(coeffs.len(), 2, "An element of Fp2 has 2 coefficients");
+    Fp2Of::<C>::new(fp_from_hex::<C>(&coeffs[0]), fp_from_hex::<C>(&coeffs[1]))
+}
+
+pub fn fp2_list_limbs<C: Curve>(list: &[Fp2Of<C>]) -> Vec<u64> {
+    to_limbs::<C>(list.iter().flat_map(|e| e.to_base_prime_field_elements()))
+}
+
+pub fn fp2_list_from_hex<C: Curve, const N: usize>(coeffs: &[String]) -> [Fp2Of<C>; N] {
+    assert_eq!(coeffs.len(), 2 * N, "Expected {N} elements of Fp2");
+    std::array::from_fn(|i| fp2_from_hex::<C>(&coeffs[2 * i..2 * i + 2]))
+}
+
+/// a^e, with the zisklib convention that 0^e = 0 for every e
+pub fn exp<C: Curve>(a: &Fp12Of<C>, e: &[u64]) -> Fp12Of<C> {
+    if a.is_zero() {
+        return *a;
+    }
+    a.pow(e)
+}
+
+/// a⁻¹, with the zisklib convention that 0⁻¹ = 0
+pub fn inverse<C: Curve>(a: &Fp12Of<C>) -> Fp12Of<C> {
+    a.inverse().unwrap_or_default()
+}
+
+/// a · (1 + b₀·vw + b₁·v²w), the line evaluations of the Miller loop
+pub fn sparse_mul<C: Curve>(a: &Fp12Of<C>, b: &[Fp2Of<C>; 2]) -> Fp12Of<C> {
+    let mut sparse = Fp12Of::<C>::one();
+    sparse.c1.c1 = b[0];
+    sparse.c1.c2 = b[1];
+    *a * sparse
+}
+
+/// Final exponentiation a^((p¹² - 1) / r), computed straight from its definition
+pub fn final_exp<C: Curve>(a: &Fp12Of<C>) -> Fp12Of<C> {
+    let p: BigUint = FpOf::<C>::MODULUS.into();
+    let r: BigUint = C::Fr::MODULUS.into();
+    let e = (p.pow(12) - 1u32) / r;
+    exp::<C>(a, &e.to_u64_digits())
+}
+
+/// Karabina compression of an element a = Σ gᵢwⁱ of the cyclotomic subgroup, keeping
+/// g₁ = c1.c0, g₄ = c0.c2, g₂ = c0.c1 and g₅ = c1.c2 in the order of the guests
+pub fn compress<C: Curve>(a: &Fp12Of<C>) -> [Fp2Of<C>; 4] {
+    [a.c1.c0, a.c0.c2, a.c0.c1, a.c1.c2]
+}
+
+/// Karabina decompression, recovering g₀ = c0.c0 and g₃ = c1.c1 from the compressed coefficients
+pub fn decompress<C: Curve>(compressed: &[Fp2Of<C>; 4]) -> Fp12Of<C> {
+    let [g1, g4, g2, g5] = *compressed;
+    let nonresidue = <Fp6ConfigOf<C> as Fp6Config>::mul_fp2_by_nonresidue;
+
+    // g₃ = (ξ·g₅² + 3·g₂² - 2·g₄) / (4·g₁), or 2·g₂·g₅ / g₄ when g₁ = 0
+    let g3 = if g1.is_zero() {
+        (g2 * g5).double() * g4.inverse().expect("g₄ is non-zero when g₁ = 0")
+    } else {
+        let num = nonresidue(g5.square()) + g2.square().double() + g2.square() - g4.double();
+        num * g1.double().double().inverse().expect("g₁ is non-zero")
+    };
+
+    // g₀ = ξ·(2·g₃² + g₁·g₅ - 3·g₂·g₄) + 1
+    let g2g4 = g2 * g4;
+    let g0 = nonresidue(g3.square().double() + g1 * g5 - g2g4.double() - g2g4) + Fp2Of::<C>::one();
+
+    let mut a = Fp12Of::<C>::zero();
+    a.c0.c0 = g0;
+    a.c0.c1 = g2;
+    a.c0.c2 = g4;
+    a.c1.c0 = g1;
+    a.c1.c1 = g3;
+    a.c1.c2 = g5;
+    a
+}
@@ -0,0 +1,308 @@
+//! Compares the steps of the revm u256 wrappers on the zisklib functions and on the ruint fallback.
+//!
+//! It builds the revm guest twice with the `profile` feature, once as is and once with
+//! `ruint-fallback`, runs both on the u256 suites (`revm/inputs/input_u256.bin`) with `ziskemu -X`
+//! and sums, per wrapper, the steps of the emulator reports for its profile tag. A function
+//! regresses when its zisklib steps exceed `threshold` percent of its ruint steps, i.e. when its
+//! zisklib path no longer pays off.
+//!
+//! The wrappers are the `profile_block!` tags of `zisk-programs/revm/src/u256`, read from the
+//! sources. Any other profile tag of the revm guest reported in a run is not compared, and fails
+//! the run so that it gets noticed. The costs are sums over the run, so they do not depend on
+//! whether the emulator reports a tag after every call or once with its number of calls; neither
+//! layout has been checked against a captured `ziskemu -X` log yet.
+
+use clap::Parser;
+use emu_stats::{number_after, steps_after, tokens};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    process::{exit, Command},
+};
+
+const ELF_PATH: &str = "target/elf/riscv64ima-zisk-zkvm-elf/release/revm";
+
+#[derive(Parser)]
+struct Args {
+    /// Prebuilt revm ELF with `--features profile`, instead of building it
+    #[arg(long, requires = "ruint_elf")]
+    zisklib_elf: Option<PathBuf>,
+
+    /// Prebuilt revm ELF with `--features profile,ruint-fallback`, instead of building it
+    #[arg(long, requires = "zisklib_elf")]
+    ruint_elf: Option<PathBuf>,
+
+    /// Guest builder binary
+    #[arg(long, default_value = "cargo-zisk")]
+    cargo_zisk: PathBuf,
+
+    /// Emulator binary
+    #[arg(long, default_value = "ziskemu")]
+    ziskemu: PathBuf,
+
+    /// Maximum zisklib steps of a function, in percent of its ruint steps
+    #[arg(long, short, default_value_t = 100.0)]
+    threshold: f64,
+
+    /// Write the comparison as CSV to this path
+    #[arg(long, short)]
+    csv: Option<PathBuf>,
+}
+
+/// Steps of one function over a run, and the number of calls they add up
+#[derive(Default, Clone, Copy)]
+struct Cost {
+    calls: u64,
+    steps: u64,
+}
+
+struct Comparison {
+    function: String,
+    zisklib: Option<Cost>,
+    ruint: Option<Cost>,
+}
+
+impl Comparison {
+    /// zisklib steps in percent of the ruint steps
+    fn ratio(&self) -> Option<f64> {
+        let (zisklib, ruint) = (self.zisklib?, self.ruint?);
+        Some(zisklib.steps as f64 * 100.0 / ruint.steps.max(1) as f64)
+    }
+
+    /// Why the function fails the comparison, if it does
+    fn failure(&self, threshold: f64) -> Option<String> {
+        let (Some(zisklib), Some(ruint)) = (self.zisklib, self.ruint) else {
+            return Some("missing from a run".to_string());
+        };
+        if zisklib.calls != ruint.calls {
+            return Some(format!("{} zisklib calls, {} ruint", zisklib.calls, ruint.calls));
+        }
+        let ratio = self.ratio().unwrap();
+        (ratio > threshold).then(|| format!("regressed, above {threshold}%"))
+    }
+}
+
+fn fmt_option(value: Option<u64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Tags of the `profile_macro!` invocations in `source`, such as `profile_block!(checked_add, {`
+fn profile_tags<'a>(source: &'a str, profile_macro: &str) -> Vec<&'a str> {
+    let pattern = format!("{profile_macro}!(");
+    source
+        .split(pattern.as_str())
+        .skip(1)
+        .filter_map(|rest| {
+            let rest = rest.trim_start();
+            let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))?;
+            // Macro definitions pass `$tag` on
+            rest[..end].starts_with(|c: char| c.is_ascii_alphabetic()).then(|| &rest[..end])
+        })
+        .collect()
+}
+
+/// Tags of the `profile_macro!` invocations of the `.rs` files under `dir`
+fn source_tags(dir: &Path, profile_macros: &[&str]) -> BTreeSet<String> {
+    let mut tags = BTreeSet::new();
+    let entries =
+        fs::read_dir(dir).unwrap_or_else(|e| panic!("Failed to read {}: {e}", dir.display()));
+    for entry in entries {
+        let path = entry.expect("Failed to read a directory entry").path();
+        if path.is_dir() {
+            tags.extend(source_tags(&path, profile_macros));
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            let source = fs::read_to_string(&path).expect("Failed to read a source file");
+            for profile_macro in profile_macros {
+                tags.extend(profile_tags(&source, profile_macro).into_iter().map(str::to_string));
+            }
+        }
+    }
+    tags
+}
+
+/// Costs of the profile tags of `tags` reported in `output`: the steps of every line that names
+/// the tag and carries a number right after `steps`, summed per tag. A line counts the calls right
+/// after its `calls` token, if it has one, so that a report per call and a report per tag with
+/// its number of calls add up alike; otherwise it counts as one call
+fn parse_costs(output: &str, tags: &BTreeSet<String>) -> BTreeMap<String, Cost> {
+    let mut costs = BTreeMap::<String, Cost>::new();
+    for line in output.lines() {
+        let tokens = tokens(line);
+        let Some(steps) = steps_after(&tokens) else {
+            continue;
+        };
+        let Some(tag) = tokens.iter().find(|t| tags.contains(**t)) else {
+            continue;
+        };
+        let cost = costs.entry(tag.to_string()).or_default();
+        cost.calls += number_after(&tokens, "calls").unwrap_or(1);
+        cost.steps += steps;
+    }
+    costs
+}
+
+/// Builds the revm guest with `features` and copies its ELF to `elf`, before the next build
+/// overwrites it
+fn build(args: &Args, programs_dir: &Path, features: &str, elf: &Path) {
+    println!("Building revm with --features {features}");
+    let status = Command::new(&args.cargo_zisk)
+        .current_dir(programs_dir)
+        .args(["build", "--release", "-p", "revm", "--features", features])
+        .status()
+        .unwrap_or_else(|e| panic!("Failed to run {}: {e}", args.cargo_zisk.display()));
+    assert!(status.success(), "Failed to build revm with --features {features}");
+    fs::copy(programs_dir.join(ELF_PATH), elf).expect("Failed to copy the revm ELF");
+}
+
+fn run(args: &Args, elf: &Path, input: &Path, tags: &BTreeSet<String>) -> BTreeMap<String, Cost> {
+    println!("Running {}", elf.display());
+    let result = Command::new(&args.ziskemu)
+        .arg("--elf")
+        .arg(elf)
+        .arg("--inputs")
+        .arg(input)
+        .arg("-X")
+        .output()
+        .unwrap_or_else(|e| panic!("Failed to run {}: {e}", args.ziskemu.display()));
+    if !result.status.success() {
+        eprintln!("{} failed on {}", args.ziskemu.display(), elf.display());
+        eprintln!("{}", String::from_utf8_lossy(&result.stderr));
+        exit(1);
+    }
+    parse_costs(&String::from_utf8_lossy(&result.stdout), tags)
+}
+
+fn write_csv(path: &PathBuf, comparisons: &[Comparison]) {
+    let mut out = String::from("function,zisklib_calls,zisklib_steps,ruint_calls,ruint_steps\n");
+    for c in comparisons {
+        out.push_str(&format!(
+            "{},{},{},{},{}\n",
+            c.function,
+            fmt_option(c.zisklib.map(|z| z.calls)),
+            fmt_option(c.zisklib.map(|z| z.steps)),
+            fmt_option(c.ruint.map(|r| r.calls)),
+            fmt_option(c.ruint.map(|r| r.steps))
+        ));
+    }
+    fs::write(path, out).expect("Failed to write CSV");
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let tool_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let current_dir = tool_dir
+        .parent() // → tools/
+        .and_then(|p| p.parent()) // → zisk-testvectors
+        .unwrap();
+    let programs_dir = current_dir.join("zisk-programs");
+
+    let (zisklib_elf, ruint_elf) = match (&args.zisklib_elf, &args.ruint_elf) {
+        (Some(zisklib), Some(ruint)) => (zisklib.clone(), ruint.clone()),
+        _ => {
+            let elf_dir = std::env::temp_dir().join("u256-cost");
+            fs::create_dir_all(&elf_dir).expect("Failed to create ELF directory");
+            let (zisklib, ruint) = (elf_dir.join("revm-zisklib"), elf_dir.join("revm-ruint"));
+            build(&args, &programs_dir, "profile", &zisklib);
+            build(&args, &programs_dir, "profile,ruint-fallback", &ruint);
+            (zisklib, ruint)
+        }
+    };
+
+    let input = programs_dir.join("revm/inputs/input_u256.bin");
+    assert!(input.exists(), "Missing {}, build the revm guest first", input.display());
+
+    // Every profile tag of the guest is parsed, to catch the ones that are not compared
+    let revm_src = programs_dir.join("revm/src");
+    let functions = source_tags(&revm_src.join("u256"), &["profile_block"]);
+    assert!(!functions.is_empty(), "No profile_block! tags in {}", revm_src.display());
+    let tags = source_tags(&revm_src, &["profile_block", "profile_call"]);
+
+    let zisklib = run(&args, &zisklib_elf, &input, &tags);
+    let ruint = run(&args, &ruint_elf, &input, &tags);
+    let comparisons: Vec<Comparison> = functions
+        .iter()
+        .map(|function| Comparison {
+            function: function.clone(),
+            zisklib: zisklib.get(function).copied(),
+            ruint: ruint.get(function).copied(),
+        })
+        .collect();
+    let not_compared: BTreeSet<&String> =
+        zisklib.keys().chain(ruint.keys()).filter(|tag| !functions.contains(*tag)).collect();
+
+    println!(
+        "\n{:<18}  {:>7}  {:>12}  {:>12}  {:>8}  CHECK",
+        "FUNCTION", "CALLS", "ZISKLIB", "RUINT", "RATIO"
+    );
+    let mut failures = 0;
+    for c in &comparisons {
+        let failure = c.failure(args.threshold);
+        println!(
+            "{:<18}  {:>7}  {:>12}  {:>12}  {:>8}  {}",
+            c.function,
+            fmt_option(c.zisklib.map(|z| z.calls)),
+            fmt_option(c.zisklib.map(|z| z.steps)),
+            fmt_option(c.ruint.map(|r| r.steps)),
+            c.ratio().map(|r| format!("{r:.1}%")).unwrap_or_default(),
+            failure.as_deref().unwrap_or("ok")
+        );
+        failures += failure.is_some() as usize;
+    }
+
+    if let Some(csv) = &args.csv {
+        write_csv(csv, &comparisons);
+        println!("\nWrote {}", csv.display());
+    }
+
+    if !not_compared.is_empty() {
+        eprintln!("\nProfile tags reported but not compared, outside of revm/src/u256:");
+        for tag in &not_compared {
+            eprintln!("  - {tag}");
+        }
+    }
+    if failures > 0 || !not_compared.is_empty() {
+        eprintln!("\n{failures} of {} functions fail the comparison", comparisons.len());
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_tags_reads_the_invocations_only() {
+        let source =
+            "macro_rules! profile_block {\n    ($tag:ident) => { profile_block!($tag, {}) };\n}\n\
+                      fn a() { profile_block!(checked_add, { 1 }) }\n\
+                      fn b() { profile_call!(\n        wrapping_sub,\n        \"suite\", 2) }\n";
+        assert_eq!(profile_tags(source, "profile_block"), ["checked_add"]);
+        assert_eq!(profile_tags(source, "profile_call"), ["wrapping_sub"]);
+    }
+
+    #[test]
+    fn parse_costs_sums_the_reports_of_each_tag() {
+        let tags: BTreeSet<String> = ["checked_add", "div_rem"].map(String::from).into();
+        let output = "process_rom() steps=9000\n\
+                      checked_add steps=412\n\
+                      div_rem: steps: 1000 calls: 1\n\
+                      checked_add steps=400\n\
+                      wrapping_add steps=5\n";
+        let costs = parse_costs(output, &tags);
+        assert_eq!(costs.len(), 2);
+        assert_eq!((costs["checked_add"].calls, costs["checked_add"].steps), (2, 812));
+        assert_eq!((costs["div_rem"].calls, costs["div_rem"].steps), (1, 1000));
+    }
+
+    #[test]
+    fn parse_costs_counts_the_calls_of_a_report_per_tag() {
+        let tags: BTreeSet<String> = ["checked_add"].map(String::from).into();
+        let per_call = "checked_add steps=412\nchecked_add steps=400\nchecked_add steps=420\n";
+        let per_tag = "checked_add: steps: 1232 calls: 3\n";
+        let (per_call, per_tag) = (parse_costs(per_call, &tags), parse_costs(per_tag, &tags));
+        assert_eq!((per_call["checked_add"].calls, per_call["checked_add"].steps), (3, 1232));
+        assert_eq!((per_tag["checked_add"].calls, per_tag["checked_add"].steps), (3, 1232));
+    }
+}
@@ -0,0 +1,185 @@
+use hash_input::{Iteration, Message};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const BLOCK_LEN: usize = 128;
+const OUTPUT_LEN: usize = 64;
+const MAX_KEY_LEN: usize = 64;
+
+/// Rounds of a BLAKE2b compression
+const ROUNDS: u32 = 12;
+
+const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+unsafe extern "C" {
+    // This gets linked to the ziskos blake2b compression (the EIP-152 F function)
+    fn blake2b_compress_c(rounds: u32, h: *mut u64, m: *const u64, t: *const u64, f: u8);
+}
+
+/// BLAKE2b compression of a message block `m` into `h`, with the byte counter `t` and the last
+/// block flag `f`
+fn compress(h: &mut [u64; 8], m: &[u64; 16], t: [u64; 2], f: bool) {
+    unsafe { blake2b_compress_c(ROUNDS, h.as_mut_ptr(), m.as_ptr(), t.as_ptr(), f as u8) };
+}
+
+/// BLAKE2b-512 of blake2b_simd, which the zisklib compression is checked against
+fn reference_blake2b_512(key: &[u8], personal: &[u8; 16], bytes: &[u8]) -> [u8; OUTPUT_LEN] {
+    let hash =
+        blake2b_simd::Params::new().hash_length(OUTPUT_LEN).key(key).personal(personal).hash(bytes);
+    hash.as_bytes().try_into().unwrap()
+}
+
+/// BLAKE2b-512 of `bytes` with an optional key (up to 64 bytes) and a personalisation, running
+/// every block through the zisklib compression
+fn blake2b_512(key: &[u8], personal: &[u8; 16], bytes: &[u8]) -> [u8; OUTPUT_LEN] {
+    assert!(key.len() <= MAX_KEY_LEN, "BLAKE2b key of {} bytes", key.len());
+
+    // Parameter block: digest length, key length, fanout and depth 1, no salt
+    let mut h = BLAKE2B_IV;
+    h[0] ^= 0x0101_0000 | (key.len() as u64) << 8 | OUTPUT_LEN as u64;
+    h[6] ^= u64::from_le_bytes(personal[..8].try_into().unwrap());
+    h[7] ^= u64::from_le_bytes(personal[8..].try_into().unwrap());
+
+    // A key is hashed as a first block of its own, padded with zeros
+    let mut block = [0u8; BLOCK_LEN];
+    let mut block_len = 0;
+    if !key.is_empty() {
+        block[..key.len()].copy_from_slice(key);
+        block_len = BLOCK_LEN;
+    }
+
+    // The last block is only compressed once the whole message is read, since it is flagged
+    let mut counter: u128 = 0;
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        if block_len == BLOCK_LEN {
+            counter += BLOCK_LEN as u128;
+            compress(&mut h, &words(&block), [counter as u64, (counter >> 64) as u64], false);
+            block_len = 0;
+        }
+        let len = (BLOCK_LEN - block_len).min(rest.len());
+        block[block_len..block_len + len].copy_from_slice(&rest[..len]);
+        block_len += len;
+        rest = &rest[len..];
+    }
+    block[block_len..].fill(0);
+    counter += block_len as u128;
+    compress(&mut h, &words(&block), [counter as u64, (counter >> 64) as u64], true);
+
+    let mut output = [0u8; OUTPUT_LEN];
+    for (bytes, word) in output.chunks_exact_mut(8).zip(h) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    output
+}
+
+// The block is copied into u64 words, so the message can be read at any alignment
+fn words(block: &[u8; BLOCK_LEN]) -> [u64; 16] {
+    let mut m = [0u64; 16];
+    for (word, bytes) in m.iter_mut().zip(block.chunks_exact(8)) {
+        *word = u64::from_le_bytes(bytes.try_into().unwrap());
+    }
+    m
+}
+
+// Hash a random message at a random offset, with a random key and personalisation, and compare
+// against blake2b_simd
+pub fn blake2b_apply(rng: &mut StdRng, iteration: &Iteration) {
+    let mut key = vec![0u8; rng.gen_range(0..=MAX_KEY_LEN)];
+    rng.fill(&mut key[..]);
+    let personal: [u8; 16] = rng.gen();
+
+    let message = Message::random(rng);
+    let hash = blake2b_512(&key, &personal, message.bytes());
+    let expected = reference_blake2b_512(&key, &personal, message.bytes());
+
+    assert!(
+        hash == expected,
+        "BLAKE2B mismatch on {} bytes at offset {} with a {}-byte key at iteration {} of seed {}: \n  expected: {}\n     found: {}",
+        message.bytes().len(),
+        message.offset(),
+        key.len(),
+        iteration.index,
+        iteration.seed,
+        hex::encode(expected),
+        hex::encode(hash)
+    );
+}
+
+pub fn run_consistency_test() {
+    // RFC 7693 appendix A, and the first keyed vectors of the reference blake2b-kat.txt (key
+    // 0x00..0x3f, message 0x00..)
+    let kat_key: Vec<u8> = (0..64).collect();
+    let vectors: [(&[u8], &[u8], &str); 4] = [
+        (&[], b"", "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"),
+        (&[], b"abc", "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"),
+        (&kat_key, b"", "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"),
+        (&kat_key, &[0x00], "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd"),
+    ];
+    for (key, message, expected) in vectors {
+        check_kat(key, &[0; 16], message, expected);
+    }
+
+    // Personalised vectors, from CPython's hashlib.blake2b (the reference C implementation) and
+    // matching blake2b_simd: the Equihash (200, 9) personalisation of Zcash, "ZcashPoW" followed
+    // by n and k in little endian, then other personalisations with and without a key
+    let equihash: [u8; 16] = *b"ZcashPoW\xc8\x00\x00\x00\x09\x00\x00\x00";
+    let kat_message: Vec<u8> = (0..255).collect();
+    let personalised: [(&[u8], &[u8; 16], &[u8], &str); 4] = [
+        (&[], &equihash, b"", "c99d68f469be358bacea1c64925a1ff2c9fb36e189bb2d90445412d388451402d4c4fbef0e40cbca80b95b49a3ce3bc7c1cff85201964b093eefb199cb460ecd"),
+        (&[], &equihash, b"abc", "e7ef81ba7fd3a4bb999f0ef0df4b71c606c843066a3a0d5fdf4ef5c8584dff65bad2feae0f56dbde2125ef5a8d5fe566b554aefd127a503d7883da4c5b23a9ca"),
+        (&[], b"personalisation!", &kat_message, "5719f6854946019fdc3dfaab89b74301ac1002018712eebf435e53b821ec0cb0f8d7a9d7c1309fcad56cc5ce3afb3b8ef09221e9b320d97c96946b9956b48587"),
+        (&kat_key[..32], b"ZcashPoWParams__", b"", "d3f5037511b60743ef2b8d87bb9c5406d983da1fc9f4250ea35ef56435449fd5396369afa95f645aa7c0aaf9bee7f7d9e77adda87ebcc92a201d75c1662f0a1b"),
+    ];
+    for (key, personal, message, expected) in personalised {
+        check_kat(key, personal, message, expected);
+    }
+
+    // Lengths around the block size, where the last block changes, read at every alignment,
+    // without and with a key and a personalisation
+    let buffer: Vec<u8> = (0..3 * BLOCK_LEN + 8).map(|i| i as u8).collect();
+    let params: [(&[u8], &[u8; 16]); 4] = [
+        (&[], &[0; 16]),
+        (&kat_key[..1], &[0; 16]),
+        (&kat_key[..32], b"ZcashPoWParams__"),
+        (&kat_key, b"personalisation!"),
+    ];
+    for (key, personal) in params {
+        for len in [0, 1, BLOCK_LEN - 1, BLOCK_LEN, BLOCK_LEN + 1, 2 * BLOCK_LEN, 3 * BLOCK_LEN] {
+            for offset in 0..8 {
+                let message = &buffer[offset..offset + len];
+                let hash = blake2b_512(key, personal, message);
+                let expected = reference_blake2b_512(key, personal, message);
+                assert!(
+                    hash == expected,
+                    "BLAKE2B mismatch on {len} bytes at offset {offset} with a {}-byte key: \n  expected: {}\n     found: {}",
+                    key.len(),
+                    hex::encode(expected),
+                    hex::encode(hash)
+                );
+            }
+        }
+    }
+}
+
+fn check_kat(key: &[u8], personal: &[u8; 16], message: &[u8], expected: &str) {
+    let hash = blake2b_512(key, personal, message);
+    assert!(
+        hex::encode(hash) == expected,
+        "BLAKE2B mismatch on {} with a {}-byte key and personalisation {}: \n  expected: {}\n     found: {}",
+        hex::encode(message),
+        key.len(),
+        hex::encode(personal),
+        expected,
+        hex::encode(hash)
+    );
+}
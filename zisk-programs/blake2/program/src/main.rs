@@ -1,37 +1,27 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
+mod blake2b;
+
+use hash_input::{HashInput, Iteration};
+use rand::rngs::StdRng;
 use rand::Rng;
 
 use precompiles_helpers::blake2b_round;
 use ziskos::syscalls::{syscall_blake2b_round, SyscallBlake2bRoundParams};
 
-const ACTIVATE_CONSISTENCY_TEST: bool = false;
+use crate::blake2b::blake2b_apply;
 
 fn main() {
-    // Get the input from ziskos
-    let num_blake2s: u64 = ziskos::io::read();
-
-    let mut rng = rand::thread_rng();
+    // Get the mode, the PRNG seed and the iterations to run from ziskos
+    let input: HashInput = ziskos::io::read();
 
-    if ACTIVATE_CONSISTENCY_TEST {
-        println!("Running BLAKE2 consistency test for {} times", num_blake2s);
-    } else {
-        println!("Running BLAKE2 random tests for {} times", num_blake2s);
-    }
-
-    for _ in 0..num_blake2s {
-        if ACTIVATE_CONSISTENCY_TEST {
-            run_consistency_test();
-        } else {
-            blake2_apply(&mut rng);
-        }
-    }
+    input.run("BLAKE2", run_consistency_test, blake2_apply, Some(blake2b_apply));
 }
 
 // Take any number and apply the blake2 function
 #[allow(deprecated)]
-fn blake2_apply(rng: &mut rand::rngs::ThreadRng) {
+fn blake2_apply(rng: &mut StdRng, iteration: &Iteration) {
     let index: u64 = rng.gen_range(0..10);
 
     let mut state = [0u64; 16];
@@ -49,12 +39,19 @@ fn blake2_apply(rng: &mut rand::rngs::ThreadRng) {
     let mut params = SyscallBlake2bRoundParams { index, state: &mut state, input: &input };
     syscall_blake2b_round(&mut params);
 
+    // Benchmark runs only measure the syscall
+    if !iteration.check {
+        return;
+    }
+
     // Compare against a tested blake2 implementation
     blake2b_round(&mut state_copy, &input, index as u32);
 
     assert!(
         state == state_copy,
-        "BLAKE2 state mismatch: \n  expected: {:x?}\n     found: {:x?}",
+        "BLAKE2 state mismatch at iteration {} of seed {}: \n  expected: {:x?}\n     found: {:x?}",
+        iteration.index,
+        iteration.seed,
         state_copy,
         state
     );
@@ -109,4 +106,6 @@ fn run_consistency_test() {
         EXPECTED_RESULT,
         state
     );
+
+    blake2b::run_consistency_test();
 }
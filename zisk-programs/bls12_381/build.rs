@@ -2,24 +2,28 @@ use std::fs;
 use std::io;
 use std::path::Path;
 
-const OUTPUT_DIR: &str = "../inputs";
+const OUTPUT_DIR: &str = "inputs";
 
 fn main() -> io::Result<()> {
-    let num_sha256fs: u64 = 1;
-
     // Ensure the output directory exists
     let output_dir = Path::new(OUTPUT_DIR);
     if !output_dir.exists() {
         fs::create_dir_all(output_dir)?;
     }
 
-    // Create the file and write the inputs
-    let file_name = format!("input_sha256f_{}.bin", num_sha256fs);
+    // Quick pass over a few random elements
+    write_input(output_dir, "input_smoke.bin", 0, 2, 2);
+
+    // Long run over many random elements
+    write_input(output_dir, "input_soak.bin", 0, 256, 8);
+
+    Ok(())
+}
+
+fn write_input(output_dir: &Path, file_name: &str, seed: u64, iterations: u64, max_pairs: u64) {
     let file_path = output_dir.join(file_name);
 
     let stdin = zisk_sdk::ZiskStdin::new();
-    stdin.write(&num_sha256fs);
+    stdin.write(&(seed, iterations, max_pairs));
     stdin.save(&file_path).expect("Failed to write input to file");
-
-    Ok(())
 }
@@ -0,0 +1,182 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use ziskos::zisklib::{
+    add_twist_bls12_381, exp_fp12_bls12_381, mul_fp12_bls12_381, neg_twist_bls12_381,
+    pairing_bls12_381, pairing_check_bls12_381, scalar_mul_bls12_381, scalar_mul_twist_bls12_381,
+};
+
+use crate::constants::{G1, G2, P};
+
+/// Error codes of zisklib's `pairing_check_bls12_381`, in the order of its checks: those of
+/// `pairing_check_bn254` (see `bn254/src/bilinearity.rs`), with the G1 subgroup check after the
+/// G1 curve check
+const G1_INVALID: u8 = 2;
+const G1_NOT_ON_CURVE: u8 = 3;
+const G1_NOT_IN_SUBGROUP: u8 = 4;
+const G2_INVALID: u8 = 5;
+const G2_NOT_ON_CURVE: u8 = 6;
+const G2_NOT_IN_SUBGROUP: u8 = 7;
+
+/// Point of E that is on the curve but not in G1
+const G1_NOT_IN_SUBGROUP_POINT: [u64; 12] = [
+    0xACC5_55C7_22AE_A803,
+    0x7144_2FBD_0AD6_2391,
+    0x1936_0C2B_6D09_29BB,
+    0x728B_874B_86B6_3ECF,
+    0x7570_F7DC_170E_554D,
+    0x038E_4078_BB8D_A22C,
+    0xD758_4BB0_FA45_8DF4,
+    0xE52E_A245_32FC_73E4,
+    0xE1B8_819F_3A60_5248,
+    0x28EF_C92E_D975_3FB9,
+    0xF0C3_4146_FEB9_D46B,
+    0x0BEC_328B_7B99_8BB4,
+];
+
+/// Point of the twist that is on the curve but not in G2
+const G2_NOT_IN_SUBGROUP_POINT: [u64; 24] = [
+    0x1CB4_583F_B5F6_6AC9,
+    0x2401_ED0B_7AF9_2F97,
+    0xE6A5_98E6_CC20_84CC,
+    0xFBB2_7674_6601_AE04,
+    0x5CAB_B19A_433B_8CA0,
+    0x03CA_68E3_5BC7_1B84,
+    0x0B09_5D11_927C_D6AA,
+    0xC5B8_3CB5_3EF8_DE34,
+    0x84FF_B6B1_8805_C913,
+    0xF5B5_99F9_2343_FCAD,
+    0x3155_569D_3603_1713,
+    0x19AB_05CA_588B_8FBC,
+    0x4F63_9BCB_9225_7C6F,
+    0x1591_B922_3611_D2B1,
+    0x509C_E2CD_A343_39D6,
+    0xD39C_C2C1_A1A3_7BBE,
+    0x30E8_3DD7_0166_6557,
+    0x1439_DAAA_F4AC_BE02,
+    0x5F1D_2856_6E10_AAE3,
+    0x3F9F_5918_CDF3_7A0C,
+    0x4903_B2F0_625B_8C0F,
+    0x64F2_9243_3A17_4E90,
+    0x11D9_BDB2_509A_FC12,
+    0x156F_D7B8_77DC_E4B3,
+];
+
+/// Random point of G1
+fn random_g1(rng: &mut StdRng) -> [u64; 12] {
+    let k: [u64; 4] = rng.gen();
+    scalar_mul_bls12_381(&G1, &k)
+}
+
+/// Random point of G2
+fn random_g2(rng: &mut StdRng) -> [u64; 24] {
+    let k: [u64; 4] = rng.gen();
+    scalar_mul_twist_bls12_381(&G2, &k)
+}
+
+/// Checks bilinearity and non-degeneracy of the pairing on `iterations` random inputs, and
+/// multi-pair `pairing_check_bls12_381` calls of up to `max_pairs` pairs, both valid and invalid
+pub fn bilinearity_tests(rng: &mut StdRng, iterations: u64, max_pairs: u64) {
+    let mut one = [0; 72];
+    one[0] = 1;
+
+    for _ in 0..iterations {
+        let (p, q) = (random_g1(rng), random_g2(rng));
+        let e = pairing_bls12_381(&p, &q);
+
+        // Non-degeneracy
+        assert_ne!(e, one, "e(P, Q) should not be 1");
+
+        // e(aP, bQ) = e(P, Q)^(ab), with a, b < 2^32 so that ab fits the exponent
+        let a = rng.gen_range(1..1u64 << 32);
+        let b = rng.gen_range(1..1u64 << 32);
+        let ap = scalar_mul_bls12_381(&p, &[a, 0, 0, 0]);
+        let bq = scalar_mul_twist_bls12_381(&q, &[b, 0, 0, 0]);
+        assert_eq!(
+            pairing_bls12_381(&ap, &bq),
+            exp_fp12_bls12_381(a * b, &e),
+            "e(aP, bQ) = e(P, Q)^(ab)"
+        );
+
+        // e([a]P + [b]P, Q) = e([a]P, Q) * e([b]P, Q), where [a]P + [b]P = [a + b]P
+        let bp = scalar_mul_bls12_381(&p, &[b, 0, 0, 0]);
+        let abp = scalar_mul_bls12_381(&p, &[a + b, 0, 0, 0]);
+        assert_eq!(
+            pairing_bls12_381(&abp, &q),
+            mul_fp12_bls12_381(&pairing_bls12_381(&ap, &q), &pairing_bls12_381(&bp, &q)),
+            "e(P1 + P2, Q) = e(P1, Q) * e(P2, Q)"
+        );
+
+        // e(P, Q1 + Q2) = e(P, Q1) * e(P, Q2)
+        let q2 = random_g2(rng);
+        assert_eq!(
+            pairing_bls12_381(&p, &add_twist_bls12_381(&q, &q2)),
+            mul_fp12_bls12_381(&e, &pairing_bls12_381(&p, &q2)),
+            "e(P, Q1 + Q2) = e(P, Q1) * e(P, Q2)"
+        );
+
+        multi_pair_check(rng, max_pairs);
+    }
+}
+
+/// Builds n random pairs ([a_i]G1, [b_i]G2) closed by ([sum a_i b_i]G1, -G2), whose product is 1,
+/// then checks the result and the errors when one of the pairs is corrupted
+fn multi_pair_check(rng: &mut StdRng, max_pairs: u64) {
+    let n = rng.gen_range(2..=max_pairs.max(2)) as usize;
+
+    let mut g1_points = Vec::with_capacity(n);
+    let mut g2_points = Vec::with_capacity(n);
+    let mut sum: u128 = 0;
+    for _ in 0..n - 1 {
+        let a = rng.gen_range(1..1u64 << 32);
+        let b = rng.gen_range(1..1u64 << 32);
+        sum += a as u128 * b as u128;
+        g1_points.push(scalar_mul_bls12_381(&G1, &[a, 0, 0, 0]));
+        g2_points.push(scalar_mul_twist_bls12_381(&G2, &[b, 0, 0, 0]));
+    }
+    g1_points.push(scalar_mul_bls12_381(&G1, &[sum as u64, (sum >> 64) as u64, 0, 0]));
+    g2_points.push(neg_twist_bls12_381(&G2));
+
+    let result =
+        pairing_check_bls12_381(&g1_points, &g2_points).expect("Valid pairs should succeed");
+    assert!(result, "{n} pairs closed by ([sum a_i b_i]G1, -G2) should multiply to 1");
+
+    // Closing with G2 instead gives e(G1, G2)^(2 sum a_i b_i) != 1
+    let mut wrong = g2_points.clone();
+    wrong[n - 1] = G2;
+    let result = pairing_check_bls12_381(&g1_points, &wrong).expect("Valid pairs should succeed");
+    assert!(!result, "{n} pairs closed by ([sum a_i b_i]G1, G2) should not multiply to 1");
+
+    // Corrupt a random pair
+    let i = rng.gen_range(0..n);
+
+    let mut bad_g1 = g1_points.clone();
+    bad_g1[i][6] ^= 1;
+    let result = pairing_check_bls12_381(&bad_g1, &g2_points);
+    assert_eq!(result, Err(G1_NOT_ON_CURVE), "G1 point not on curve at pair {i}");
+
+    let mut bad_g1 = g1_points.clone();
+    bad_g1[i][..6].copy_from_slice(&P);
+    let result = pairing_check_bls12_381(&bad_g1, &g2_points);
+    assert_eq!(result, Err(G1_INVALID), "G1 coordinate >= P at pair {i}");
+
+    let mut bad_g1 = g1_points.clone();
+    bad_g1[i] = G1_NOT_IN_SUBGROUP_POINT;
+    let result = pairing_check_bls12_381(&bad_g1, &g2_points);
+    assert_eq!(result, Err(G1_NOT_IN_SUBGROUP), "G1 point not in subgroup at pair {i}");
+
+    let mut bad_g2 = g2_points.clone();
+    bad_g2[i][12] ^= 1;
+    let result = pairing_check_bls12_381(&g1_points, &bad_g2);
+    assert_eq!(result, Err(G2_NOT_ON_CURVE), "G2 point not on curve at pair {i}");
+
+    let mut bad_g2 = g2_points.clone();
+    bad_g2[i][18..].copy_from_slice(&P);
+    let result = pairing_check_bls12_381(&g1_points, &bad_g2);
+    assert_eq!(result, Err(G2_INVALID), "G2 coordinate >= P at pair {i}");
+
+    let mut bad_g2 = g2_points;
+    bad_g2[i] = G2_NOT_IN_SUBGROUP_POINT;
+    let result = pairing_check_bls12_381(&g1_points, &bad_g2);
+    assert_eq!(result, Err(G2_NOT_IN_SUBGROUP), "G2 point not in subgroup at pair {i}");
+}
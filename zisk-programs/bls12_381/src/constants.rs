@@ -1,5 +1,4 @@
 /// Base field size
-#[allow(dead_code)]
 pub const P: [u64; 6] = [
     0xB9FEFFFFFFFFAAAB,
     0x1EABFFFEB153FFFF,
@@ -9,6 +8,13 @@ pub const P: [u64; 6] = [
     0x1A0111EA397FE69A,
 ];
 
+/// Scalar field size
+pub const R: [u64; 4] =
+    [0xFFFFFFFF00000001, 0x53BDA402FFFE5BFE, 0x3339D80809A1D805, 0x73EDA753299D7D48];
+
+/// Absolute value of the curve parameter x = -0xD201000000010000
+pub const X_ABS: u64 = 0xD201000000010000;
+
 /// Generator of G1
 pub const G1: [u64; 12] = [
     0xFB3A_F00A_DB22_C6BB,
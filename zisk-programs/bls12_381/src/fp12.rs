@@ -5,13 +5,6 @@ use ziskos::zisklib::{
 };
 
 pub fn fp12_tests() {
-    let zero = [0; 72];
-    let one = {
-        let mut one = zero;
-        one[0] = 1;
-        one
-    };
-
     // Multiplication
     let a = [
         0x22771B6FA5020290,
@@ -19,13 +12,13 @@ pub fn fp12_tests() {
         0x88388517389F7D8E,
         0xE781B16DA0A46D21,
         0xD9F48FF2A453BA47,
-        0x3A6C486A4A5D670,
-        0xCC9BBB4C6C65B4C,
+        0x03A6C486A4A5D670,
+        0x0CC9BBB4C6C65B4C,
         0x5E4731F6CBBCDC89,
-        0x4E716A7169E1D0B,
+        0x04E716A7169E1D0B,
         0x168750C8A6D3C395,
         0x8FAEFE566EAF0134,
-        0xECD4F8FDBC2859C,
+        0x0ECD4F8FDBC2859C,
         0x8ACB2BA3C558F0F8,
         0x74B5511B928BAE37,
         0x1F23C1F9746F70DA,
@@ -39,13 +32,13 @@ pub fn fp12_tests() {
         0x280646A408053A8E,
         0x14AD3BD0AC7839ED,
         0x5C078B80C2061CFB,
-        0x9C17F6F399AC541,
+        0x09C17F6F399AC541,
         0x54BBFAD6F6848F8A,
         0x34513B58D43DAB85,
         0xFECE7FFAC34A7BFB,
-        0x9CD7F2122DFB4C4,
+        0x09CD7F2122DFB4C4,
         0xD0774B7E9CC29484,
-        0x3823A3612B1BD23,
+        0x03823A3612B1BD23,
         0x280D779942FC7008,
         0xABAD2EF17A4FE249,
         0x9EAD5BFA77357E06,
@@ -61,19 +54,19 @@ pub fn fp12_tests() {
         0xD40036CF8849312C,
         0xD0E97BD9DCEEABD0,
         0x8458CBCB2BA592C9,
-        0xC74949F26CE302,
+        0x00C74949F26CE302,
         0x9BE99E472F44C1E0,
         0x4A064B70B18010DC,
         0xDEB45D5CD6416878,
         0x411638BBBBD153FA,
         0x7928ADD96A256803,
-        0x560D79A5C54126C,
+        0x0560D79A5C54126C,
         0x929502AE87FC8AEF,
         0x63D725797F2E464C,
         0x3A232056A6B45D8D,
         0x73241E0652D86AE7,
         0x1BE09103CDAED5C5,
-        0x19BDD7950C74174,
+        0x019BDD7950C74174,
         0xE09929D1D0476643,
         0x81F03EBA09E818DF,
         0xA41741FFE54256B0,
@@ -85,7 +78,7 @@ pub fn fp12_tests() {
         0x2BA30A47C3BBBDBF,
         0xE1E5CF021FA17F1F,
         0xEE4476F9A3FC8779,
-        0xC6A5F930DBC4398,
+        0x0C6A5F930DBC4398,
     ];
     let b = [
         0x1EE1EE8909F1191F,
@@ -93,7 +86,7 @@ pub fn fp12_tests() {
         0x9B83B4BD62AB3912,
         0x2BED13D83D019650,
         0x788ACD61B2FB94DC,
-        0xAFC0A063D5B08C6,
+        0x0AFC0A063D5B08C6,
         0x6D83CF3529FDE505,
         0x328C3BFA972FAEE1,
         0xC805A260DE99106F,
@@ -111,7 +104,7 @@ pub fn fp12_tests() {
         0x3E2E2FFD7987E19C,
         0x87A634C460257BB1,
         0xDC9A98783C7DD8C6,
-        0xE08E6F26DF392D4,
+        0x0E08E6F26DF392D4,
         0x2D8C161F8A0768C9,
         0x279FC1DE750D6221,
         0xC61AC3F3B05283EE,
@@ -123,7 +116,7 @@ pub fn fp12_tests() {
         0xFC379D3AB0C095B8,
         0xD660D4B28A88232B,
         0x762A6674DCCE2BD1,
-        0xBB43ADA1CC0A9A4,
+        0x0BB43ADA1CC0A9A4,
         0x9A3256F97B10C371,
         0x90DF3E259172E5D4,
         0x16F1DE014B73D2BA,
@@ -135,7 +128,7 @@ pub fn fp12_tests() {
         0xFA1B4BBE8E48CB1A,
         0x997F02224A78D697,
         0x34BE0CEF594EC311,
-        0x7EC6153D6A40C75,
+        0x07EC6153D6A40C75,
         0xCD0EC88EDC07BD06,
         0xEC231B7F62E240FB,
         0xCAFF0977B19B5A35,
@@ -147,19 +140,19 @@ pub fn fp12_tests() {
         0x956E0933A25D51A4,
         0x61BF5F7EE7983D30,
         0x3EA99B482A874C89,
-        0x71BF896063982EE,
+        0x071BF896063982EE,
         0x64F2F9A203C390FD,
-        0xF469984E41F58C1,
-        0x675C96074076A74,
+        0x0F469984E41F58C1,
+        0x0675C96074076A74,
         0xF0C49C85ABB34E1E,
         0x814BA608C6AFD4D3,
-        0xBFC72BD6B6D2D0E,
+        0x0BFC72BD6B6D2D0E,
         0xE2C879FC5A505F79,
         0x9E3809B7B6F65060,
         0xEEDF614481925D5C,
         0x2D1ECE2B41D27964,
         0x18D7DD0BF2FB32C2,
-        0xC057BAD39B09ADB,
+        0x0C057BAD39B09ADB,
     ];
     let res = mul_fp12_bls12_381(&a, &b);
     let res_exp = [
@@ -168,13 +161,13 @@ pub fn fp12_tests() {
         0xCA844FCE1D1181B3,
         0xFFD6FCEABAA939D0,
         0x1075023407C86DB9,
-        0xEA5C096A13272C8,
+        0x0EA5C096A13272C8,
         0x3E9B61EEAC02141E,
         0xB6CC27C705359319,
         0x74CC9E120E30BAB0,
         0x45E103B93D3668B4,
         0xC77D889D8371FD25,
-        0xE8AB87875C687A0,
+        0x0E8AB87875C687A0,
         0x5A4E434108CFD749,
         0xD2C489AA797EEFE8,
         0x1C95006DFD1513A8,
@@ -186,13 +179,13 @@ pub fn fp12_tests() {
         0xB8C9701E5A1A262B,
         0x5A859AD95801CAAE,
         0xD3BC4529FFF9F8A3,
-        0xC942D00C50AF337,
+        0x0C942D00C50AF337,
         0x672538AE4059E00A,
         0x55F390C21AE40596,
         0x601C60381842939D,
         0xF39DD382738F0489,
         0xC578E61C5B2FA1E1,
-        0x34ABAB07EC5C2E5,
+        0x034ABAB07EC5C2E5,
         0xEA6BEB7FB099FF83,
         0x6B07AA2FFF9EAD17,
         0x91E9DF01C955109C,
@@ -204,13 +197,13 @@ pub fn fp12_tests() {
         0x601318184994578B,
         0xC6878B5A9E374388,
         0x9FE420EC88AF2627,
-        0xFDFE4A8B64D7147,
+        0x0FDFE4A8B64D7147,
         0x6EDBA60A3582664C,
         0xD370CCFD6AE387DF,
         0xBE35371ACE9659AF,
-        0xED84FE4E5A0ABDD,
+        0x0ED84FE4E5A0ABDD,
         0x28F2EA4E59EA8A8D,
-        0x7B6365FCDD76B04,
+        0x07B6365FCDD76B04,
         0xDAD63DD76BA9607B,
         0xD6BAF53EB9D95A33,
         0xDA1BC5A953A56721,
@@ -228,7 +221,7 @@ pub fn fp12_tests() {
         0x3A16A5DE5C3326CD,
         0x22382FFDC936156F,
         0x2CB56991AC86E777,
-        0x9BC82BF673EF727,
+        0x09BC82BF673EF727,
         0xC82269C981BBE5E8,
         0xDAFC06B8244ABE57,
         0x4CC4C90CBE4E1D2A,
@@ -251,18 +244,18 @@ pub fn fp12_tests() {
         0x2968A16E150C6146,
         0x702E89B640585AA5,
         0x4FBA4F775C29AD9C,
-        0xB5881A6EEADDA2C,
+        0x0B5881A6EEADDA2C,
         0xD0260E0799508FCA,
         0x937FACE7C7719D50,
         0x180F1D26B9919293,
         0x6A360343F32B9141,
         0x55041D918B56E992,
-        0x1FA308271289B69,
+        0x01FA308271289B69,
         0x362EE4C8B181CB9A,
         0xC30E9F0501B84B9C,
         0x459533498CF791B8,
         0xD370A16258EEE6B4,
-        0xFBAE0D66000F1B6,
+        0x0FBAE0D66000F1B6,
         0x149E28F3C42D9DA7,
         0xA607C4AEFF0F5527,
         0xC0AB4D89C2BEEFF9,
@@ -275,19 +268,19 @@ pub fn fp12_tests() {
         0x549775D007A40A71,
         0x8DD783252002F674,
         0xC9DE4B267CA325A3,
-        0xA336FF3754E7388,
+        0x0A336FF3754E7388,
         0x62C22A810F2D5396,
         0x1CB12AF977EA2985,
         0xC56C44236278D7D3,
         0xABA7DB2D3F64C45E,
         0x73184F3EB7B13A7C,
-        0xAB31C2EDBEADF6D,
+        0x0AB31C2EDBEADF6D,
         0x3BC6637F97F1A5E8,
         0x2C1C2BD31425255D,
         0x56BE5022E654A548,
         0xDD3879CBFE7B523E,
         0x314718FE5717314A,
-        0x6B59286AB1A4025,
+        0x06B59286AB1A4025,
         0x89B360C03E8711D5,
         0x9BF9A7E561EFF503,
         0x73EFB4976598B0A5,
@@ -299,7 +292,7 @@ pub fn fp12_tests() {
         0xFDD3D883C78D6A78,
         0xD6F5DA42A6143CB6,
         0x67D81BCD1C85B007,
-        0xA88C37693427AAF,
+        0x0A88C37693427AAF,
         0x4B357EACF273273F,
         0x93BEF72653DDEFA9,
         0x8D2B3570916490F2,
@@ -308,60 +301,12 @@ pub fn fp12_tests() {
         0x106080BD177A5E5C,
         0x1584EF9B1DAEA143,
         0xE02AAF8E391F7196,
-        0x46A4797CACF6218,
+        0x046A4797CACF6218,
         0x44F83E2F88EC912C,
         0x40DFE21CB7FB5C6E,
         0x12CA4AD775C2F72E,
     ];
-    let b1 = [
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
+    let b = [
         0x2362B785C79D2603,
         0x3C96FCB3B4AB1357,
         0xBBFCB095964533A9,
@@ -379,43 +324,90 @@ pub fn fp12_tests() {
         0x1DD71CEE19FCC0F9,
         0xEFAF85FA8ED955B6,
         0x791E0248A7423E6F,
-        0x4942A39996FF976,
+        0x04942A39996FF976,
         0x54157EA1092E01C2,
         0x78E6CB5283F2EB22,
         0x5025AB37A6C5F180,
         0x813B665EE73C1376,
         0x10353ADDC9CDCD28,
-        0x5CD50C7254E93F6,
+        0x05CD50C7254E93F6,
     ];
-    let b2 = [
-        0x2362B785C79D2603,
-        0x3C96FCB3B4AB1357,
-        0xBBFCB095964533A9,
-        0x8542DBC12B4018CE,
-        0xBC9BF6D32B92E96A,
-        0x109C47D16491C171,
-        0xD1DA817D3E541B13,
-        0x38F4F7140F090BD8,
-        0xDE2397DCE2CE1ADE,
-        0xD09563B48373E8CE,
-        0x90099CF9ABC4987C,
-        0x13B6A36F81A2A862,
-        0x347392602EE20244,
-        0x91FA0ED0C2F21B43,
-        0x1DD71CEE19FCC0F9,
-        0xEFAF85FA8ED955B6,
-        0x791E0248A7423E6F,
-        0x4942A39996FF976,
-        0x54157EA1092E01C2,
-        0x78E6CB5283F2EB22,
-        0x5025AB37A6C5F180,
-        0x813B665EE73C1376,
-        0x10353ADDC9CDCD28,
-        0x5CD50C7254E93F6,
+    let res = sparse_mul_fp12_bls12_381(&a, &b);
+    let res_exp = [
+        0xBB74E0547E0540F3,
+        0xA82AA3F2DBB6C5C9,
+        0x7E80188D0BF6990C,
+        0x52D082F6739C0E5C,
+        0x9F90BE64586FBD4C,
+        0x02CAB0FBC6CDA93D,
+        0x64153FF367179140,
+        0xB72EAE20A0EB4EB5,
+        0x905D58F99635D750,
+        0xC9641294A5BA19A1,
+        0x28A563904FC5ABF7,
+        0x0CD2296B3B973A79,
+        0x52606A0CC66F2C51,
+        0xB119A7FE3961D594,
+        0x9F5D0D704044378E,
+        0x73010721FF692901,
+        0xF38587750E6E3EBA,
+        0x08021B7FF7A5C85F,
+        0xA36D4719A43C7D1D,
+        0xEF48ACC51C58B332,
+        0xFD89F8B65AC0E739,
+        0x6714940DA536BC77,
+        0xC7A3412926531BF2,
+        0x0AEFC182687BF84C,
+        0xF7411490FE36E4F9,
+        0xAA65892F6D3D3DAF,
+        0xB493F8EDF3BAB144,
+        0x8A1377861F739966,
+        0x3594792C48CE031E,
+        0x0B4D36449BB05629,
+        0xB13DFA53E8EAECEC,
+        0x816448B537D0E6B8,
+        0xE2AA8261B40FE923,
+        0x2BCBC1E1E0FDA711,
+        0xCAB2C4CDF223EBE1,
+        0x0A8BD41D2D2BB93C,
+        0x19E18DF68CBF940D,
+        0x618566E9C4C1D38E,
+        0x2540E16451560F98,
+        0x9CEE2AFEE8152455,
+        0xD724FFD1B9231C24,
+        0x1392370A5767D4AD,
+        0x0D8D8A225D3D7B5B,
+        0xAE30EAB65DBF7F8C,
+        0xE245F7971E29BFAC,
+        0xC99BF768F6C570F5,
+        0x88ABE18D3C57DBF1,
+        0x1176DC39DEE4F6C9,
+        0xB7F1EB19CFC19D0B,
+        0x7DD45AA68FF6A1C5,
+        0x662CC0AD3E900535,
+        0x97F9765E7BB8973C,
+        0x6BDACB8300BC5F43,
+        0x097163AAAC9017EA,
+        0xE5279ACAE21B7D99,
+        0x1DCC32E95B0D02C4,
+        0x4A26B0391E10BF1F,
+        0xF0733D617BE8CDB8,
+        0xEA09E42FE472A4B3,
+        0x13291EEB790C7E90,
+        0x4EB4B5538334F1BE,
+        0x6DFD1A0176059380,
+        0xDBD0DF54AE3D3E5F,
+        0x9F217D49F7DB393D,
+        0x623F857F04494AC8,
+        0x173D88CFA47E9B87,
+        0x903375B26A980FFA,
+        0x75CE567E486393CE,
+        0x678F6DB38F1FCA32,
+        0xA15868A40363B0E7,
+        0x9BB4BE506D667599,
+        0x022EE4B0621182C9,
     ];
-    let res_mul = mul_fp12_bls12_381(&a, &b1);
-    let res_exp = sparse_mul_fp12_bls12_381(&a, &b2);
-    assert_eq!(res_mul, res_exp);
+    assert_eq!(res, res_exp);
 
     // Squaring
     let a = [
@@ -433,7 +425,7 @@ pub fn fp12_tests() {
         0x16CB2DE1BEF4E850,
         0xA4001CD14EC51486,
         0x8A768B0F20B52C41,
-        0xB0D642408B69E0C,
+        0x0B0D642408B69E0C,
         0x6B264EE8BE54C821,
         0xB50B8BAAF3A77D1B,
         0x179D5511947514C7,
@@ -442,11 +434,11 @@ pub fn fp12_tests() {
         0xDBAB62437CFDAFEC,
         0xB829276DF6D08EEB,
         0xF8ADFE2BDABFA256,
-        0x9CE994CF02C2762,
+        0x09CE994CF02C2762,
         0xEC1220A3B5435895,
         0xCA5CE71D25C34A90,
-        0xFF6096EBA167C84,
-        0xAC58A76E45B3530,
+        0x0FF6096EBA167C84,
+        0x0AC58A76E45B3530,
         0x41B5A03537CA0D7E,
         0x177E035DCC13E992,
         0xE627D3B2D362A4CE,
@@ -460,19 +452,19 @@ pub fn fp12_tests() {
         0xEEFAB320EDFA5717,
         0x8DFF141F5C76C56C,
         0x26827B2E7C540893,
-        0x567042D897F0CA2,
+        0x0567042D897F0CA2,
         0x59B9A80F574BFDD7,
-        0x2A55EC7732CE593,
+        0x02A55EC7732CE593,
         0x862D01E0D1A52499,
         0x54060C066D1FB661,
         0xF401DF52BD0D8B2F,
-        0x49C93902539A3E0,
+        0x049C93902539A3E0,
         0xF2E7804670FDF554,
         0x84C1A6F5B96FE461,
-        0x5119D81ED5EE248,
+        0x05119D81ED5EE248,
         0x7D23022D1F5F7E1F,
         0x87D5CFAD5514269B,
-        0x4B824D15BC94B08,
+        0x04B824D15BC94B08,
         0x802E9BEDC337F4BD,
         0x42A691A095496471,
         0x16A617745AB3AE4A,
@@ -484,7 +476,7 @@ pub fn fp12_tests() {
         0x883E77467749AA2B,
         0xB9387A6264DA0215,
         0xF80F50671A0D8726,
-        0x1424AFBC196156,
+        0x001424AFBC196156,
         0xFA9A59F8BA3603BA,
         0x83ED6CEF8450A002,
         0xF1341781B2C0D274,
@@ -499,55 +491,55 @@ pub fn fp12_tests() {
         0xEF08E54CE732D5A5,
         0xA230D86CCEB32658,
         0xBD1916CAA7998C3C,
-        0xA74FA4313AC776C,
+        0x0A74FA4313AC776C,
         0xF336E3DA67ED1D86,
         0x95F7557F2B6966F9,
         0xC971251C44E1F77F,
         0xA124FFC8BB4294AC,
         0x83299AD440A76ABD,
-        0xBA7C93CF7632B4B,
+        0x0BA7C93CF7632B4B,
         0x8C00912D6E8CC8C9,
         0xAF8CF866403F9E84,
         0xE0BCA75D06DEA8AB,
         0x3F3BD89D27918057,
         0x1F8D72F5E6476E7B,
         0x1A00088CF622986E,
-        0xAF0854A0BFF1D3C,
+        0x0AF0854A0BFF1D3C,
         0x6CD8B77218F59264,
         0x53EB8AC9F6CFA03C,
         0xCB0406B3D67512B4,
         0x199472AEF59FBB8D,
-        0x969D4BC478B77F5,
+        0x0969D4BC478B77F5,
         0x942B2239AAF9DB3A,
         0x453394B2A8D0F5B5,
         0xEAF56150363ED1A6,
-        0x5ADB21C0A461E5A,
+        0x05ADB21C0A461E5A,
         0x72D5226F4CD30943,
         0x1901AADA38B9E90F,
         0xF9C2617B860BBD62,
-        0x9C5EA595DEBE909,
+        0x09C5EA595DEBE909,
         0x8FE93804D8CA29DC,
         0x673E99D933DF3F36,
         0x13AD811048726051,
-        0x4F26F05D3541743,
+        0x04F26F05D3541743,
         0x8084B299FB283A0E,
         0x792C748170EF4721,
         0x90D7194E2F0F9057,
         0x1B21929333A26FD5,
         0x83981F93912EA231,
-        0xA3C305564423761,
+        0x0A3C305564423761,
         0xFAC4EC48B283AE53,
         0x960A629445717360,
         0xCCB9300EB34F0B68,
         0x76EE0FE21CFCF55B,
         0x2FD019E9B84B39B1,
-        0x38DAD8BEA4E880E,
+        0x038DAD8BEA4E880E,
         0x8E51AAD4C5D942BE,
         0xF4A43A3B9C0FCC13,
         0x2F71104D8D6A158F,
         0x8CB3BFEACD507686,
         0x9868DD2A83B6885B,
-        0x49236B8F25824BD,
+        0x049236B8F25824BD,
         0xB7F647CC266E0F5E,
         0x389FF54934F3B802,
         0xEE326EB9BB35C595,
@@ -559,7 +551,7 @@ pub fn fp12_tests() {
         0xFCF6E6A8179ACED3,
         0x405C92E4573EB657,
         0x2F1D9E2B7CDF273B,
-        0xC0145F5707B6BE5,
+        0x0C0145F5707B6BE5,
         0xA78D1A029F5E3FB4,
         0xCCF335F90511A688,
         0x44570BDAE10A061A,
@@ -570,7 +562,7 @@ pub fn fp12_tests() {
     assert_eq!(res, res_exp);
 
     // Inversion
-    let a = zero;
+    let a = [0; 72];
     let res = inv_fp12_bls12_381(&a);
     let res_exp = a;
     assert_eq!(res, res_exp);
@@ -581,7 +573,7 @@ pub fn fp12_tests() {
         0x10A09239738D2355,
         0x9296ECC5E09CED36,
         0x4BF40226A2DD715E,
-        0x63FDB65EECBC2FA,
+        0x063FDB65EECBC2FA,
         0x8597D26F9B1909D2,
         0x874965CE3F7DA86E,
         0xE121847EAE8812A8,
@@ -593,12 +585,12 @@ pub fn fp12_tests() {
         0x72EFF31CB074A813,
         0xBA5A28C129ABC744,
         0xB2009CC6FA064777,
-        0xA72FC0BD341BB59,
+        0x0A72FC0BD341BB59,
         0x572DF093638AEBAF,
-        0xA6DAF7C7A2256C6,
+        0x0A6DAF7C7A2256C6,
         0x9142E59B91BDE765,
         0xA3D243FCE79AAE50,
-        0xE45A142B4BE8F72,
+        0x0E45A142B4BE8F72,
         0x16111EBDAC0AB7EF,
         0xB2E72828DB4CDDE7,
         0x2B901BDEB228F17B,
@@ -609,7 +601,7 @@ pub fn fp12_tests() {
         0xB2A641959AB3D255,
         0xE6B0FE20A170C8EE,
         0x917865982E39F717,
-        0xF3804463B1F2D49,
+        0x0F3804463B1F2D49,
         0xA5A0340A7B3EDA1B,
         0x19E14F7E7E904C9D,
         0xC6D33ADE9EB69D55,
@@ -629,28 +621,102 @@ pub fn fp12_tests() {
         0x45F95ABD4B7C3A72,
         0x7EDB40A923D0ED32,
         0x4ABE9847DAE8A171,
-        0x2BE56B88855A04,
+        0x002BE56B88855A04,
         0xC6F7CB4B33128BDA,
         0x27646233440D173B,
         0x2CC8A707CBFC3D24,
-        0xAA5E5DA0F2161B9,
+        0x0AA5E5DA0F2161B9,
         0x38EA45595F2E5A67,
-        0x8921FB75A97DEEE,
+        0x08921FB75A97DEEE,
         0x23EB4D96044818E7,
         0x32E23DF4B98A3E90,
         0x3447C799CA3FA126,
         0x515B963E5B5A64E4,
         0xE66B11CCCB3BFD03,
-        0x551914C661E29BC,
+        0x0551914C661E29BC,
         0x623FCD8968C99143,
         0x439DDCA9ED522CE8,
         0x8DFE1B6EA3B2FF7F,
         0x96FB74D8C9E951BB,
         0x59457AB7CE10183A,
-        0x35761333DD9053C,
+        0x035761333DD9053C,
     ];
     let res = inv_fp12_bls12_381(&a);
-    assert_eq!(mul_fp12_bls12_381(&a, &res), one);
+    let res_exp = [
+        0xD2F5E80034C79C1E,
+        0xE0F6775C3D79D250,
+        0x4264B5CF7794A23A,
+        0xDFFBA76C79DBE974,
+        0xD434DCC41F0C3B69,
+        0x140FA3BBD388ABAE,
+        0x9D00EF5A32CD2773,
+        0x73649CE444CBF259,
+        0x410B297DB68A426A,
+        0xD5BCBD433E094BED,
+        0x415BC0463942F4D0,
+        0x0CDB8FC4378EA503,
+        0xA3AD15F22D0899F6,
+        0x259A2013E0C7D3B5,
+        0x825892955A0B3283,
+        0xF76B5590D1BE3798,
+        0x890E8C0D55FEB6DA,
+        0x16CEA5D3ECD04C2B,
+        0x8679548814AA4131,
+        0xDB0B6DF295965EB0,
+        0x63A60709294772A3,
+        0xD68C624E9B8766CE,
+        0x68338EE3C1ADD541,
+        0x0EC7C6592A323D37,
+        0x3EB79E1DF32EC10B,
+        0x0A2BB2CFC7ED2A91,
+        0x3CEA34AA07EB0B98,
+        0x606BA38EED65E68A,
+        0x2E968D410CE6EDBB,
+        0x0EB96326DA13D9A8,
+        0x68576070884F8B15,
+        0x839E44895CEBD241,
+        0x02756E71CAAEC2DD,
+        0xF195FEB85D739DAB,
+        0xCCEFA220A7C92283,
+        0x06698F680DB4DC47,
+        0x6BDE75307650FA32,
+        0xFFE403B88CF5CB31,
+        0xF7C5C4A65EBB8A98,
+        0x06661CA104DDEE2C,
+        0x47C0201E4F48027A,
+        0x0CB721B4C006589F,
+        0x8D696E10ED964A37,
+        0xF9BD59214B808D32,
+        0xB3BAAD675A4CB87E,
+        0x83D8637437946F73,
+        0x01ACE0E44C08948D,
+        0x12A0FBD709562864,
+        0xF93BC01B5389AE27,
+        0xCEA3F15E3750ACAE,
+        0x7E4498B69700C3FB,
+        0xBFCACE7AB141CBB1,
+        0xCF120D33A6B54B99,
+        0x1911B64F038D08A7,
+        0x6508C193CFD72987,
+        0x00FDB039790FAB70,
+        0x422F2774C709D794,
+        0xD16EA34719D047B8,
+        0xF29C82B2C5EF2177,
+        0x1321F5C72F953B9A,
+        0x6CCCB72C16E53457,
+        0x76DD2900C9704FFC,
+        0x41EECD1FE6D60FA2,
+        0x5162D843531C9AB6,
+        0x64689F8094C4B3A4,
+        0x1698B577E6B33606,
+        0xEE56AEE0E6CF3F30,
+        0x16611359587A3995,
+        0xE5F58DA1B9C6E723,
+        0x54AFC09AA00B409A,
+        0xB580C6E6A350AFCE,
+        0x073D4411A2C142BE,
+    ];
+    assert_eq!(res, res_exp);
 
     // Conjugate
     let a = [
@@ -659,7 +725,7 @@ pub fn fp12_tests() {
         0x275B251F46FD434E,
         0x1894A147FF20CE94,
         0x24DB790390766C40,
-        0x625A32C59A22BB9,
+        0x0625A32C59A22BB9,
         0xF2222923683EF1FF,
         0xE591B1800B92C15F,
         0x2D6F8D92F2B89972,
@@ -673,17 +739,17 @@ pub fn fp12_tests() {
         0xA1144D738183EDC4,
         0x17965328ECD31B84,
         0x703882DFBDBBCA9A,
-        0x17F3E589E4E7E2C,
+        0x017F3E589E4E7E2C,
         0x9FCEAE3C1EEC20E4,
         0x3B3C5BF3E5184EA4,
         0xDE0B6095FEB51CF5,
         0x13A086F2A30BF76E,
-        0xD8811B95009053E,
-        0xBC1B65702CE3C09,
+        0x0D8811B95009053E,
+        0x0BC1B65702CE3C09,
         0x7B69F12BA38BE0A0,
         0x5B6DA2FF6A055CAA,
         0x7C7B04FCC693778A,
-        0xEB6186C8E382E10,
+        0x0EB6186C8E382E10,
         0x4A6B568C7090F684,
         0x6719DDEDE8F720ED,
         0xE8064D6788C4F601,
@@ -693,9 +759,9 @@ pub fn fp12_tests() {
         0xB41DA1ADBE20EE29,
         0xE4D589701F353985,
         0x4C3C40631BE9A2B2,
-        0x25CFDC14995F5C5,
+        0x025CFDC14995F5C5,
         0x75321DA3BD64763D,
-        0x973E949AAE5ACFC,
+        0x0973E949AAE5ACFC,
         0x9F59960E589F6631,
         0xED19E90F370CE5A9,
         0xA208703912B03103,
@@ -703,29 +769,29 @@ pub fn fp12_tests() {
         0x8C3E36262357656C,
         0x122F96F3BBFEEBA5,
         0xFD4797AD1D464412,
-        0xCB89A5A98BC83A7,
+        0x0CB89A5A98BC83A7,
         0x2057E5F72DC88C26,
         0x81EC3AC949A0777B,
         0x96764253C57AFB01,
-        0x76291FED53A8E89,
+        0x076291FED53A8E89,
         0x155F85EBC2E447F4,
         0x3B0EC0C96A0A1C87,
         0x7D8C52CA1951BB61,
         0x12A5DE79745C47E9,
         0x7AE1F6E9535CE83A,
-        0xD5953B0208FF5E,
+        0x00D5953B0208FF5E,
         0xAF305B2E0253327A,
-        0x1C524601825C513,
+        0x01C524601825C513,
         0x3A815BB5F680EF18,
         0xF82DCB4A69C9519B,
         0xC48C7B3E84D1A441,
-        0xBEB5C6753B72F96,
+        0x0BEB5C6753B72F96,
         0xACEB18264AB63C1A,
         0xB3117A8AE465E81C,
         0x59DCA49FEDBC7DE3,
         0xE2B2515DB63C0AF1,
         0xDD15D3A873A321E3,
-        0xBE6D4CDD3B2E55,
+        0x00BE6D4CDD3B2E55,
     ];
     let res = conjugate_fp12_bls12_381(&a);
     let res_exp = [
@@ -734,7 +800,7 @@ pub fn fp12_tests() {
         0x275B251F46FD434E,
         0x1894A147FF20CE94,
         0x24DB790390766C40,
-        0x625A32C59A22BB9,
+        0x0625A32C59A22BB9,
         0xF2222923683EF1FF,
         0xE591B1800B92C15F,
         0x2D6F8D92F2B89972,
@@ -748,24 +814,24 @@ pub fn fp12_tests() {
         0xA1144D738183EDC4,
         0x17965328ECD31B84,
         0x703882DFBDBBCA9A,
-        0x17F3E589E4E7E2C,
+        0x017F3E589E4E7E2C,
         0x9FCEAE3C1EEC20E4,
         0x3B3C5BF3E5184EA4,
         0xDE0B6095FEB51CF5,
         0x13A086F2A30BF76E,
-        0xD8811B95009053E,
-        0xBC1B65702CE3C09,
+        0x0D8811B95009053E,
+        0x0BC1B65702CE3C09,
         0x7B69F12BA38BE0A0,
         0x5B6DA2FF6A055CAA,
         0x7C7B04FCC693778A,
-        0xEB6186C8E382E10,
+        0x0EB6186C8E382E10,
         0x4A6B568C7090F684,
         0x6719DDEDE8F720ED,
         0xE8064D6788C4F601,
         0x25BEFBFF8F3E7489,
         0xBA6A7C583CB46050,
         0x16C458E32F26E4F9,
-        0x5E15E5241DEBC82,
+        0x05E15E5241DEBC82,
         0x39D6768E921EC67A,
         0x1AF4923DDAC75371,
         0x621A4DC3A9EF1CFA,
@@ -776,7 +842,7 @@ pub fn fp12_tests() {
         0xC5286267E400C520,
         0x467950133B9DAC85,
         0xBEDD71901FF4476B,
-        0x7D17AF67D80FAF4,
+        0x07D17AF67D80FAF4,
         0xBCB76852E2B96699,
         0x11F365A418977C57,
         0x46D8ECA9C8E869FE,
@@ -789,15 +855,15 @@ pub fn fp12_tests() {
         0x51D16D0B7F28CAD5,
         0xD039B0CCEFEEC49D,
         0x192B7CAF3776E73B,
-        0xACEA4D1FDAC7831,
+        0x0ACEA4D1FDAC7831,
         0x1CE6DB9E992E3AEC,
         0x2CAF76EB0030070C,
         0x6C49803A89BBC124,
         0x868F2C77BE7A0895,
-        0xE15B582E5C8B703,
-        0xD13E7D9B5496E91,
+        0x0E15B582E5C8B703,
+        0x0D13E7D9B5496E91,
         0x6B9A8573CCEE17E3,
-        0xD542E0108F47840,
+        0x0D542E0108F47840,
         0x81C4FA273D4907CE,
         0x6E05D40DCFA88AF3,
         0x1942A49D5C44B844,
@@ -820,7 +886,7 @@ pub fn fp12_tests() {
         0x16CB2DE1BEF4E850,
         0xA4001CD14EC51486,
         0x8A768B0F20B52C41,
-        0xB0D642408B69E0C,
+        0x0B0D642408B69E0C,
         0x6B264EE8BE54C821,
         0xB50B8BAAF3A77D1B,
         0x179D5511947514C7,
@@ -829,11 +895,11 @@ pub fn fp12_tests() {
         0xDBAB62437CFDAFEC,
         0xB829276DF6D08EEB,
         0xF8ADFE2BDABFA256,
-        0x9CE994CF02C2762,
+        0x09CE994CF02C2762,
         0xEC1220A3B5435895,
         0xCA5CE71D25C34A90,
-        0xFF6096EBA167C84,
-        0xAC58A76E45B3530,
+        0x0FF6096EBA167C84,
+        0x0AC58A76E45B3530,
         0x41B5A03537CA0D7E,
         0x177E035DCC13E992,
         0xE627D3B2D362A4CE,
@@ -847,19 +913,19 @@ pub fn fp12_tests() {
         0xEEFAB320EDFA5717,
         0x8DFF141F5C76C56C,
         0x26827B2E7C540893,
-        0x567042D897F0CA2,
+        0x0567042D897F0CA2,
         0x59B9A80F574BFDD7,
-        0x2A55EC7732CE593,
+        0x02A55EC7732CE593,
         0x862D01E0D1A52499,
         0x54060C066D1FB661,
         0xF401DF52BD0D8B2F,
-        0x49C93902539A3E0,
+        0x049C93902539A3E0,
         0xF2E7804670FDF554,
         0x84C1A6F5B96FE461,
-        0x5119D81ED5EE248,
+        0x05119D81ED5EE248,
         0x7D23022D1F5F7E1F,
         0x87D5CFAD5514269B,
-        0x4B824D15BC94B08,
+        0x04B824D15BC94B08,
         0x802E9BEDC337F4BD,
         0x42A691A095496471,
         0x16A617745AB3AE4A,
@@ -871,7 +937,7 @@ pub fn fp12_tests() {
         0x883E77467749AA2B,
         0xB9387A6264DA0215,
         0xF80F50671A0D8726,
-        0x1424AFBC196156,
+        0x001424AFBC196156,
         0xFA9A59F8BA3603BA,
         0x83ED6CEF8450A002,
         0xF1341781B2C0D274,
@@ -891,14 +957,14 @@ pub fn fp12_tests() {
         0xFC6EF5FF9BD3DDA8,
         0x551671B15B00B7D1,
         0x754CEE17265A1BCF,
-        0xBB1CDA448259D90,
-        0x335E4087A8AFE4A,
+        0x0BB1CDA448259D90,
+        0x0335E4087A8AFE4A,
         0x85E79F2034C13509,
         0x668A23F01800AB87,
         0x47A6E0765FA5284A,
         0xFA0A3D4102AC1A26,
         0x9766D0521177175A,
-        0x6892B638003D650,
+        0x06892B638003D650,
         0x586A36193F600E11,
         0xB87CF915DFD31165,
         0x111B3F30F1DFE7BC,
@@ -910,25 +976,25 @@ pub fn fp12_tests() {
         0x5227F525CA547A25,
         0x9A5385E737F1C63B,
         0x710A027EA50C9FD1,
-        0x24AA4FACBFEBEC,
+        0x0024AA4FACBFEBEC,
         0xDFCCFA458F767DDA,
         0x92215760177C0395,
         0x6E5C6D142EDE5FD7,
         0xD7281E201A58E3C7,
         0xA4EDCBA2FF55A919,
-        0x7FFE56A2B4D6654,
+        0x07FFE56A2B4D6654,
         0x732EDC3347CA03C9,
         0x7978DA9123A68AED,
         0x4373E5AA6219ADBD,
         0x266F1BF2A857656E,
         0xF9C5E70E7E6789D7,
-        0x3DBAE38594CBF9F,
+        0x03DBAE38594CBF9F,
         0x1A1C0E1A7BF634D3,
         0x9E0AD6CBC7C51F92,
         0x263C3531539EC3E4,
         0xE289E8A95B4D437A,
         0xC5D781D0141AB604,
-        0x53DB20B51D7DF20,
+        0x053DB20B51D7DF20,
         0xF9D32DC3AFFB9AC6,
         0x1C606298E0CAD696,
         0xD99C80BB9A551D8C,
@@ -940,19 +1006,19 @@ pub fn fp12_tests() {
         0xFFF4BC04F3A133F2,
         0xE7A087A363233C42,
         0x240DA2C2485BA826,
-        0x861E309CD421816,
+        0x0861E309CD421816,
         0xC18A72420D614F2E,
         0x756D86794D2D8361,
         0x9247F22A97BD5C78,
-        0xB9ACF4F93915724,
+        0x0B9ACF4F93915724,
         0x5CB23A493CB13EA8,
-        0x34D288A7E18C336,
+        0x034D288A7E18C336,
         0x18B97565FD3F816D,
         0x2BE0797D5C7D03CC,
         0x48459546B11E0FFA,
         0x3033E3863B36A743,
         0x37D62658C8A0E6EA,
-        0x44FD6A27D118107,
+        0x044FD6A27D118107,
     ];
     assert_eq!(res, res_exp);
 
@@ -972,7 +1038,7 @@ pub fn fp12_tests() {
         0x16CB2DE1BEF4E850,
         0xA4001CD14EC51486,
         0x8A768B0F20B52C41,
-        0xB0D642408B69E0C,
+        0x0B0D642408B69E0C,
         0x6B264EE8BE54C821,
         0xB50B8BAAF3A77D1B,
         0x179D5511947514C7,
@@ -981,11 +1047,11 @@ pub fn fp12_tests() {
         0xDBAB62437CFDAFEC,
         0xB829276DF6D08EEB,
         0xF8ADFE2BDABFA256,
-        0x9CE994CF02C2762,
+        0x09CE994CF02C2762,
         0xEC1220A3B5435895,
         0xCA5CE71D25C34A90,
-        0xFF6096EBA167C84,
-        0xAC58A76E45B3530,
+        0x0FF6096EBA167C84,
+        0x0AC58A76E45B3530,
         0x41B5A03537CA0D7E,
         0x177E035DCC13E992,
         0xE627D3B2D362A4CE,
@@ -999,19 +1065,19 @@ pub fn fp12_tests() {
         0xEEFAB320EDFA5717,
         0x8DFF141F5C76C56C,
         0x26827B2E7C540893,
-        0x567042D897F0CA2,
+        0x0567042D897F0CA2,
         0x59B9A80F574BFDD7,
-        0x2A55EC7732CE593,
+        0x02A55EC7732CE593,
         0x862D01E0D1A52499,
         0x54060C066D1FB661,
         0xF401DF52BD0D8B2F,
-        0x49C93902539A3E0,
+        0x049C93902539A3E0,
         0xF2E7804670FDF554,
         0x84C1A6F5B96FE461,
-        0x5119D81ED5EE248,
+        0x05119D81ED5EE248,
         0x7D23022D1F5F7E1F,
         0x87D5CFAD5514269B,
-        0x4B824D15BC94B08,
+        0x04B824D15BC94B08,
         0x802E9BEDC337F4BD,
         0x42A691A095496471,
         0x16A617745AB3AE4A,
@@ -1023,7 +1089,7 @@ pub fn fp12_tests() {
         0x883E77467749AA2B,
         0xB9387A6264DA0215,
         0xF80F50671A0D8726,
-        0x1424AFBC196156,
+        0x001424AFBC196156,
         0xFA9A59F8BA3603BA,
         0x83ED6CEF8450A002,
         0xF1341781B2C0D274,
@@ -1050,19 +1116,19 @@ pub fn fp12_tests() {
         0xB23901ECF2CB667E,
         0xAC07B7DA65518211,
         0x140EFBEE6C0EB3A4,
-        0x71757D11B2BBE4D,
+        0x071757D11B2BBE4D,
         0x91C064BD1C9D1C72,
         0x7E2511E5767C4EAE,
         0x43DE8FE71A0E1DED,
         0xB243E6D5FA0869AD,
         0xBB06D9385714F325,
-        0x9A94D39C94FE8E6,
+        0x09A94D39C94FE8E6,
         0x38545BB699976CF1,
         0xA9BB388BB254C594,
         0xA962BE5806EEF3C4,
         0xF40546F5471BA3CA,
         0x7A7009FFB08E3F2A,
-        0x2A7B8DC1A2BE8F4,
+        0x02A7B8DC1A2BE8F4,
         0xAE0932079D2632AE,
         0xD283C39F86BAC6A9,
         0x478AF87EAFDC52DF,
@@ -1080,7 +1146,7 @@ pub fn fp12_tests() {
         0x9D3AF0D792BF3F98,
         0x7F594CAEFD172BC4,
         0x12000C8A72B69E0E,
-        0x6D51A4DAD3AFFD0,
+        0x06D51A4DAD3AFFD0,
         0xC7177FB98F01B557,
         0x99EA5908F7E41B9D,
         0x621F351F095213DB,
@@ -1091,8 +1157,8 @@ pub fn fp12_tests() {
         0xDC056E5E1C0A9B8E,
         0x508ABB2C9BFD47D9,
         0x1480FAF35270B0DC,
-        0x1FCD4A01531232,
-        0x609EB64B94AD043,
+        0x001FCD4A01531232,
+        0x0609EB64B94AD043,
         0x407B1D74665C9C80,
         0xF73FF8AEE345E2A2,
         0x3B79E4BED3F9A8B9,
@@ -1110,155 +1176,190 @@ pub fn fp12_tests() {
 
     // Exponentiation
     let e = 0u64;
-    let a = zero;
+    let a = [0; 72];
     let res = exp_fp12_bls12_381(e, &a);
     let res_exp = a;
     assert_eq!(res, res_exp);
 
     let e = 10u64;
-    let a = zero;
+    let a = [0; 72];
     let res = exp_fp12_bls12_381(e, &a);
     let res_exp = a;
     assert_eq!(res, res_exp);
 
     let e = 0u64;
-    let a = one;
+    let a = {
+        let mut tmp = [0; 72];
+        tmp[0] = 1;
+        tmp
+    };
     let res = exp_fp12_bls12_381(e, &a);
     let res_exp = a;
     assert_eq!(res, res_exp);
 
     let e = 0u64;
-    let mut a = zero;
-    a[0] = 2;
+    let a = {
+        let mut tmp = [0; 72];
+        tmp[0] = 2;
+        tmp
+    };
     let res = exp_fp12_bls12_381(e, &a);
-    let res_exp = one;
+    let res_exp = {
+        let mut tmp = [0; 72];
+        tmp[0] = 1;
+        tmp
+    };
     assert_eq!(res, res_exp);
 
-    // let e = 0x44E992B44A6909F1;
-    // let a = [
-    //     0x1A1DA99F19CC62EC,
-    //     0xC6E7BB919DAA1CFF,
-    //     0x5CAF2D5F0F66F3F9,
-    //     0x8C31BEF315CD933C,
-    //     0x341A80C19A54C262,
-    //     0x1313B37DEF02A323,
-    //     0xD1675B2966653403,
-    //     0x223D09FF15802256,
-    //     0x121A60EF9BB03E52,
-    //     0xEF2A5D6DCD2AF6F0,
-    //     0x3F69DA11FB260F46,
-    //     0x16CB2DE1BEF4E850,
-    //     0xA4001CD14EC51486,
-    //     0x8A768B0F20B52C41,
-    //     0xB0D642408B69E0C,
-    //     0x6B264EE8BE54C821,
-    //     0xB50B8BAAF3A77D1B,
-    //     0x179D5511947514C7,
-    //     0xA256FC22AEA15930,
-    //     0x39FCCA2922D705C9,
-    //     0xDBAB62437CFDAFEC,
-    //     0xB829276DF6D08EEB,
-    //     0xF8ADFE2BDABFA256,
-    //     0x9CE994CF02C2762,
-    //     0xEC1220A3B5435895,
-    //     0xCA5CE71D25C34A90,
-    //     0xFF6096EBA167C84,
-    //     0xAC58A76E45B3530,
-    //     0x41B5A03537CA0D7E,
-    //     0x177E035DCC13E992,
-    //     0xE627D3B2D362A4CE,
-    //     0xD8B2E4FDC47135BF,
-    //     0x187A3FAF0EA73990,
-    //     0xB5C267D7701B129F,
-    //     0xF4B2E08ECF647356,
-    //     0x198C5CE404DC5504,
-    //     0x7AF00C89799BF9C2,
-    //     0xC7ED5DDEF23E13DF,
-    //     0xEEFAB320EDFA5717,
-    //     0x8DFF141F5C76C56C,
-    //     0x26827B2E7C540893,
-    //     0x567042D897F0CA2,
-    //     0x59B9A80F574BFDD7,
-    //     0x2A55EC7732CE593,
-    //     0x862D01E0D1A52499,
-    //     0x54060C066D1FB661,
-    //     0xF401DF52BD0D8B2F,
-    //     0x49C93902539A3E0,
-    //     0xF2E7804670FDF554,
-    //     0x84C1A6F5B96FE461,
-    //     0x5119D81ED5EE248,
-    //     0x7D23022D1F5F7E1F,
-    //     0x87D5CFAD5514269B,
-    //     0x4B824D15BC94B08,
-    //     0x802E9BEDC337F4BD,
-    //     0x42A691A095496471,
-    //     0x16A617745AB3AE4A,
-    //     0x4FF65091A11461E3,
-    //     0x4AFBDA6C41F89AA5,
-    //     0x13F7268580351657,
-    //     0x91A5A5D1F1CC05A3,
-    //     0x6948C60A2BD60A05,
-    //     0x883E77467749AA2B,
-    //     0xB9387A6264DA0215,
-    //     0xF80F50671A0D8726,
-    //     0x1424AFBC196156,
-    //     0xFA9A59F8BA3603BA,
-    //     0x83ED6CEF8450A002,
-    //     0xF1341781B2C0D274,
-    //     0xE6F674626EC960A0,
-    //     0xEDA23504015911F2,
-    //     0x171E721CBB072F05,
-    // ];
-    // let res = exp_fp12_bls12_381(e, &a);
-    // let res_exp = [
-    //     0xA339FC19399C8FCC,
-    //     0x1C3A9719D1CC373F,
-    //     0x4C1270DB9D45D256,
-    //     0x130FF2E6E85F8AC7,
-    //     0xE18A756C82DB8BF7,
-    //     0x2A5A608DA5865D83,
-    //     0x4A5168CD84528AD7,
-    //     0x132B6697FAD496AE,
-    //     0xD37EE7ED214D16DF,
-    //     0x0A8351EB44488EDE,
-    //     0xF4D5B82E43C2B339,
-    //     0x271547B5976B5473,
-    //     0x0E1C121F08CCD44B,
-    //     0x24F372B5C815C65F,
-    //     0x93D9D091717DE527,
-    //     0x124A43233B3E569D,
-    //     0xDB380FD5AB4A7770,
-    //     0xDD086C2E5AB15448,
-    //     0xA710D35F981C7BFB,
-    //     0x1451DF1F188D82A6,
-    //     0xAF76A9211EA1ADCD,
-    //     0x73CC965B956FFFCA,
-    //     0x34AC30ED233AFBB0,
-    //     0x2899B63798B1CB59,
-    //     0xE1AFAFC403939B1D,
-    //     0xCBAC0805E503FD63,
-    //     0x8692A057912D5D2F,
-    //     0x2AE88834EA26FB20,
-    //     0xE30DD06AC8DA1EB8,
-    //     0x990A77FE291D3BAC,
-    //     0x6882C88F5524E012,
-    //     0x22BC7AECD2F3BEB4,
-    //     0x9D054E3C6379BC20,
-    //     0x8B73EE50F7F68B22,
-    //     0xF1AE801590B1EA16,
-    //     0x245E99159B410E57,
-    //     0x0332679856928291,
-    //     0x0CE6662357B122F7,
-    //     0x21497855EF918598,
-    //     0x19CC97AF043FEB4B,
-    //     0xF3D2D11225E9BD82,
-    //     0xEA7E2103A4B69D34,
-    //     0x9C3456BFA96911B5,
-    //     0x1EF6A30FE3AFDF1E,
-    //     0xDEB4A4E48E77F734,
-    //     0x90BF91B925DF33D5,
-    //     0x3ED3C34E49161BDC,
-    //     0x2CBA6E19A3250365,
-    // ];
-    // assert_eq!(res, res_exp);
+    let e = 0x44E992B44A6909F1;
+    let a = [
+        0x1A1DA99F19CC62EC,
+        0xC6E7BB919DAA1CFF,
+        0x5CAF2D5F0F66F3F9,
+        0x8C31BEF315CD933C,
+        0x341A80C19A54C262,
+        0x1313B37DEF02A323,
+        0xD1675B2966653403,
+        0x223D09FF15802256,
+        0x121A60EF9BB03E52,
+        0xEF2A5D6DCD2AF6F0,
+        0x3F69DA11FB260F46,
+        0x16CB2DE1BEF4E850,
+        0xA4001CD14EC51486,
+        0x8A768B0F20B52C41,
+        0x0B0D642408B69E0C,
+        0x6B264EE8BE54C821,
+        0xB50B8BAAF3A77D1B,
+        0x179D5511947514C7,
+        0xA256FC22AEA15930,
+        0x39FCCA2922D705C9,
+        0xDBAB62437CFDAFEC,
+        0xB829276DF6D08EEB,
+        0xF8ADFE2BDABFA256,
+        0x09CE994CF02C2762,
+        0xEC1220A3B5435895,
+        0xCA5CE71D25C34A90,
+        0x0FF6096EBA167C84,
+        0x0AC58A76E45B3530,
+        0x41B5A03537CA0D7E,
+        0x177E035DCC13E992,
+        0xE627D3B2D362A4CE,
+        0xD8B2E4FDC47135BF,
+        0x187A3FAF0EA73990,
+        0xB5C267D7701B129F,
+        0xF4B2E08ECF647356,
+        0x198C5CE404DC5504,
+        0x7AF00C89799BF9C2,
+        0xC7ED5DDEF23E13DF,
+        0xEEFAB320EDFA5717,
+        0x8DFF141F5C76C56C,
+        0x26827B2E7C540893,
+        0x0567042D897F0CA2,
+        0x59B9A80F574BFDD7,
+        0x02A55EC7732CE593,
+        0x862D01E0D1A52499,
+        0x54060C066D1FB661,
+        0xF401DF52BD0D8B2F,
+        0x049C93902539A3E0,
+        0xF2E7804670FDF554,
+        0x84C1A6F5B96FE461,
+        0x05119D81ED5EE248,
+        0x7D23022D1F5F7E1F,
+        0x87D5CFAD5514269B,
+        0x04B824D15BC94B08,
+        0x802E9BEDC337F4BD,
+        0x42A691A095496471,
+        0x16A617745AB3AE4A,
+        0x4FF65091A11461E3,
+        0x4AFBDA6C41F89AA5,
+        0x13F7268580351657,
+        0x91A5A5D1F1CC05A3,
+        0x6948C60A2BD60A05,
+        0x883E77467749AA2B,
+        0xB9387A6264DA0215,
+        0xF80F50671A0D8726,
+        0x001424AFBC196156,
+        0xFA9A59F8BA3603BA,
+        0x83ED6CEF8450A002,
+        0xF1341781B2C0D274,
+        0xE6F674626EC960A0,
+        0xEDA23504015911F2,
+        0x171E721CBB072F05,
+    ];
+    let res = exp_fp12_bls12_381(e, &a);
+    let res_exp = [
+        0xE1721F96651F8E40,
+        0x904B79235B716871,
+        0x0F753472DD8C67EE,
+        0x1A6726FE39ADA432,
+        0x82642D670C94629F,
+        0x0F4D055ACC07AB62,
+        0x4AC1E39B468EFAC1,
+        0xB3A4A686D88194E2,
+        0xFABDF85AA1EC0698,
+        0xEF46C15372BF780C,
+        0xE2DF26334F18E672,
+        0x02040CF026AD0623,
+        0xE5805F1507BD35D6,
+        0x2D2434905B9C892F,
+        0x79304EC9FB209274,
+        0x5C4E8B97122D1BA6,
+        0xCE9101966305AAB7,
+        0x1532AF7E1BF43275,
+        0x0BD8624CE9B5646C,
+        0x85E8ED7318072804,
+        0xE950D371E315A193,
+        0x40E8B33F458ECCE3,
+        0xBD1703AB5203AD2A,
+        0x18C08E9730333429,
+        0x012E54EE108F0422,
+        0x9091E6DD6F580710,
+        0xABD3BCC79A647496,
+        0x2BCBFBD6D22FD250,
+        0xBB636B32A6841FAC,
+        0x191FB6D1E964AC2C,
+        0x39D55ED7C3D3C661,
+        0x1E1DA854D34ED53F,
+        0x7B90BDFFB15BC871,
+        0xB5E1625B04713A73,
+        0x50A5F158123AB6EC,
+        0x04A193FAEE43378C,
+        0x442AD6BCD2EB06A8,
+        0x01EABDC1039FC1E9,
+        0x59CF9343635E1362,
+        0x2FBE9552FC83D535,
+        0xCE4B6FF070EDDCFC,
+        0x030D7A2C03E00A13,
+        0x7ED17A220DD7F216,
+        0xACC799F0B0BFF9A3,
+        0xB468530427F9DF58,
+        0x79C61E5CDEC9A5F5,
+        0x5B2D43966F01384A,
+        0x103F9F1486F20985,
+        0x096CF44C0559C532,
+        0x9ED5E4BBFC6292A0,
+        0x56612328F664ECF7,
+        0x1BBF21371BBB68DE,
+        0xA90F9B874B69EB32,
+        0x11E5C7EE1EDBAC83,
+        0x65BE997FC9F56377,
+        0x6DD20647D8B59A49,
+        0xDF5A82BBD34151DB,
+        0xC1FB40EF8EFD4AA8,
+        0x7A08AA2C6A7F14F4,
+        0x005462864D471380,
+        0x705D51A8D8D002AF,
+        0x557CEC65B831F5B3,
+        0xDCF31D4FBFC86DBE,
+        0x4325D31488F0A707,
+        0x4C71F383E902DC6B,
+        0x03C1C64FCAEAD583,
+        0xF0009195FEB91340,
+        0x68550E58730651D1,
+        0xD5B01C59E864B205,
+        0x88506A66847966C7,
+        0x52CB31FFF6872A4F,
+        0x0A0956CDEBEA5742,
+    ];
+    assert_eq!(res, res_exp);
 }
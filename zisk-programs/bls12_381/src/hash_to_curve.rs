@@ -0,0 +1,1237 @@
+use ziskos::zisklib::{
+    add_bls12_381, add_twist_bls12_381, clear_cofactor_bls12_381, clear_cofactor_twist_bls12_381,
+    encode_to_curve_bls12_381, encode_to_curve_twist_bls12_381, expand_message_xmd,
+    hash_to_curve_bls12_381, hash_to_curve_twist_bls12_381, hash_to_field_bls12_381,
+    hash_to_field_twist_bls12_381, isogeny_bls12_381, isogeny_twist_bls12_381,
+    map_to_curve_bls12_381, map_to_curve_twist_bls12_381, sswu_bls12_381, sswu_twist_bls12_381,
+};
+
+// Test vectors of RFC 9380: expand_message_xmd with SHA-256 from Appendix K.1 and the
+// BLS12381G1_XMD:SHA-256_SSWU_{RO,NU}_ and BLS12381G2_XMD:SHA-256_SSWU_{RO,NU}_ suites from
+// Appendices J.9.1 and J.10.1. Field elements are big-endian hexadecimal, as printed in the RFC,
+// and Fp2 elements are written as [c0, c1]. The RFC does not list the points of the simplified
+// SWU map on the 11-isogenous (G1) and 3-isogenous (G2) curves, those were computed with arkworks
+
+/// expand_message_xmd vector, the output is `len` bytes long
+struct ExpandVector {
+    msg: &'static str,
+    len: usize,
+    uniform_bytes: &'static str,
+}
+
+/// hash_to_curve (RO) or encode_to_curve (NU) vector for G1
+struct G1Vector {
+    msg: &'static str,
+    /// Output of hash_to_field, two elements for RO and one for NU
+    u: &'static [&'static str],
+    /// Simplified SWU map of each uᵢ, on the isogenous curve E'
+    iso: &'static [[&'static str; 2]],
+    /// Isogeny of each SWU point, that is map_to_curve(uᵢ)
+    q: &'static [[&'static str; 2]],
+    p: [&'static str; 2],
+}
+
+/// hash_to_curve (RO) or encode_to_curve (NU) vector for G2
+struct G2Vector {
+    msg: &'static str,
+    u: &'static [[&'static str; 2]],
+    iso: &'static [[[&'static str; 2]; 2]],
+    q: &'static [[[&'static str; 2]; 2]],
+    p: [[&'static str; 2]; 2],
+}
+
+pub fn hash_to_curve_tests() {
+    // expand_message_xmd, with a short DST and with a DST longer than 255 bytes, which is
+    // first hashed to H2C-OVERSIZE-DST-
+    expand_message_xmd_tests(EXPAND_DST, &EXPAND_VECTORS);
+    expand_message_xmd_tests(EXPAND_LONG_DST, &EXPAND_LONG_DST_VECTORS);
+
+    // G1
+    g1_tests(G1_RO_DST, &G1_RO_VECTORS, true);
+    g1_tests(G1_NU_DST, &G1_NU_VECTORS, false);
+
+    // G2
+    g2_tests(G2_RO_DST, &G2_RO_VECTORS, true);
+    g2_tests(G2_NU_DST, &G2_NU_VECTORS, false);
+}
+
+fn expand_message_xmd_tests(dst: &str, vectors: &[ExpandVector]) {
+    for v in vectors {
+        let res = expand_message_xmd(v.msg.as_bytes(), dst.as_bytes(), v.len);
+        let res_exp = hex::decode(v.uniform_bytes).unwrap();
+        assert_eq!(res, res_exp);
+    }
+}
+
+/// Checks every step of the map to G1 against the vector, and then the whole hash. With `ro`
+/// the vectors are of hash_to_curve, otherwise of encode_to_curve
+fn g1_tests(dst: &str, vectors: &[G1Vector], ro: bool) {
+    for v in vectors {
+        let (msg, dst) = (v.msg.as_bytes(), dst.as_bytes());
+        let u: Vec<[u64; 6]> = v.u.iter().map(|u| fp(u)).collect();
+        if ro {
+            let res = hash_to_field_bls12_381(msg, dst);
+            assert_eq!(res.as_slice(), u.as_slice());
+        }
+
+        // map_to_curve(u) = iso_map(sswu(u))
+        let mut q = Vec::new();
+        for (i, u) in u.iter().enumerate() {
+            let res = sswu_bls12_381(u);
+            let res_exp = g1(&v.iso[i]);
+            assert_eq!(res, res_exp);
+
+            let res = isogeny_bls12_381(&res);
+            let res_exp = g1(&v.q[i]);
+            assert_eq!(res, res_exp);
+
+            let res = map_to_curve_bls12_381(u);
+            assert_eq!(res, res_exp);
+            q.push(res);
+        }
+
+        // P = clear_cofactor(Q₀ + Q₁) for RO, clear_cofactor(Q₀) for NU
+        let r = if ro { add_bls12_381(&q[0], &q[1]) } else { q[0] };
+        let res = clear_cofactor_bls12_381(&r);
+        let res_exp = g1(&v.p);
+        assert_eq!(res, res_exp);
+
+        let res = if ro {
+            hash_to_curve_bls12_381(msg, dst)
+        } else {
+            encode_to_curve_bls12_381(msg, dst)
+        };
+        assert_eq!(res, res_exp);
+    }
+}
+
+/// Same as [`g1_tests`] for G2
+fn g2_tests(dst: &str, vectors: &[G2Vector], ro: bool) {
+    for v in vectors {
+        let (msg, dst) = (v.msg.as_bytes(), dst.as_bytes());
+        let u: Vec<[u64; 12]> = v.u.iter().map(fp2).collect();
+        if ro {
+            let res = hash_to_field_twist_bls12_381(msg, dst);
+            assert_eq!(res.as_slice(), u.as_slice());
+        }
+
+        // map_to_curve(u) = iso_map(sswu(u))
+        let mut q = Vec::new();
+        for (i, u) in u.iter().enumerate() {
+            let res = sswu_twist_bls12_381(u);
+            let res_exp = g2(&v.iso[i]);
+            assert_eq!(res, res_exp);
+
+            let res = isogeny_twist_bls12_381(&res);
+            let res_exp = g2(&v.q[i]);
+            assert_eq!(res, res_exp);
+
+            let res = map_to_curve_twist_bls12_381(u);
+            assert_eq!(res, res_exp);
+            q.push(res);
+        }
+
+        // P = clear_cofactor(Q₀ + Q₁) for RO, clear_cofactor(Q₀) for NU
+        let r = if ro { add_twist_bls12_381(&q[0], &q[1]) } else { q[0] };
+        let res = clear_cofactor_twist_bls12_381(&r);
+        let res_exp = g2(&v.p);
+        assert_eq!(res, res_exp);
+
+        let res = if ro {
+            hash_to_curve_twist_bls12_381(msg, dst)
+        } else {
+            encode_to_curve_twist_bls12_381(msg, dst)
+        };
+        assert_eq!(res, res_exp);
+    }
+}
+
+/// Little-endian limbs of a big-endian hexadecimal element of Fp
+fn fp(hex: &str) -> [u64; 6] {
+    let bytes = hex::decode(hex).unwrap();
+    assert_eq!(bytes.len(), 48);
+    let mut limbs = [0; 6];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+fn fp2(c: &[&str; 2]) -> [u64; 12] {
+    let mut res = [0; 12];
+    res[..6].copy_from_slice(&fp(c[0]));
+    res[6..].copy_from_slice(&fp(c[1]));
+    res
+}
+
+fn g1(p: &[&str; 2]) -> [u64; 12] {
+    let mut res = [0; 12];
+    res[..6].copy_from_slice(&fp(p[0]));
+    res[6..].copy_from_slice(&fp(p[1]));
+    res
+}
+
+fn g2(p: &[[&str; 2]; 2]) -> [u64; 24] {
+    let mut res = [0; 24];
+    res[..12].copy_from_slice(&fp2(&p[0]));
+    res[12..].copy_from_slice(&fp2(&p[1]));
+    res
+}
+
+const EXPAND_DST: &str = "QUUX-V01-CS02-with-expander-SHA256-128";
+
+const EXPAND_VECTORS: [ExpandVector; 10] = [
+    ExpandVector {
+        msg: "",
+        len: 0x20,
+        uniform_bytes: "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+    },
+    ExpandVector {
+        msg: "abc",
+        len: 0x20,
+        uniform_bytes: "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+    },
+    ExpandVector {
+        msg: "abcdef0123456789",
+        len: 0x20,
+        uniform_bytes: "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+    },
+    ExpandVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        len: 0x20,
+        uniform_bytes: "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+    },
+    ExpandVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        len: 0x20,
+        uniform_bytes: "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+    },
+    ExpandVector {
+        msg: "",
+        len: 0x80,
+        uniform_bytes: "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+            e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+            eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+            c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+    },
+    ExpandVector {
+        msg: "abc",
+        len: 0x80,
+        uniform_bytes: "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+            647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+            bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+            058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+    },
+    ExpandVector {
+        msg: "abcdef0123456789",
+        len: 0x80,
+        uniform_bytes: "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9\
+            ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4b\
+            c95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be1\
+            4cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+    },
+    ExpandVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        len: 0x80,
+        uniform_bytes: "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bb\
+            d88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0\
+            e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b\
+            29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a",
+    },
+    ExpandVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        len: 0x80,
+        uniform_bytes: "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d0\
+            6d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608\
+            ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4\
+            a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487",
+    },
+];
+
+const EXPAND_LONG_DST: &str = "QUUX-V01-CS02-with-expander-SHA256-128-long-DST-1111111111111111\
+    1111111111111111111111111111111111111111111111111111111111111111\
+    1111111111111111111111111111111111111111111111111111111111111111\
+    1111111111111111111111111111111111111111111111111111111111111111";
+
+const EXPAND_LONG_DST_VECTORS: [ExpandVector; 10] = [
+    ExpandVector {
+        msg: "",
+        len: 0x20,
+        uniform_bytes: "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3",
+    },
+    ExpandVector {
+        msg: "abc",
+        len: 0x20,
+        uniform_bytes: "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12",
+    },
+    ExpandVector {
+        msg: "abcdef0123456789",
+        len: 0x20,
+        uniform_bytes: "35387dcf22618f3728e6c686490f8b431f76550b0b2c61cbc1ce7001536f4521",
+    },
+    ExpandVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        len: 0x20,
+        uniform_bytes: "01b637612bb18e840028be900a833a74414140dde0c4754c198532c3a0ba42bc",
+    },
+    ExpandVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        len: 0x20,
+        uniform_bytes: "20cce7033cabc5460743180be6fa8aac5a103f56d481cf369a8accc0c374431b",
+    },
+    ExpandVector {
+        msg: "",
+        len: 0x80,
+        uniform_bytes: "14604d85432c68b757e485c8894db3117992fc57e0e136f71ad987f789a0abc2\
+            87c47876978e2388a02af86b1e8d1342e5ce4f7aaa07a87321e691f6fba7e007\
+            2eecc1218aebb89fb14a0662322d5edbd873f0eb35260145cd4e64f748c5dfe6\
+            0567e126604bcab1a3ee2dc0778102ae8a5cfd1429ebc0fa6bf1a53c36f55dfc",
+    },
+    ExpandVector {
+        msg: "abc",
+        len: 0x80,
+        uniform_bytes: "1a30a5e36fbdb87077552b9d18b9f0aee16e80181d5b951d0471d55b66684914\
+            aef87dbb3626eaabf5ded8cd0686567e503853e5c84c259ba0efc37f71c839da\
+            2129fe81afdaec7fbdc0ccd4c794727a17c0d20ff0ea55e1389d6982d1241cb8\
+            d165762dbc39fb0cee4474d2cbbd468a835ae5b2f20e4f959f56ab24cd6fe267",
+    },
+    ExpandVector {
+        msg: "abcdef0123456789",
+        len: 0x80,
+        uniform_bytes: "d2ecef3635d2397f34a9f86438d772db19ffe9924e28a1caf6f1c8f15603d402\
+            8f40891044e5c7e39ebb9b31339979ff33a4249206f67d4a1e7c765410bcd249\
+            ad78d407e303675918f20f26ce6d7027ed3774512ef5b00d816e51bfcc96c353\
+            9601fa48ef1c07e494bdc37054ba96ecb9dbd666417e3de289d4f424f502a982",
+    },
+    ExpandVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        len: 0x80,
+        uniform_bytes: "ed6e8c036df90111410431431a232d41a32c86e296c05d426e5f44e75b9a50d3\
+            35b2412bc6c91e0a6dc131de09c43110d9180d0a70f0d6289cb4e43b05f7ee5e\
+            9b3f42a1fad0f31bac6a625b3b5c50e3a83316783b649e5ecc9d3b1d9471cb50\
+            24b7ccf40d41d1751a04ca0356548bc6e703fca02ab521b505e8e45600508d32",
+    },
+    ExpandVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        len: 0x80,
+        uniform_bytes: "78b53f2413f3c688f07732c10e5ced29a17c6a16f717179ffbe38d92d6c9ec29\
+            6502eb9889af83a1928cd162e845b0d3c5424e83280fed3d10cffb2f8431f14e\
+            7a23f4c68819d40617589e4c41169d0b56e0e3535be1fd71fbb08bb70c5b5ffe\
+            d953d6c14bf7618b35fc1f4c4b30538236b4b08c9fbf90462447a8ada60be495",
+    },
+];
+
+const G1_RO_DST: &str = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+const G1_RO_VECTORS: [G1Vector; 5] = [
+    G1Vector {
+        msg: "",
+        u: &[
+            "0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f",
+            "019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9",
+        ],
+        iso: &[
+            [
+                "005cbc3c711cef5b30f2ea9193a25275fe14ec9ae2b9dfc7f7bcae7edd7a2cc1c8499138300aec11f791430f0921a08c",
+                "0e1e665b91553233a8b01c1124db33c65d807d5e990dd3ad72189a1e18bc726559b5269679ab120bc1e1555e8a836e09",
+            ],
+            [
+                "14f2745e6da476bd66b268d01e0967d157ecabcf30b934713dedaeb9d10e91b0b477f097832332ef75aa9d441e9ffcc1",
+                "08bc9f0afe741d5d7e4389c86a270e76f951c99d4fa9d6124c3f956a81f4b1229c980fc0c09a488414ff0bd21b19eee9",
+            ],
+        ],
+        q: &[
+            [
+                "11a3cce7e1d90975990066b2f2643b9540fa40d6137780df4e753a8054d07580db3b7f1f03396333d4a359d1fe3766fe",
+                "0eeaf6d794e479e270da10fdaf768db4c96b650a74518fc67b04b03927754bac66f3ac720404f339ecdcc028afa091b7",
+            ],
+            [
+                "160003aaf1632b13396dbad518effa00fff532f604de1a7fc2082ff4cb0afa2d63b2c32da1bef2bf6c5ca62dc6b72f9c",
+                "0d8bb2d14e20cf9f6036152ed386d79189415b6d015a20133acb4e019139b94e9c146aaad5817f866c95d609a361735e",
+            ],
+        ],
+        p: [
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ],
+    },
+    G1Vector {
+        msg: "abc",
+        u: &[
+            "0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951",
+            "003574a00b109ada2f26a37a91f9d1e740dffd8d69ec0c35e1e9f4652c7dba61123e9dd2e76c655d956e2b3462611139",
+        ],
+        iso: &[
+            [
+                "00387b6d4bf7afea41cfeb518a44bd3b84598a81256dbbc19b1fac9db312d775b1fe6f721b7326a487ee6882be4c001d",
+                "040fdcb16326061e7a0af53ae690a3e33e2b3423f9df61d89c318fc84948947af71a62eccec8492e82e15238825c5f97",
+            ],
+            [
+                "083c9ac6dc78720c2880ac44e5b369f0e51d92a1f9ec4671f82d6c996cd82341e2cae904539582e148e688a730c436a4",
+                "15311d6aa0f5529401779eb4a3267235e6f122be5aa4d1fab9c4e16dc43453f8b47745f6205e59d52f59c30c6b45cb39",
+            ],
+        ],
+        q: &[
+            [
+                "125435adce8e1cbd1c803e7123f45392dc6e326d292499c2c45c5865985fd74fe8f042ecdeeec5ecac80680d04317d80",
+                "0e8828948c989126595ee30e4f7c931cbd6f4570735624fd25aef2fa41d3f79cfb4b4ee7b7e55a8ce013af2a5ba20bf2",
+            ],
+            [
+                "11def93719829ecda3b46aa8c31fc3ac9c34b428982b898369608e4f042babee6c77ab9218aad5c87ba785481eff8ae4",
+                "0007c9cef122ccf2efd233d6eb9bfc680aa276652b0661f4f820a653cec1db7ff69899f8e52b8e92b025a12c822a6ce6",
+            ],
+        ],
+        p: [
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ],
+    },
+    G1Vector {
+        msg: "abcdef0123456789",
+        u: &[
+            "062d1865eb80ebfa73dcfc45db1ad4266b9f3a93219976a3790ab8d52d3e5f1e62f3b01795e36834b17b70e7b76246d4",
+            "0cdc3e2f271f29c4ff75020857ce6c5d36008c9b48385ea2f2bf6f96f428a3deb798aa033cd482d1cdc8b30178b08e3a",
+        ],
+        iso: &[
+            [
+                "1485029306c5ee47ea20a8832c01e04539db2613eeb7ae3e77a228e1647612c51e2bc2516e9737ebcfbc506cdf3f468a",
+                "006f8e7132f56b93ba5c45a9169b933e09c144b49d09bf6a6883b755d161fcff17ef0af284f969618c9dfaa920b8656c",
+            ],
+            [
+                "0a198fa0eb6947c3fe0ad766cd468e286d6206508f837c20830fd93343394fda14ddf4a834ebae1ace020367b08871cd",
+                "11cd6a1bd34aacfa41144131cd4ded69d54c87b583619cbed087c076a91d70573f4532bb971ee7f2d4a5ed060d96511a",
+            ],
+        ],
+        q: &[
+            [
+                "08834484878c217682f6d09a4b51444802fdba3d7f2df9903a0ddadb92130ebbfa807fffa0eabf257d7b48272410afff",
+                "0b318f7ecf77f45a0f038e62d7098221d2dbbca2a394164e2e3fe953dc714ac2cde412d8f2d7f0c03b259e6795a2508e",
+            ],
+            [
+                "158418ed6b27e2549f05531a8281b5822b31c3bf3144277fbb977f8d6e2694fedceb7011b3c2b192f23e2a44b2bd106e",
+                "1879074f344471fac5f839e2b4920789643c075792bec5af4282c73f7941cda5aa77b00085eb10e206171b9787c4169f",
+            ],
+        ],
+        p: [
+            "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+            "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+        ],
+    },
+    G1Vector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        u: &[
+            "010476f6a060453c0b1ad0b628f3e57c23039ee16eea5e71bb87c3b5419b1255dc0e5883322e563b84a29543823c0e86",
+            "0b1a912064fb0554b180e07af7e787f1f883a0470759c03c1b6509eb8ce980d1670305ae7b928226bb58fdc0a419f46e",
+        ],
+        iso: &[
+            [
+                "0d9d18d69527718d7f22052b1196de312ba09a34b780b4957af88bc265adbeed5a5258bf64f5063808afc74ee0979c11",
+                "07d11013d02d794ee986fa3342223531be39fc6d436030717c3e79eeae33942c063d6aa6bf7ad5afb10b4ec2c4f497c0",
+            ],
+            [
+                "0e836a753a052c2e453a49ee9719e84b4b5f4cd719e2921d4163de6e2d2b73398d56d83e5ae327546ed49747f535ac87",
+                "11ce79e25682887b0a7a370d313a43f2679513d2137c7cd79da91f4570691f356e93cd33910dfccc2e98e8bea23044c8",
+            ],
+        ],
+        q: &[
+            [
+                "0cbd7f84ad2c99643fea7a7ac8f52d63d66cefa06d9a56148e58b984b3dd25e1f41ff47154543343949c64f88d48a710",
+                "052c00e4ed52d000d94881a5638ae9274d3efc8bc77bc0e5c650de04a000b2c334a9e80b85282a00f3148dfdface0865",
+            ],
+            [
+                "06493fb68f0d513af08be0372f849436a787e7b701ae31cb964d968021d6ba6bd7d26a38aaa5a68e8c21a6b17dc8b579",
+                "02e98f2ccf5802b05ffaac7c20018bc0c0b2fd580216c4aa2275d2909dc0c92d0d0bdc979226adeb57a29933536b6bb4",
+            ],
+        ],
+        p: [
+            "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488",
+            "1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+        ],
+    },
+    G1Vector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        u: &[
+            "0a8ffa7447f6be1c5a2ea4b959c9454b431e29ccc0802bc052413a9c5b4f9aac67a93431bd480d15be1e057c8a08e8c6",
+            "05d487032f602c90fa7625dbafe0f4a49ef4a6b0b33d7bb349ff4cf5410d297fd6241876e3e77b651cfc8191e40a68b7",
+        ],
+        iso: &[
+            [
+                "0651f86717170b6d60aeddc496976d64321ca126d2cec8dd687f53a839b36dfc2d13528b435046e658914c957dcc8265",
+                "0ddc5bed58754fd7e5cca2aa94ca02ece07e3e5e5c1d42f046dad0e25a923a59cdc18970249ce34b2ceb93289f4618d6",
+            ],
+            [
+                "09f0b1e7787e6902fb5f890cacc44ae225cd740141c5b902d4c5431efd7f4e6b6bcfcecb7abc0999cc79cc69ca2b61da",
+                "04c629fd8237b8c6a30c5553c617d7cadf8beb80d2bec654da049f4d029d73bb6daff8aea9798da919650a1742f6a597",
+            ],
+        ],
+        q: &[
+            [
+                "0cf97e6dbd0947857f3e578231d07b309c622ade08f2c08b32ff372bd90db19467b2563cc997d4407968d4ac80e154f8",
+                "127f0cddf2613058101a5701f4cb9d0861fd6c2a1b8e0afe194fccf586a3201a53874a2761a9ab6d7220c68661a35ab3",
+            ],
+            [
+                "092f1acfa62b05f95884c6791fba989bbe58044ee6355d100973bf9553ade52b47929264e6ae770fb264582d8dce512a",
+                "028e6d0169a72cfedb737be45db6c401d3adfb12c58c619c82b93a5dfcccef12290de530b0480575ddc8397cda0bbebf",
+            ],
+        ],
+        p: [
+            "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe",
+            "05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+        ],
+    },
+];
+
+const G1_NU_DST: &str = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_";
+
+const G1_NU_VECTORS: [G1Vector; 5] = [
+    G1Vector {
+        msg: "",
+        u: &[
+            "156c8a6a2c184569d69a76be144b5cdc5141d2d2ca4fe341f011e25e3969c55ad9e9b9ce2eb833c81a908e5fa4ac5f03",
+        ],
+        iso: &[
+            [
+                "147ae7153322c4a74e57780f0653fd69b83444493583b09e032d28a5596098508c1e96d024c5b70c32cbb4721807c256",
+                "08f18987781c85921578642dce6fb2107844f9328ac5b88391a1d27bf0d0e88f70bab049b32f055363a3d7c03da7c92b",
+            ],
+        ],
+        q: &[
+            [
+                "11398d3b324810a1b093f8e35aa8571cced95858207e7f49c4fd74656096d61d8a2f9a23cdb18a4dd11cd1d66f41f709",
+                "19316b6fb2ba7717355d5d66a361899057e1e84a6823039efc7beccefe09d023fb2713b1c415fcf278eb0c39a89b4f72",
+            ],
+        ],
+        p: [
+            "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+            "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+        ],
+    },
+    G1Vector {
+        msg: "abc",
+        u: &[
+            "147e1ed29f06e4c5079b9d14fc89d2820d32419b990c1c7bb7dbea2a36a045124b31ffbde7c99329c05c559af1c6cc82",
+        ],
+        iso: &[
+            [
+                "056c941a3c17e5928a4fb7ec4c58079992bef659e10e2f2a03f9493560e2869b86ffdafd1b522c60a354efaa4fa8bb8f",
+                "0a6e78d64917c5cb4fd1dc25d8d3ac2b5c59afa47151481b534165ee9b6517faa39a56e6962b4d8e204e9871c1612bc6",
+            ],
+        ],
+        q: &[
+            [
+                "1998321bc27ff6d71df3051b5aec12ff47363d81a5e9d2dff55f444f6ca7e7d6af45c56fd029c58237c266ef5cda5254",
+                "034d274476c6307ae584f951c82e7ea85b84f72d28f4d6471732356121af8d62a49bc263e8eb913a6cf6f125995514ee",
+            ],
+        ],
+        p: [
+            "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+            "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+        ],
+    },
+    G1Vector {
+        msg: "abcdef0123456789",
+        u: &[
+            "04090815ad598a06897dd89bcda860f25837d54e897298ce31e6947378134d3761dc59a572154963e8c954919ecfa82d",
+        ],
+        iso: &[
+            [
+                "141dfb991defe8a33935818d3ca1c9f47a3b15f6cd64cd21fd5ccc935e7e0d7bcb920c7cf60bd18831ba12398476cd32",
+                "16edccaf65f8553f1136e4fe281946e964f81e8c6b8eeef85614333a1ce114d2b39026403963d1dd5b20ab656b628fa3",
+            ],
+        ],
+        q: &[
+            [
+                "17d502fa43bd6a4cad2859049a0c3ecefd60240d129be65da271a4c03a9c38fa78163b9d2a919d2beb57df7d609b4919",
+                "109019902ae93a8732abecf2ff7fecd2e4e305eb91f41c9c3267f16b6c19de138c7272947f25512745da6c466cdfd1ac",
+            ],
+        ],
+        p: [
+            "1974dbb8e6b5d20b84df7e625e2fbfecb2cdb5f77d5eae5fb2955e5ce7313cae8364bc2fff520a6c25619739c6bdcb6a",
+            "15f9897e11c6441eaa676de141c8d83c37aab8667173cbe1dfd6de74d11861b961dccebcd9d289ac633455dfcc7013a3",
+        ],
+    },
+    G1Vector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        u: &[
+            "08dccd088ca55b8bfbc96fb50bb25c592faa867a8bb78d4e94a8cc2c92306190244532e91feba2b7fed977e3c3bb5a1f",
+        ],
+        iso: &[
+            [
+                "0bb38d8483f15f91815a98d4215fcc5814215f4e8de22117aa258c786b97c7e6b1127a19711201408db6b12838e69ee2",
+                "0ba351d3fcae72447ba1a95270caf5d14ebb6f5b7f71637a01b6f5a530ba9bb052b153c4157e84321354513c8a978b9b",
+            ],
+        ],
+        q: &[
+            [
+                "112eb92dd2b3aa9cd38b08de4bef603f2f9fb0ca226030626a9a2e47ad1e9847fe0a5ed13766c339e38f514bba143b21",
+                "17542ce2f8d0a54f2c5ba8c4b14e10b22d5bcd7bae2af3c965c8c872b571058c720eac448276c99967ded2bf124490e1",
+            ],
+        ],
+        p: [
+            "0a7a047c4a8397b3446450642c2ac64d7239b61872c9ae7a59707a8f4f950f101e766afe58223b3bff3a19a7f754027c",
+            "1383aebba1e4327ccff7cf9912bda0dbc77de048b71ef8c8a81111d71dc33c5e3aa6edee9cf6f5fe525d50cc50b77cc9",
+        ],
+    },
+    G1Vector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        u: &[
+            "0dd824886d2123a96447f6c56e3a3fa992fbfefdba17b6673f9f630ff19e4d326529db37e1c1be43f905bf9202e0278d",
+        ],
+        iso: &[
+            [
+                "094bfb561808a118a6212c7f3894c618bb17c95a1066a2efdf54b7d2ca208c9762f99bccd450d794fc12bd7ec11ce486",
+                "0a8d2f20054f4b6558cde422223fd75d9a287fee5691d1b937df84cee5c0c5899ddbdf5a5a1288f4781102736b83b623",
+            ],
+        ],
+        q: &[
+            [
+                "1775d400a1bacc1c39c355da7e96d2d1c97baa9430c4a3476881f8521c09a01f921f592607961efc99c4cd46bd78ca19",
+                "1109b5d59f65964315de65a7a143e86eabc053104ed289cf480949317a5685fad7254ff8e7fe6d24d3104e5d55ad6370",
+            ],
+        ],
+        p: [
+            "0e7a16a975904f131682edbb03d9560d3e48214c9986bd50417a77108d13dc957500edf96462a3d01e62dc6cd468ef11",
+            "0ae89e677711d05c30a48d6d75e76ca9fb70fe06c6dd6ff988683d89ccde29ac7d46c53bb97a59b1901abf1db66052db",
+        ],
+    },
+];
+
+const G2_RO_DST: &str = "QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+const G2_RO_VECTORS: [G2Vector; 5] = [
+    G2Vector {
+        msg: "",
+        u: &[
+            [
+                "03dbc2cce174e91ba93cbb08f26b917f98194a2ea08d1cce75b2b9cc9f21689d80bd79b594a613d0a68eb807dfdc1cf8",
+                "05a2acec64114845711a54199ea339abd125ba38253b70a92c876df10598bd1986b739cad67961eb94f7076511b3b39a",
+            ],
+            [
+                "02f99798e8a5acdeed60d7e18e9120521ba1f47ec090984662846bc825de191b5b7641148c0dbc237726a334473eee94",
+                "145a81e418d4010cc027a68f14391b30074e89e60ee7a22f87217b2f6eb0c4b94c9115b436e6fa4607e95a98de30a435",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "199d282a389d4dbc1991eecfd5455f9a84ba23dd8229d6fd045d9f4b77b1a4d3fcdeffc95cd270a63df35bbc5a5c49d4",
+                    "16e451c6dd0fda21510fa207db905f6f29d2f3f90f54b8a6340f5b2ba393afbd504a51ada9ea3e6cdff39ff6c72baea1",
+                ],
+                [
+                    "0bf91670a17a46bfadc5ea2d7874aef4ad2d794c549fa74d7ff7d1bd8ba4ba23322e23545100443bc798fe0efc322ec8",
+                    "0c78bd191c411d8d499b453ce46505bdb33aa109c39a2838208b4d74654c3f1e21112a9a041eb1642502c9a89d0f773c",
+                ],
+            ],
+            [
+                [
+                    "075cffd7ed388b29c9512b4c94293426d34a36de2d56937e7ae59d9e8551554f40f4b3f3ad35e5a7439093674b677c00",
+                    "0f5b7f37a1ccd381120e7d636fd5cb583d1d1f4f1dce74b056e8faa7478a22a36f493f14c5d04cc7d2907b12e7dc0789",
+                ],
+                [
+                    "0dc422d7b285dd7b6ab072598691e8851b24c71ddd9208617bcc44e900293981614303daea51f7632041aa3f679e45d8",
+                    "070134804bdc43cee8f93164373fbcd8c35899aa9c70882197dcd1091c979a0bb8082e28bfae315e373ed81134837b93",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "019ad3fc9c72425a998d7ab1ea0e646a1f6093444fc6965f1cad5a3195a7b1e099c050d57f45e3fa191cc6d75ed7458c",
+                    "171c88b0b0efb5eb2b88913a9e74fe111a4f68867b59db252ce5868af4d1254bfab77ebde5d61cd1a86fb2fe4a5a1c1d",
+                ],
+                [
+                    "0ba10604e62bdd9eeeb4156652066167b72c8d743b050fb4c1016c31b505129374f76e03fa127d6a156213576910fef3",
+                    "0eb22c7a543d3d376e9716a49b72e79a89c9bfe9feee8533ed931cbb5373dde1fbcd7411d8052e02693654f71e15410a",
+                ],
+            ],
+            [
+                [
+                    "113d2b9cd4bd98aee53470b27abc658d91b47a78a51584f3d4b950677cfb8a3e99c24222c406128c91296ef6b45608be",
+                    "13855912321c5cb793e9d1e88f6f8d342d49c0b0dbac613ee9e17e3c0b3c97dfbb5a49cc3fb45102fdbaf65e0efe2632",
+                ],
+                [
+                    "0fd3def0b7574a1d801be44fde617162aa2e89da47f464317d9bb5abc3a7071763ce74180883ad7ad9a723a9afafcdca",
+                    "056f617902b3c0d0f78a9a8cbda43a26b65f602f8786540b9469b060db7b38417915b413ca65f875c130bebfaa59790c",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+            ],
+            [
+                "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "abc",
+        u: &[
+            [
+                "15f7c0aa8f6b296ab5ff9c2c7581ade64f4ee6f1bf18f55179ff44a2cf355fa53dd2a2158c5ecb17d7c52f63e7195771",
+                "01c8067bf4c0ba709aa8b9abc3d1cef589a4758e09ef53732d670fd8739a7274e111ba2fcaa71b3d33df2a3a0c8529dd",
+            ],
+            [
+                "187111d5e088b6b9acfdfad078c4dacf72dcd17ca17c82be35e79f8c372a693f60a033b461d81b025864a0ad051a06e4",
+                "08b852331c96ed983e497ebc6dee9b75e373d923b729194af8e72a051ea586f3538a6ebb1e80881a082fa2b24df9f566",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "0b3fff7dc6a7015883648df186d36db0b46237c33d3695faf503c4ce8cce9f411df898870f88e9ce0476eeeb3633a874",
+                    "0fae7b91244f19582b8dd1d36934d2f02fe174dd5bd353744d06147e54ffe41ebd3e0c525395e481e724a09af0345ebc",
+                ],
+                [
+                    "04ca263f52900ae8ddf50a60dd36496c34e5e01403cfbd31bd6113f8431ea29723d11b68b592aeb09c2b8f74a755d2a1",
+                    "0a71d03f376bd39f84bc7df6f2f9b982fb9ed40dc9c1405e4c459f5845f48ea6e6e9049f8af784f46c8a7cf27a038d7e",
+                ],
+            ],
+            [
+                [
+                    "051633200f8f3a36eebc0e4dd39e8071652f1839739df558e61a8fa2fb6bc14224e65556b49b5c62d4b5dffeaace9bf6",
+                    "19b5059b137b9bc859ca91785fe348ba495b9a2c6026dc35a30f9e406dbde9d1610d54b0d341a89cdab216748893b343",
+                ],
+                [
+                    "0b19a26edd8ce57cb53691ecd5884bbb864e89ba014bcfaf452367f8d5bafa176f83882fee50e64d043c4c025b51e318",
+                    "0a2e80b1ae2fccd151fbaee0f038d2c6d91723bed492105875e2e98bd4e8cd95bc4c67c3b1a853f404bc6bd0a9f012e1",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "12b2e525281b5f4d2276954e84ac4f42cf4e13b6ac4228624e17760faf94ce5706d53f0ca1952f1c5ef75239aeed55ad",
+                    "05d8a724db78e570e34100c0bc4a5fa84ad5839359b40398151f37cff5a51de945c563463c9efbdda569850ee5a53e77",
+                ],
+                [
+                    "02eacdc556d0bdb5d18d22f23dcb086dd106cad713777c7e6407943edbe0b3d1efe391eedf11e977fac55f9b94f2489c",
+                    "04bbe48bfd5814648d0b9e30f0717b34015d45a861425fabc1ee06fdfce36384ae2c808185e693ae97dcde118f34de41",
+                ],
+            ],
+            [
+                [
+                    "19f18cc5ec0c2f055e47c802acc3b0e40c337256a208001dde14b25afced146f37ea3d3ce16834c78175b3ed61f3c537",
+                    "15b0dadc256a258b4c68ea43605dffa6d312eef215c19e6474b3e101d33b661dfee43b51abbf96fee68fc6043ac56a58",
+                ],
+                [
+                    "05e47c1781286e61c7ade887512bd9c2cb9f640d3be9cf87ea0bad24bd0ebfe946497b48a581ab6c7d4ca74b5147287f",
+                    "19f98db2f4a1fcdf56a9ced7b320ea9deecf57c8e59236b0dc21f6ee7229aa9705ce9ac7fe7a31c72edca0d92370c096",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+            ],
+            [
+                "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "abcdef0123456789",
+        u: &[
+            [
+                "0313d9325081b415bfd4e5364efaef392ecf69b087496973b229303e1816d2080971470f7da112c4eb43053130b785e1",
+                "062f84cb21ed89406890c051a0e8b9cf6c575cf6e8e18ecf63ba86826b0ae02548d83b483b79e48512b82a6c0686df8f",
+            ],
+            [
+                "1739123845406baa7be5c5dc74492051b6d42504de008c635f3535bb831d478a341420e67dcc7b46b2e8cba5379cca97",
+                "01897665d9cb5db16a27657760bbea7951f67ad68f8d55f7113f24ba6ddd82caef240a9bfa627972279974894701d975",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "0391bc65078c4f38e8f26bd6b067b8b8149c4edde2219df1307453a6e95240c347481750c7c0cac7088fa3394e449d69",
+                    "0ad97ea56b4beba859384de2ddf74b47f3fdc920af628d8d1454e4fd4d7494a82ac90c09aafc3f5ea6739c6e56deb3df",
+                ],
+                [
+                    "018edeac8b2cf359456e51abe43192f9c32856c79e757115507d27d3aa7149be89e1d96a2a7957fa72ce1a2a301c25ab",
+                    "1660e3bd183b53a1dfc5eec9ce5deed8e59c8eafd727595f4dee9c462226f20e0e44ea6bd9b03ce5f2580a444d83a062",
+                ],
+            ],
+            [
+                [
+                    "0b16d44e8651ea260756f5228e3d4f0c0012d1e8b282be0c251843446d7277d1dddbbcd6927dafa7cdb47deb052fb09e",
+                    "010bb5a7ff40ddf6d2a56ee2625773b7cb47c0696fd0caa009df6972ab483444c1eef650264d780d4b7e4a591d02faad",
+                ],
+                [
+                    "0455525b40ebdde78eabf375c25ebc08cbb07d1fc975e31a74d53456dec366d22ad15b37a00ee62ac7e602737f8a39fb",
+                    "10c30c18c53cfd3282c602fdfbfd85e0752bb48c3a1f54effdfdafe5c7425a9e6399fde1c29a4452ca6600851dc0ed2e",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "0f48f1ea1318ddb713697708f7327781fb39718971d72a9245b9731faaca4dbaa7cca433d6c434a820c28b18e20ea208",
+                    "06051467c8f85da5ba2540974758f7a1e0239a5981de441fdd87680a995649c211054869c50edbac1f3a86c561ba3162",
+                ],
+                [
+                    "168b3d6df80069dbbedb714d41b32961ad064c227355e1ce5fac8e105de5e49d77f0c64867f3834848f152497eb76333",
+                    "134e0e8331cee8cb12f9c2d0742714ed9eee78a84d634c9a95f6a7391b37125ed48bfc6e90bf3546e99930ff67cc97bc",
+                ],
+            ],
+            [
+                [
+                    "004fd03968cd1c99a0dd84551f44c206c84dcbdb78076c5bfee24e89a92c8508b52b88b68a92258403cbe1ea2da3495f",
+                    "1674338ea298281b636b2eb0fe593008d03171195fd6dcd4531e8a1ed1f02a72da238a17a635de307d7d24aa2d969a47",
+                ],
+                [
+                    "0dc7fa13fff6b12558419e0a1e94bfc3cfaf67238009991c5f24ee94b632c3d09e27eca329989aee348a67b50d5e236c",
+                    "169585e164c131103d85324f2d7747b23b91d66ae5d947c449c8194a347969fc6bbd967729768da485ba71868df8aed2",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0",
+                "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c",
+            ],
+            [
+                "05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+                "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        u: &[
+            [
+                "025820cefc7d06fd38de7d8e370e0da8a52498be9b53cba9927b2ef5c6de1e12e12f188bbc7bc923864883c57e49e253",
+                "034147b77ce337a52e5948f66db0bab47a8d038e712123bb381899b6ab5ad20f02805601e6104c29df18c254b8618c7b",
+            ],
+            [
+                "0930315cae1f9a6017c3f0c8f2314baa130e1cf13f6532bff0a8a1790cd70af918088c3db94bda214e896e1543629795",
+                "10c4df2cacf67ea3cb3108b00d4cbd0b3968031ebc8eac4b1ebcefe84d6b715fde66bef0219951ece29d1facc8a520ef",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "17826fecd1c4fc31a4b0194537ca3af502e64c5617eb96bd1bfcf2bb5efd079d3c9ceeb7663cb7b2946fa80a63af110d",
+                    "04111fcd500506b2a2aa64f4c11eafcc4bc563741d8a00b8b0b79f3e0bca2f286c4e16b120f01413408c44a8b7c6022e",
+                ],
+                [
+                    "108faacab1ce701dca21b80e5b85f84363d5244a213c866ebeb8bb3a3990e588db3b9c2ac15f3754498c37c1de8e1509",
+                    "04dee529192721f45d70f3a73107bc95dfa360f126de6d4887f18b37ea8456abfcfdd6ca78815b4373ac97a6794a02b4",
+                ],
+            ],
+            [
+                [
+                    "14ca7775b78d7cf538b1ec1f1eddceb4028aca5eb739fe78f567b47cd774e0b561e20f03e123f9fad1204cc389d7466a",
+                    "09d98c4e1af36d10d5a48b913eb6f898aa30b69ad51cb2c12339dd807225e6c91f2188f68e700a3e17b03adacbe74045",
+                ],
+                [
+                    "115a9ac44217757fed6907065e53296c9f1d3087cba545ed46d3495dfc714c40542607f58237042ec44d5fdd74b17117",
+                    "1390ef958be068b440f2060e929fde863edfb1f9af849a17732976f076de95e49b5f943f5b04eb83ed1701fbfb2fbf55",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "09eccbc53df677f0e5814e3f86e41e146422834854a224bf5a83a50e4cc0a77bfc56718e8166ad180f53526ea9194b57",
+                    "0c3633943f91daee715277bd644fba585168a72f96ded64fc5a384cce4ec884a4c3c30f08e09cd2129335dc8f67840ec",
+                ],
+                [
+                    "0eb6186a0457d5b12d132902d4468bfeb7315d83320b6c32f1c875f344efcba979952b4aa418589cb01af712f98cc555",
+                    "119e3cf167e69eb16c1c7830e8df88856d48be12e3ff0a40791a5cd2f7221311d4bf13b1847f371f467357b3f3c0b4c7",
+                ],
+            ],
+            [
+                [
+                    "0eb3aabc1ddfce17ff18455fcc7167d15ce6b60ddc9eb9b59f8d40ab49420d35558686293d046fc1e42f864b7f60e381",
+                    "198bdfb19d7441ebcca61e8ff774b29d17da16547d2c10c273227a635cacea3f16826322ae85717630f0867539b5ed8b",
+                ],
+                [
+                    "0aaf1dee3adf3ed4c80e481c09b57ea4c705e1b8d25b897f0ceeec3990748716575f92abff22a1c8f4582aff7b872d52",
+                    "0d058d9061ed27d4259848a06c96c5ca68921a5d269b078650c882cb3c2bd424a8702b7a6ee4e0ead9982baf6843e924",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "19a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da",
+                "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb91",
+            ],
+            [
+                "14f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+                "09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e5662",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        u: &[
+            [
+                "190b513da3e66fc9a3587b78c76d1d132b1152174d0b83e3c1114066392579a45824c5fa17649ab89299ddd4bda54935",
+                "12ab625b0fe0ebd1367fe9fac57bb1168891846039b4216b9d94007b674de2d79126870e88aeef54b2ec717a887dcf39",
+            ],
+            [
+                "0e6a42010cf435fb5bacc156a585e1ea3294cc81d0ceb81924d95040298380b164f702275892cedd81b62de3aba3f6b5",
+                "117d9a0defc57a33ed208428cb84e54c85a6840e7648480ae428838989d25d97a0af8e3255be62b25c2a85630d2dddd8",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "0460e19c147bcf87e897ca7b42ff19c5505587347eec69e432ce67ca860c5469e6f90e517de2317458e6b2c3fbdbe015",
+                    "0d9c37f2ed441a06d32ec92f2a5bff3be32227db7ad9186ea9b4ba57c8fd53beed5c9756e2361c4100f48c0c94b2153b",
+                ],
+                [
+                    "0b4d59c8bb8da2691c018c100c3fda620a7686405986705e28cd83b6f3975ad6d83cb7ef1768e578de96044de1b4cf6b",
+                    "0741e5aedf772fc6fae66c113a764e7c07988b06e72d7933f4a210b3ab7993a74a12419000048af91eb611df266d2196",
+                ],
+            ],
+            [
+                [
+                    "076011ddde33c80da7a22b549d492d36a6e8a715a2355d6732c43fe484bdb3b12c70aac51549f4c89f15109c2db41bdd",
+                    "0236a5505ee4173d4d99105d25887b88410d57edce39126b40c36a343b2b991c0517238f98273ce179e6e4d4e8e87edc",
+                ],
+                [
+                    "19508a97b93ebbfb700073b5e055a5733c18550085a6ff8f42eb0d3c65fb196df7fd9d6790322b9f47e86f4a19067615",
+                    "1212ef491b76c1e02cdb55049c911f4bad1c928d50abae53b109d76349609c59ec7e88212841cdb6a6ba744800e5e470",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "17cadf8d04a1a170f8347d42856526a24cc466cb2ddfd506cff01191666b7f944e31244d662c904de5440516a2b09004",
+                    "0d13ba91f2a8b0051cf3279ea0ee63a9f19bc9cb8bfcc7d78b3cbd8cc4fc43ba726774b28038213acf2b0095391c523e",
+                ],
+                [
+                    "17ef19497d6d9246fa94d35575c0f8d06ee02f21a284dbeaa78768cb1e25abd564e3381de87bda26acd04f41181610c5",
+                    "12c3c913ba4ed03c24f0721a81a6be7430f2971ffca8fd1729aafe496bb725807531b44b34b59b3ae5495e5a2dcbd5c8",
+                ],
+            ],
+            [
+                [
+                    "16ec57b7fe04c71dfe34fb5ad84dbce5a2dbbd6ee085f1d8cd17f45e8868976fc3c51ad9eeda682c7869024d24579bfd",
+                    "13103f7aace1ae1420d208a537f7d3a9679c287208026e4e3439ab8cd534c12856284d95e27f5e1f33eec2ce656533b0",
+                ],
+                [
+                    "0958b2c4c2c10fcef5a6c59b9e92c4a67b0fae3e2e0f1b6b5edad9c940b8f3524ba9ebbc3f2ceb3cfe377655b3163bd7",
+                    "0ccb594ed8bd14ca64ed9cb4e0aba221be540f25dd0d6ba15a4a4be5d67bcf35df7853b2d8dad3ba245f1ea3697f66aa",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "01a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534",
+                "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d01569",
+            ],
+            [
+                "0b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+                "03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab52",
+            ],
+        ],
+    },
+];
+
+const G2_NU_DST: &str = "QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_";
+
+const G2_NU_VECTORS: [G2Vector; 5] = [
+    G2Vector {
+        msg: "",
+        u: &[
+            [
+                "07355d25caf6e7f2f0cb2812ca0e513bd026ed09dda65b177500fa31714e09ea0ded3a078b526bed3307f804d4b93b04",
+                "02829ce3c021339ccb5caf3e187f6370e1e2a311dec9b75363117063ab2015603ff52c3d3b98f19c2f65575e99e8b78c",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "116dee9e272feaadb2b5fe8ca1f803d1ecbfaffe61da71c0a9cb6232a8a6a545e3de79d7cb991c4fe96dd971f08ca54c",
+                    "0abe26054b468d22938fb182f0c730d395092d796e92d95b8177da4337c115328e98d0b00b12a83e5bcf8a33077762d2",
+                ],
+                [
+                    "05f3ab6cd0a32c09ba9191155347f13b410cc15daac77ec2d449915fa7b9ed8b9b7415222be2314f3c6da5290489c3d2",
+                    "049dc35f96bbb926340f1135efc9f3a233482d77f2e18b611f0c15131aeb6868c837419b647601c6a5aa1624b46323e9",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "18ed3794ad43c781816c523776188deafba67ab773189b8f18c49bc7aa841cd81525171f7a5203b2a340579192403bef",
+                    "0727d90785d179e7b5732c8a34b660335fed03b913710b60903cf4954b651ed3466dc3728e21855ae822d4a0f1d06587",
+                ],
+                [
+                    "00764a5cf6c5f61c52c838523460eb2168b5a5b43705e19cb612e006f29b717897facfd15dd1c8874c915f6d53d0342d",
+                    "19290bb9797c12c1d275817aa2605ebe42275b66860f0e4d04487ebc2e47c50b36edd86c685a60c20a2bd584a82b011a",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7",
+                "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b",
+            ],
+            [
+                "0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+                "1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "abc",
+        u: &[
+            [
+                "138879a9559e24cecee8697b8b4ad32cced053138ab913b99872772dc753a2967ed50aabc907937aefb2439ba06cc50c",
+                "0a1ae7999ea9bab1dcc9ef8887a6cb6e8f1e22566015428d220b7eec90ffa70ad1f624018a9ad11e78d588bd3617f9f2",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "10d88e6de34eb55d0f450774ff58a435a0d2bbfda17affd9d746c577c66469a706dc1816fcfe554fc43c1ff7b2d7645a",
+                    "1773a2aea219a1432dc6c06d5de06c0de0a3270d86e3e93f00843ede42139532c4065abfc7f9485247ccf6280d25bf6b",
+                ],
+                [
+                    "149fd8f0b7493549522687678f7fbbed6a3db297c6a96e7fd92889cc8bc6ded423cabd6e834f8e1933df7daec06a79ee",
+                    "0928d6dc7f83fe8cc4136e622fca7234103b20283e08afdf0eec2e92427937847c2b37789f7d8b3f6ab8f59c50d15a1d",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "0f40e1d5025ecef0d850aa0bb7bbeceab21a3d4e85e6bee857805b09693051f5b25428c6be343edba5f14317fcc30143",
+                    "02e0d261f2b9fee88b82804ec83db330caa75fbb12719cfa71ccce1c532dc4e1e79b0a6a281ed8d3817524286c8bc04c",
+                ],
+                [
+                    "0cf4a4adc5c66da0bca4caddc6a57ecd97c8252d7526a8ff478e0dfed816c4d321b5c3039c6683ae9b1e6a3a38c9c0ae",
+                    "11cad1646bb3768c04be2ab2bbe1f80263b7ff6f8f9488f5bc3b6850e5a3e97e20acc583613c69cf3d2bfe8489744ebb",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "108ed59fd9fae381abfd1d6bce2fd2fa220990f0f837fa30e0f27914ed6e1454db0d1ee957b219f61da6ff8be0d6441f",
+                "0296238ea82c6d4adb3c838ee3cb2346049c90b96d602d7bb1b469b905c9228be25c627bffee872def773d5b2a2eb57d",
+            ],
+            [
+                "033f90f6057aadacae7963b0a0b379dd46750c1c94a6357c99b65f63b79e321ff50fe3053330911c56b6ceea08fee656",
+                "153606c417e59fb331b7ae6bce4fbf7c5190c33ce9402b5ebe2b70e44fca614f3f1382a3625ed5493843d0b0a652fc3f",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "abcdef0123456789",
+        u: &[
+            [
+                "18c16fe362b7dbdfa102e42bdfd3e2f4e6191d479437a59db4eb716986bf08ee1f42634db66bde97d6c16bbfd342b3b8",
+                "0e37812ce1b146d998d5f92bdd5ada2a31bfd63dfe18311aa91637b5f279dd045763166aa1615e46a50d8d8f475f184e",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "0ffb76be4d569ce9402a76c0cec83116aa14053e1bde56549c5938b7e3fbc15e5a048e338c6d1e77705611458013e3c6",
+                    "0e7fadeaa268b1fdd8c47719eaa787233cad7a1a3b7fbc4f8872968f679c865735b6aec776f973dd3333ce33bb5a7179",
+                ],
+                [
+                    "09abee9886d572a1df1eab94f8d9fb50feb6c7bd5ebc48563dac6b569c5275394120fa30aae050f6ea72b621828e1c5c",
+                    "112dd7aef6c97a6f118e9ff8bbd50f34c077a92e4bcf50a04c25a5241e6592714d66a06fac970af8b1cdbd43236a6a2b",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "13a9d4a738a85c9f917c7be36b240915434b58679980010499b9ae8d7a1bf7fbe617a15b3cd6060093f40d18e0f19456",
+                    "16fa88754e7670366a859d6f6899ad765bf5a177abedb2740aacc9252c43f90cd0421373fbd5b2b76bb8f5c4886b5d37",
+                ],
+                [
+                    "0a7fa7d82c46797039398253e8765a4194100b330dfed6d7fbb46d6fbf01e222088779ac336e3675c7a7a0ee05bbb6e3",
+                    "0c6ee170ab766d11fa9457cef53253f2628010b2cffc102b3b28351eb9df6c281d3cfc78e9934769d661b72a5265338d",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "038af300ef34c7759a6caaa4e69363cafeed218a1f207e93b2c70d91a1263d375d6730bd6b6509dcac3ba5b567e85bf3",
+                "0da75be60fb6aa0e9e3143e40c42796edf15685cafe0279afd2a67c3dff1c82341f17effd402e4f1af240ea90f4b659b",
+            ],
+            [
+                "19b148cbdf163cf0894f29660d2e7bfb2b68e37d54cc83fd4e6e62c020eaa48709302ef8e746736c0e19342cc1ce3df4",
+                "0492f4fed741b073e5a82580f7c663f9b79e036b70ab3e51162359cec4e77c78086fe879b65ca7a47d34374c8315ac5e",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqq",
+        u: &[
+            [
+                "08d4a0997b9d52fecf99427abb721f0fa779479963315fe21c6445250de7183e3f63bfdf86570da8929489e421d4ee95",
+                "16cb4ccad91ec95aab070f22043916cd6a59c4ca94097f7f510043d48515526dc8eaaea27e586f09151ae613688d5a89",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "19eb9bdaed7afdbe6908af1e42ae6e6f88ba4044493153342552ffb03098b8a842c3ceeb09543419e04e48fe2fa092f7",
+                    "16de573f38f59a1ecbfec1173d58c15e2cea0a98b5c6ad41511dadcfa4ccfd37d761f35dbc673ca07689c1cce383ea8c",
+                ],
+                [
+                    "0ebbfbf00803c2ffdfbb948e34bccb0b400059740ebdb159594ffbe9efcaf3d85058505946bc91f07e5545e9305a54a7",
+                    "1159758ae35886d06d0ab163a33c605ab686563517e5202591fdc60891aba6716c8502637b50bb4ebb27fa86a6107064",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "0a08b2f639855dfdeaaed972702b109e2241a54de198b2b4cd12ad9f88fa419a6086a58d91fc805de812ea29bee427c2",
+                    "04a7442e4cb8b42ef0f41dac9ee74e65ecad3ce0851f0746dc47568b0e7a8134121ed09ba054509232c49148aef62cda",
+                ],
+                [
+                    "05d60b1f04212b2c87607458f71d770f43973511c260f0540eef3a565f42c7ce59aa1cea684bb2a7bcab84acd2f36c8c",
+                    "1017aa5747ba15505ece266a86b0ca9c712f41a254b76ca04094ca442ce45ecd224bd5544cd16685d0d1b9d156dd0531",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "0c5ae723be00e6c3f0efe184fdc0702b64588fe77dda152ab13099a3bacd3876767fa7bbad6d6fd90b3642e902b208f9",
+                "12c8c05c1d5fc7bfa847f4d7d81e294e66b9a78bc9953990c358945e1f042eedafce608b67fdd3ab0cb2e6e263b9b1ad",
+            ],
+            [
+                "04e77ddb3ede41b5ec4396b7421dd916efc68a358a0d7425bddd253547f2fb4830522358491827265dfc5bcc1928a569",
+                "11c624c56dbe154d759d021eec60fab3d8b852395a89de497e48504366feedd4662d023af447d66926a28076813dd646",
+            ],
+        ],
+    },
+    G2Vector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaa",
+        u: &[
+            [
+                "03f80ce4ff0ca2f576d797a3660e3f65b274285c054feccc3215c879e2c0589d376e83ede13f93c32f05da0f68fd6a10",
+                "006488a837c5413746d868d1efb7232724da10eca410b07d8b505b9363bdccf0a1fc0029bad07d65b15ccfe6dd25e20d",
+            ],
+        ],
+        iso: &[
+            [
+                [
+                    "02c02011fa316807c2d33d2fbe20454f3995102b86564dd6fbc8f23612cd0df380894ec8b17099f57354687089e89735",
+                    "15990aaf866c5ee85cab7703164d07f9a794b4875131a644d5369f9631e9e86ea9a382ca683cc2c21ed7fe258dd6fdc5",
+                ],
+                [
+                    "19a765d14c1f5858a2aa07fa9ee250aadd2785f5535707b40057127d1464bba386c2cac6e49a1d9b1e8887cbec1fdd30",
+                    "076a1868eaaa517fffc7f8783ee896ddc554b04c1bb9c37bc5fa59b4547eae35c69cfee238802e0030bba9e57c263118",
+                ],
+            ],
+        ],
+        q: &[
+            [
+                [
+                    "19592c812d5a50c5601062faba14c7d670711745311c879de1235a0a11c75aab61327bf2d1725db07ec4d6996a682886",
+                    "0eef4fa41ddc17ed47baf447a2c498548f3c72a02381313d13bef916e240b61ce125539090d62d9fbb14a900bf1b8e90",
+                ],
+                [
+                    "1260d6e0987eae96af9ebe551e08de22b37791d53f4db9e0d59da736e66699735793e853e26362531fe4adf99c1883e3",
+                    "0dbace5df0a4ac4ac2f45d8fdf8aee45484576fdd6efc4f98ab9b9f4112309e628255e183022d98ea5ed6e47ca00306c",
+                ],
+            ],
+        ],
+        p: [
+            [
+                "0ea4e7c33d43e17cc516a72f76437c4bf81d8f4eac69ac355d3bf9b71b8138d55dc10fd458be115afa798b55dac34be1",
+                "1565c2f625032d232f13121d3cfb476f45275c303a037faa255f9da62000c2c864ea881e2bcddd111edc4a3c0da3e88d",
+            ],
+            [
+                "043b6f5fe4e52c839148dc66f2b3751e69a0f6ebb3d056d6465d50d4108543ecd956e10fa1640dfd9bc0030cc2558d28",
+                "0f8991d2a1ad662e7b6f58ab787947f1fa607fce12dde171bc17903b012091b657e15333e11701edcf5b63ba2a561247",
+            ],
+        ],
+    },
+];
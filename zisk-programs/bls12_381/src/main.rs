@@ -1,6 +1,7 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
+mod bilinearity;
 mod constants;
 mod cyclotomic;
 mod final_exp;
@@ -8,19 +9,33 @@ mod fp;
 mod fp12;
 mod fp2;
 mod fp6;
+mod hash_to_curve;
 mod pairing;
+mod properties;
+mod signature;
 mod twist;
 
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use bilinearity::bilinearity_tests;
 use cyclotomic::cyclotomic_tests;
 use final_exp::final_exp_tests;
 use fp::fp_tests;
 use fp12::fp12_tests;
 use fp2::fp2_tests;
 use fp6::fp6_tests;
+use hash_to_curve::hash_to_curve_tests;
 use pairing::pairing_valid_tests;
+use properties::property_tests;
+use signature::signature_tests;
 use twist::twist_tests;
 
 fn main() {
+    // Get the PRNG seed, the number of random elements per tower level and the maximum number of
+    // pairs per pairing check from ziskos
+    let (seed, iterations, max_pairs): (u64, u64, u64) = ziskos::io::read();
+
     // Fp
     fp_tests();
 
@@ -45,4 +60,21 @@ fn main() {
     // Pairing
     pairing_valid_tests();
     // pairing_invalid_tests();
+
+    // Hash to curve
+    hash_to_curve_tests();
+
+    // BLS signatures
+    signature_tests();
+
+    // Field tower and twist properties on random elements
+    println!(
+        "Running field tower and twist properties with seed {seed} for {iterations} iterations"
+    );
+    let mut rng = StdRng::seed_from_u64(seed);
+    property_tests(&mut rng, iterations);
+
+    // Pairing bilinearity on random points
+    println!("Running pairing bilinearity with up to {max_pairs} pairs per check");
+    bilinearity_tests(&mut rng, iterations, max_pairs);
 }
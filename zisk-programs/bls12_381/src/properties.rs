@@ -0,0 +1,335 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use ziskos::zisklib::{
+    add_fp2_bls12_381, add_fp6_bls12_381, add_fp_bls12_381, add_twist_bls12_381,
+    compress_cyclo_bls12_381, conjugate_fp12_bls12_381, conjugate_fp2_bls12_381, dbl_fp2_bls12_381,
+    dbl_fp6_bls12_381, dbl_twist_bls12_381, decompress_cyclo_bls12_381, exp_by_x_cyclo_bls12_381,
+    exp_by_xdiv3_cyclo_bls12_381, exp_by_xone_cyclo_bls12_381, exp_fp12_bls12_381,
+    frobenius1_fp12_bls12_381, frobenius2_fp12_bls12_381, inv_fp12_bls12_381, inv_fp2_bls12_381,
+    inv_fp6_bls12_381, inv_fp_bls12_381, is_on_curve_twist_bls12_381,
+    is_on_subgroup_twist_bls12_381, mul_fp12_bls12_381, mul_fp2_bls12_381, mul_fp6_bls12_381,
+    mul_fp_bls12_381, neg_fp2_bls12_381, neg_fp6_bls12_381, neg_fp_bls12_381, neg_twist_bls12_381,
+    scalar_mul_by_abs_x_twist_bls12_381, scalar_mul_fp2_bls12_381, scalar_mul_twist_bls12_381,
+    square_cyclo_bls12_381, square_fp12_bls12_381, square_fp2_bls12_381, square_fp_bls12_381,
+    sub_fp2_bls12_381, sub_fp6_bls12_381,
+};
+
+use crate::constants::{G2, IDENTITY_G2, P, R, X_ABS};
+
+/// Random element of Fp, sampled uniformly by rejection
+fn random_fp(rng: &mut StdRng) -> [u64; 6] {
+    loop {
+        let mut a: [u64; 6] = rng.gen();
+        // P has 381 bits, so the top limb never needs more than 61 bits
+        a[5] &= (1 << 61) - 1;
+        // Compare limbs from the most significant one
+        if a.iter().rev().lt(P.iter().rev()) {
+            return a;
+        }
+    }
+}
+
+/// Random element of a tower level with `N` limbs
+fn random_ext<const N: usize>(rng: &mut StdRng) -> [u64; N] {
+    let mut a = [0u64; N];
+    for chunk in a.chunks_mut(6) {
+        chunk.copy_from_slice(&random_fp(rng));
+    }
+    a
+}
+
+/// Random point of the G2 subgroup of the twist
+fn random_g2(rng: &mut StdRng) -> [u64; 24] {
+    let k: [u64; 4] = rng.gen();
+    scalar_mul_twist_bls12_381(&G2, &k)
+}
+
+/// Addition in Fp12 = Fp6[w], component-wise over Fp6
+fn add_fp12(a: &[u64; 72], b: &[u64; 72]) -> [u64; 72] {
+    let mut res = [0u64; 72];
+    for i in [0, 36] {
+        let a = a[i..i + 36].try_into().unwrap();
+        let b = b[i..i + 36].try_into().unwrap();
+        res[i..i + 36].copy_from_slice(&add_fp6_bls12_381(a, b));
+    }
+    res
+}
+
+/// Multiplicative identity of a tower level with `N` limbs
+fn one<const N: usize>() -> [u64; N] {
+    let mut one = [0u64; N];
+    one[0] = 1;
+    one
+}
+
+/// Checks the field axioms and the tower-specific identities on `iterations` random elements
+/// of every level of the BLS12-381 tower, and the group law of the twist
+pub fn property_tests(rng: &mut StdRng, iterations: u64) {
+    for _ in 0..iterations {
+        fp_properties(rng);
+        fp2_properties(rng);
+        fp6_properties(rng);
+        fp12_properties(rng);
+        cyclotomic_properties(rng);
+        twist_properties(rng);
+    }
+}
+
+fn fp_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_fp(rng), random_fp(rng), random_fp(rng));
+    let zero = [0u64; 6];
+
+    // Commutativity and associativity
+    assert_eq!(add_fp_bls12_381(&a, &b), add_fp_bls12_381(&b, &a));
+    assert_eq!(mul_fp_bls12_381(&a, &b), mul_fp_bls12_381(&b, &a));
+    assert_eq!(
+        add_fp_bls12_381(&add_fp_bls12_381(&a, &b), &c),
+        add_fp_bls12_381(&a, &add_fp_bls12_381(&b, &c))
+    );
+    assert_eq!(
+        mul_fp_bls12_381(&mul_fp_bls12_381(&a, &b), &c),
+        mul_fp_bls12_381(&a, &mul_fp_bls12_381(&b, &c))
+    );
+
+    // Distributivity
+    assert_eq!(
+        mul_fp_bls12_381(&a, &add_fp_bls12_381(&b, &c)),
+        add_fp_bls12_381(&mul_fp_bls12_381(&a, &b), &mul_fp_bls12_381(&a, &c))
+    );
+
+    // Additive inverse
+    assert_eq!(add_fp_bls12_381(&a, &neg_fp_bls12_381(&a)), zero);
+    assert_eq!(neg_fp_bls12_381(&neg_fp_bls12_381(&a)), a);
+
+    // Square is mul by self
+    assert_eq!(square_fp_bls12_381(&a), mul_fp_bls12_381(&a, &a));
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp_bls12_381(&a, &inv_fp_bls12_381(&a)), one::<6>());
+        assert_eq!(inv_fp_bls12_381(&inv_fp_bls12_381(&a)), a);
+    }
+}
+
+fn fp2_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_ext::<12>(rng), random_ext::<12>(rng), random_ext::<12>(rng));
+    let zero = [0u64; 12];
+
+    // Commutativity and associativity
+    assert_eq!(add_fp2_bls12_381(&a, &b), add_fp2_bls12_381(&b, &a));
+    assert_eq!(mul_fp2_bls12_381(&a, &b), mul_fp2_bls12_381(&b, &a));
+    assert_eq!(
+        add_fp2_bls12_381(&add_fp2_bls12_381(&a, &b), &c),
+        add_fp2_bls12_381(&a, &add_fp2_bls12_381(&b, &c))
+    );
+    assert_eq!(
+        mul_fp2_bls12_381(&mul_fp2_bls12_381(&a, &b), &c),
+        mul_fp2_bls12_381(&a, &mul_fp2_bls12_381(&b, &c))
+    );
+
+    // Distributivity
+    assert_eq!(
+        mul_fp2_bls12_381(&a, &add_fp2_bls12_381(&b, &c)),
+        add_fp2_bls12_381(&mul_fp2_bls12_381(&a, &b), &mul_fp2_bls12_381(&a, &c))
+    );
+
+    // Additive inverse, subtraction and doubling
+    assert_eq!(add_fp2_bls12_381(&a, &neg_fp2_bls12_381(&a)), zero);
+    assert_eq!(neg_fp2_bls12_381(&neg_fp2_bls12_381(&a)), a);
+    assert_eq!(add_fp2_bls12_381(&sub_fp2_bls12_381(&a, &b), &b), a);
+    assert_eq!(dbl_fp2_bls12_381(&a), add_fp2_bls12_381(&a, &a));
+
+    // Square is mul by self
+    assert_eq!(square_fp2_bls12_381(&a), mul_fp2_bls12_381(&a, &a));
+
+    // Scalar multiplication is multiplication by an element of the base field
+    let s = random_fp(rng);
+    let mut s_fp2 = zero;
+    s_fp2[..6].copy_from_slice(&s);
+    assert_eq!(scalar_mul_fp2_bls12_381(&a, &s), mul_fp2_bls12_381(&a, &s_fp2));
+
+    // Conjugation is a multiplicative involution and the norm lies in the base field
+    assert_eq!(conjugate_fp2_bls12_381(&conjugate_fp2_bls12_381(&a)), a);
+    assert_eq!(
+        conjugate_fp2_bls12_381(&mul_fp2_bls12_381(&a, &b)),
+        mul_fp2_bls12_381(&conjugate_fp2_bls12_381(&a), &conjugate_fp2_bls12_381(&b))
+    );
+    let norm = mul_fp2_bls12_381(&a, &conjugate_fp2_bls12_381(&a));
+    assert_eq!(norm[6..], [0u64; 6]);
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp2_bls12_381(&a, &inv_fp2_bls12_381(&a)), one::<12>());
+        assert_eq!(inv_fp2_bls12_381(&inv_fp2_bls12_381(&a)), a);
+    }
+}
+
+fn fp6_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_ext::<36>(rng), random_ext::<36>(rng), random_ext::<36>(rng));
+    let zero = [0u64; 36];
+
+    // Commutativity and associativity
+    assert_eq!(add_fp6_bls12_381(&a, &b), add_fp6_bls12_381(&b, &a));
+    assert_eq!(mul_fp6_bls12_381(&a, &b), mul_fp6_bls12_381(&b, &a));
+    assert_eq!(
+        add_fp6_bls12_381(&add_fp6_bls12_381(&a, &b), &c),
+        add_fp6_bls12_381(&a, &add_fp6_bls12_381(&b, &c))
+    );
+    assert_eq!(
+        mul_fp6_bls12_381(&mul_fp6_bls12_381(&a, &b), &c),
+        mul_fp6_bls12_381(&a, &mul_fp6_bls12_381(&b, &c))
+    );
+
+    // Distributivity
+    assert_eq!(
+        mul_fp6_bls12_381(&a, &add_fp6_bls12_381(&b, &c)),
+        add_fp6_bls12_381(&mul_fp6_bls12_381(&a, &b), &mul_fp6_bls12_381(&a, &c))
+    );
+
+    // Additive inverse, subtraction and doubling
+    assert_eq!(add_fp6_bls12_381(&a, &neg_fp6_bls12_381(&a)), zero);
+    assert_eq!(neg_fp6_bls12_381(&neg_fp6_bls12_381(&a)), a);
+    assert_eq!(add_fp6_bls12_381(&sub_fp6_bls12_381(&a, &b), &b), a);
+    assert_eq!(dbl_fp6_bls12_381(&a), add_fp6_bls12_381(&a, &a));
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp6_bls12_381(&a, &inv_fp6_bls12_381(&a)), one::<36>());
+        assert_eq!(inv_fp6_bls12_381(&inv_fp6_bls12_381(&a)), a);
+    }
+}
+
+fn fp12_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_ext::<72>(rng), random_ext::<72>(rng), random_ext::<72>(rng));
+    let zero = [0u64; 72];
+
+    // Commutativity and associativity
+    assert_eq!(mul_fp12_bls12_381(&a, &b), mul_fp12_bls12_381(&b, &a));
+    assert_eq!(
+        mul_fp12_bls12_381(&mul_fp12_bls12_381(&a, &b), &c),
+        mul_fp12_bls12_381(&a, &mul_fp12_bls12_381(&b, &c))
+    );
+
+    // Distributivity
+    assert_eq!(
+        mul_fp12_bls12_381(&a, &add_fp12(&b, &c)),
+        add_fp12(&mul_fp12_bls12_381(&a, &b), &mul_fp12_bls12_381(&a, &c))
+    );
+
+    // Square is mul by self, and exponentiation agrees with repeated multiplication
+    assert_eq!(square_fp12_bls12_381(&a), mul_fp12_bls12_381(&a, &a));
+    let e1 = rng.gen_range(0..1u64 << 16);
+    let e2 = rng.gen_range(0..1u64 << 16);
+    assert_eq!(exp_fp12_bls12_381(2, &a), square_fp12_bls12_381(&a));
+    assert_eq!(
+        exp_fp12_bls12_381(e1 + e2, &a),
+        mul_fp12_bls12_381(&exp_fp12_bls12_381(e1, &a), &exp_fp12_bls12_381(e2, &a))
+    );
+
+    // Conjugation is a multiplicative involution
+    assert_eq!(conjugate_fp12_bls12_381(&conjugate_fp12_bls12_381(&a)), a);
+    assert_eq!(
+        conjugate_fp12_bls12_381(&mul_fp12_bls12_381(&a, &b)),
+        mul_fp12_bls12_381(&conjugate_fp12_bls12_381(&a), &conjugate_fp12_bls12_381(&b))
+    );
+
+    // Frobenius: multiplicative, composes as x -> x^(p^k) and has order 12, with
+    // x^(p^6) being the conjugation
+    assert_eq!(
+        frobenius1_fp12_bls12_381(&mul_fp12_bls12_381(&a, &b)),
+        mul_fp12_bls12_381(&frobenius1_fp12_bls12_381(&a), &frobenius1_fp12_bls12_381(&b))
+    );
+    assert_eq!(
+        frobenius1_fp12_bls12_381(&frobenius1_fp12_bls12_381(&a)),
+        frobenius2_fp12_bls12_381(&a)
+    );
+    let frob6 =
+        frobenius2_fp12_bls12_381(&frobenius2_fp12_bls12_381(&frobenius2_fp12_bls12_381(&a)));
+    assert_eq!(frob6, conjugate_fp12_bls12_381(&a));
+
+    let mut frob = a;
+    for i in 1..=12 {
+        frob = frobenius1_fp12_bls12_381(&frob);
+        assert_eq!(frob == a, i == 12, "Frobenius order mismatch at power {i}");
+    }
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp12_bls12_381(&a, &inv_fp12_bls12_381(&a)), one::<72>());
+        assert_eq!(inv_fp12_bls12_381(&inv_fp12_bls12_381(&a)), a);
+    }
+}
+
+fn cyclotomic_properties(rng: &mut StdRng) {
+    // Map a random element to the cyclotomic subgroup with the easy part of the final
+    // exponentiation: a^((p^6 - 1)(p^2 + 1))
+    let a = random_ext::<72>(rng);
+    let g = mul_fp12_bls12_381(&conjugate_fp12_bls12_381(&a), &inv_fp12_bls12_381(&a));
+    let c = mul_fp12_bls12_381(&frobenius2_fp12_bls12_381(&g), &g);
+
+    // Elements of the cyclotomic subgroup are unitary
+    assert_eq!(conjugate_fp12_bls12_381(&c), inv_fp12_bls12_381(&c));
+
+    // Compressed squaring agrees with generic squaring
+    let compressed = compress_cyclo_bls12_381(&c);
+    assert_eq!(decompress_cyclo_bls12_381(&compressed), c);
+    assert_eq!(
+        decompress_cyclo_bls12_381(&square_cyclo_bls12_381(&compressed)),
+        square_fp12_bls12_381(&c)
+    );
+
+    // Exponentiations by the curve parameter agree with generic exponentiation
+    assert_eq!(exp_by_x_cyclo_bls12_381(&c), exp_fp12_bls12_381(X_ABS, &c));
+    assert_eq!(exp_by_xone_cyclo_bls12_381(&c), exp_fp12_bls12_381(X_ABS + 1, &c));
+    assert_eq!(exp_by_xdiv3_cyclo_bls12_381(&c), exp_fp12_bls12_381((X_ABS + 1) / 3, &c));
+}
+
+fn twist_properties(rng: &mut StdRng) {
+    let (p, q, s) = (random_g2(rng), random_g2(rng), random_g2(rng));
+
+    // Subgroup members
+    assert!(is_on_curve_twist_bls12_381(&p));
+    assert!(is_on_subgroup_twist_bls12_381(&p));
+    assert_eq!(scalar_mul_twist_bls12_381(&p, &R), IDENTITY_G2);
+
+    // Group law
+    assert_eq!(add_twist_bls12_381(&p, &q), add_twist_bls12_381(&q, &p));
+    assert_eq!(
+        add_twist_bls12_381(&add_twist_bls12_381(&p, &q), &s),
+        add_twist_bls12_381(&p, &add_twist_bls12_381(&q, &s))
+    );
+    assert_eq!(add_twist_bls12_381(&p, &neg_twist_bls12_381(&p)), IDENTITY_G2);
+    assert_eq!(neg_twist_bls12_381(&neg_twist_bls12_381(&p)), p);
+    assert_eq!(dbl_twist_bls12_381(&p), add_twist_bls12_381(&p, &p));
+
+    // Scalar multiplication is linear: [k1]P + [k2]P = [k1 + k2]P
+    let k1: u128 = rng.gen();
+    let k2: u128 = rng.gen();
+    let (sum, carry) = k1.overflowing_add(k2);
+    let k1 = [k1 as u64, (k1 >> 64) as u64, 0, 0];
+    let k2 = [k2 as u64, (k2 >> 64) as u64, 0, 0];
+    let sum = [sum as u64, (sum >> 64) as u64, carry as u64, 0];
+    assert_eq!(
+        add_twist_bls12_381(
+            &scalar_mul_twist_bls12_381(&p, &k1),
+            &scalar_mul_twist_bls12_381(&p, &k2)
+        ),
+        scalar_mul_twist_bls12_381(&p, &sum)
+    );
+
+    // Multiplication by |x| agrees with double-and-add, and by x = -|x|, taken as r - |x| since
+    // [r]P = O in G2, gives its negation
+    let mut abs_x_p = p;
+    for i in (0..X_ABS.ilog2()).rev() {
+        abs_x_p = dbl_twist_bls12_381(&abs_x_p);
+        if (X_ABS >> i) & 1 == 1 {
+            abs_x_p = add_twist_bls12_381(&abs_x_p, &p);
+        }
+    }
+    assert_eq!(scalar_mul_by_abs_x_twist_bls12_381(&p), abs_x_p);
+    assert_eq!(scalar_mul_twist_bls12_381(&p, &[X_ABS, 0, 0, 0]), abs_x_p);
+    // The low limb of r is above |x|, so r - |x| does not borrow
+    let x = [R[0] - X_ABS, R[1], R[2], R[3]];
+    assert_eq!(scalar_mul_twist_bls12_381(&p, &x), neg_twist_bls12_381(&abs_x_p));
+}
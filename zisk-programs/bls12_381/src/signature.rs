@@ -0,0 +1,136 @@
+use serde::Deserialize;
+use ziskos::zisklib::{
+    add_bls12_381, decompress_bls12_381, decompress_twist_bls12_381, hash_to_curve_twist_bls12_381,
+    is_on_subgroup_bls12_381, is_on_subgroup_twist_bls12_381, neg_twist_bls12_381,
+    pairing_check_bls12_381,
+};
+
+use crate::constants::{G1, IDENTITY_G1};
+
+// BLS signature vectors of the Ethereum consensus specs (tests/generators/bls), one JSON file per
+// handler. The keys and messages are those of the spec generator, and the pubkeys and signatures
+// are the ZCash compressed encodings of the spec's minimal-pubkey-size, proof-of-possession scheme
+
+/// Domain separation tag of the proof-of-possession ciphersuite used by the consensus layer
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+#[derive(Deserialize)]
+struct TestCase<I> {
+    name: String,
+    input: I,
+    output: bool,
+}
+
+#[derive(Deserialize)]
+struct VerifyInput {
+    pubkey: String,
+    message: String,
+    signature: String,
+}
+
+#[derive(Deserialize)]
+struct FastAggregateVerifyInput {
+    pubkeys: Vec<String>,
+    message: String,
+    signature: String,
+}
+
+#[derive(Deserialize)]
+struct AggregateVerifyInput {
+    pubkeys: Vec<String>,
+    messages: Vec<String>,
+    signature: String,
+}
+
+pub fn signature_tests() {
+    // Single signature
+    let tests: Vec<TestCase<VerifyInput>> =
+        serde_json::from_str(include_str!("testdata/verify.json")).expect("valid JSON");
+    for test in tests {
+        let input = test.input;
+        let res = verify(&hex(&input.pubkey), &hex(&input.message), &hex(&input.signature));
+        assert_eq!(res, test.output, "{}", test.name);
+    }
+
+    // Aggregate signature of a single message
+    let tests: Vec<TestCase<FastAggregateVerifyInput>> =
+        serde_json::from_str(include_str!("testdata/fast_aggregate_verify.json"))
+            .expect("valid JSON");
+    for test in tests {
+        let input = test.input;
+        let pubkeys: Vec<Vec<u8>> = input.pubkeys.iter().map(|p| hex(p)).collect();
+        let res = fast_aggregate_verify(&pubkeys, &hex(&input.message), &hex(&input.signature));
+        assert_eq!(res, test.output, "{}", test.name);
+    }
+
+    // Aggregate signature of one message per pubkey
+    let tests: Vec<TestCase<AggregateVerifyInput>> =
+        serde_json::from_str(include_str!("testdata/aggregate_verify.json")).expect("valid JSON");
+    for test in tests {
+        let input = test.input;
+        let pubkeys: Vec<Vec<u8>> = input.pubkeys.iter().map(|p| hex(p)).collect();
+        let messages: Vec<Vec<u8>> = input.messages.iter().map(|m| hex(m)).collect();
+        let res = aggregate_verify(&pubkeys, &messages, &hex(&input.signature));
+        assert_eq!(res, test.output, "{}", test.name);
+    }
+}
+
+fn verify(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Some(pubkey) = key_validate(pubkey) else {
+        return false;
+    };
+    core_aggregate_verify(&[pubkey], &[message.to_vec()], signature)
+}
+
+fn fast_aggregate_verify(pubkeys: &[Vec<u8>], message: &[u8], signature: &[u8]) -> bool {
+    let Some(pubkeys) = pubkeys.iter().map(|p| key_validate(p)).collect::<Option<Vec<_>>>() else {
+        return false;
+    };
+    let Some((first, rest)) = pubkeys.split_first() else {
+        return false;
+    };
+    let aggregate = rest.iter().fold(*first, |acc, p| add_bls12_381(&acc, p));
+    core_aggregate_verify(&[aggregate], &[message.to_vec()], signature)
+}
+
+fn aggregate_verify(pubkeys: &[Vec<u8>], messages: &[Vec<u8>], signature: &[u8]) -> bool {
+    let Some(pubkeys) = pubkeys.iter().map(|p| key_validate(p)).collect::<Option<Vec<_>>>() else {
+        return false;
+    };
+    core_aggregate_verify(&pubkeys, messages, signature)
+}
+
+/// KeyValidate: the pubkey must decode to a point of G1 other than the identity
+fn key_validate(pubkey: &[u8]) -> Option<[u64; 12]> {
+    let pubkey = decompress_bls12_381(pubkey.try_into().ok()?).ok()?;
+    (pubkey != IDENTITY_G1 && is_on_subgroup_bls12_381(&pubkey)).then_some(pubkey)
+}
+
+/// The signature must decode to a point of G2, which may be the identity
+fn signature_to_point(signature: &[u8]) -> Option<[u64; 24]> {
+    let signature = decompress_twist_bls12_381(signature.try_into().ok()?).ok()?;
+    is_on_subgroup_twist_bls12_381(&signature).then_some(signature)
+}
+
+/// e(PK₁, H(m₁))···e(PKₙ, H(mₙ)) = e(G1, σ), checked as a single pairing check closed by
+/// (G1, -σ)
+fn core_aggregate_verify(pubkeys: &[[u64; 12]], messages: &[Vec<u8>], signature: &[u8]) -> bool {
+    if pubkeys.is_empty() || pubkeys.len() != messages.len() {
+        return false;
+    }
+    let Some(signature) = signature_to_point(signature) else {
+        return false;
+    };
+
+    let mut g1_points = pubkeys.to_vec();
+    let mut g2_points: Vec<[u64; 24]> =
+        messages.iter().map(|m| hash_to_curve_twist_bls12_381(m, DST)).collect();
+    g1_points.push(G1);
+    g2_points.push(neg_twist_bls12_381(&signature));
+
+    pairing_check_bls12_381(&g1_points, &g2_points).expect("Validated points should succeed")
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    hex::decode(s.trim_start_matches("0x")).expect("valid hex")
+}
@@ -0,0 +1,211 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use ark_bn254::{Fr, G1Projective, G2Projective};
+use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+use ark_ff::{Field, PrimeField, UniformRand};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[path = "src/input.rs"]
+mod input;
+
+use input::{Fixture, Groth16Proof, Rejection, Statement, VerifyingKey};
+
+const OUTPUT_DIR: &str = "inputs";
+
+/// Scalar field size, to build public inputs and values that are not reduced
+const R: [u64; 4] = <Fr as PrimeField>::MODULUS.0;
+
+/// Error code of zisklib's `pairing_check_bn254` for a G1 point off the curve, as pinned by the
+/// pairing tests of the bn254 guest
+const G1_NOT_ON_CURVE: u8 = 3;
+
+fn main() -> io::Result<()> {
+    // Ensure the output directory exists
+    let output_dir = Path::new(OUTPUT_DIR);
+    if !output_dir.exists() {
+        fs::create_dir_all(output_dir)?;
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut fixtures = groth16_fixtures(&mut rng);
+    fixtures.extend(kzg_fixtures(&mut rng));
+
+    // Every fixture
+    write_input(output_dir, "input_all.bin", &fixtures);
+
+    // One input per fixture, to measure each verification on its own
+    for fixture in fixtures {
+        write_input(output_dir, &format!("input_{}.bin", fixture.name), &[fixture]);
+    }
+
+    Ok(())
+}
+
+fn write_input(output_dir: &Path, file_name: &str, fixtures: &[Fixture]) {
+    let file_path = output_dir.join(file_name);
+
+    let stdin = zisk_sdk::ZiskStdin::new();
+    stdin.write(&fixtures);
+    stdin.save(&file_path).expect("Failed to write input to file");
+}
+
+/// Valid Groth16 proofs for 1 and 8 public inputs, and tampered variants of the latter.
+///
+/// The verifying keys are sampled from known trapdoors α, β, γ, δ and ICᵢ, and each proof is
+/// produced by the Groth16 simulator: A = [a]G1 and B = [b]G2 for random a, b, and
+/// C = [(a·b - α·β - (IC₀ + Σ xᵢ·ICᵢ)·γ) / δ]G1. The verifier cannot tell them from prover output.
+fn groth16_fixtures(rng: &mut StdRng) -> Vec<Fixture> {
+    let mut fixtures = vec![];
+
+    let (vk, proof, public_inputs) = simulated_groth16(rng, 1);
+    let statement = Statement::Groth16 { vk: Box::new(vk), proof, public_inputs };
+    fixtures.push(Fixture { name: "groth16_valid_1_input".into(), statement, expected: Ok(true) });
+
+    let (vk, proof, public_inputs) = simulated_groth16(rng, 8);
+
+    let statement = Statement::Groth16 { vk: Box::new(vk), proof, public_inputs };
+    fixtures.push(Fixture { name: "groth16_valid_8_inputs".into(), statement, expected: Ok(true) });
+
+    // Tampered copies of the valid 8-input fixture, all of which must be rejected, each for its
+    // own reason
+    let tampered =
+        |name: &str,
+         expected: Result<bool, Rejection>,
+         tamper: &dyn Fn(&mut VerifyingKey, &mut Groth16Proof, &mut Vec<_>)| {
+            let mut statement = fixtures[1].statement.clone();
+            let Statement::Groth16 { vk, proof, public_inputs } = &mut statement else {
+                unreachable!()
+            };
+            tamper(vk, proof, public_inputs);
+            Fixture { name: name.into(), statement, expected }
+        };
+
+    let mut invalid = vec![
+        tampered("groth16_wrong_public_input", Ok(false), &|_, _, x| x[0] = add_one(x[0])),
+        // x + r is the same element of Fr, so only the range check rejects it
+        tampered("groth16_public_input_not_reduced", Err(Rejection::NotReduced), &|_, _, x| {
+            x[0] = add_r(x[0])
+        }),
+        tampered("groth16_missing_public_input", Err(Rejection::InputCount), &|_, _, x| {
+            x.pop();
+        }),
+        tampered("groth16_tampered_a", Ok(false), &|_, p, _| p.a = g1(double_g1(&p.a))),
+        tampered("groth16_c_not_on_curve", Err(Rejection::Pairing(G1_NOT_ON_CURVE)), &|_, p, _| {
+            p.c[4] ^= 1
+        }),
+        tampered("groth16_wrong_delta", Ok(false), &|vk, _, _| vk.delta_g2 = vk.gamma_g2),
+    ];
+    fixtures.append(&mut invalid);
+
+    fixtures
+}
+
+fn simulated_groth16(
+    rng: &mut StdRng,
+    num_inputs: usize,
+) -> (VerifyingKey, Groth16Proof, Vec<[u64; 4]>) {
+    let (alpha, beta, gamma, delta) = (Fr::rand(rng), Fr::rand(rng), Fr::rand(rng), Fr::rand(rng));
+    let ic: Vec<Fr> = (0..=num_inputs).map(|_| Fr::rand(rng)).collect();
+    let public_inputs: Vec<Fr> = (0..num_inputs).map(|_| Fr::rand(rng)).collect();
+
+    let vk_x = ic[0] + public_inputs.iter().zip(&ic[1..]).map(|(x, ic)| *x * ic).sum::<Fr>();
+    let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+    let c = (a * b - alpha * beta - vk_x * gamma) * delta.inverse().unwrap();
+
+    let vk = VerifyingKey {
+        alpha_g1: g1(G1Projective::generator() * alpha),
+        beta_g2: g2(G2Projective::generator() * beta),
+        gamma_g2: g2(G2Projective::generator() * gamma),
+        delta_g2: g2(G2Projective::generator() * delta),
+        ic: ic.iter().map(|ic| g1(G1Projective::generator() * ic)).collect(),
+    };
+    let proof = Groth16Proof {
+        a: g1(G1Projective::generator() * a),
+        b: g2(G2Projective::generator() * b),
+        c: g1(G1Projective::generator() * c),
+    };
+    (vk, proof, public_inputs.iter().map(|x| fr(*x)).collect())
+}
+
+/// Valid opening of a degree 15 polynomial committed with a known τ, and tampered variants
+fn kzg_fixtures(rng: &mut StdRng) -> Vec<Fixture> {
+    let tau = Fr::rand(rng);
+    let coeffs: Vec<Fr> = (0..16).map(|_| Fr::rand(rng)).collect();
+    let eval = |x: Fr| coeffs.iter().rev().fold(Fr::from(0u64), |acc, c| acc * x + c);
+
+    let z = Fr::rand(rng);
+    let y = eval(z);
+    let q = (eval(tau) - y) * (tau - z).inverse().unwrap();
+
+    let tau_g2 = g2(G2Projective::generator() * tau);
+    let commitment = g1(G1Projective::generator() * eval(tau));
+    let proof = g1(G1Projective::generator() * q);
+    let (z, y) = (fr(z), fr(y));
+
+    let fixture = |name: &str, z, y, proof, expected: Result<bool, Rejection>| Fixture {
+        name: name.into(),
+        statement: Statement::Kzg { tau_g2, commitment, z, y, proof },
+        expected,
+    };
+
+    vec![
+        fixture("kzg_valid", z, y, proof, Ok(true)),
+        fixture("kzg_wrong_value", z, add_one(y), proof, Ok(false)),
+        fixture("kzg_wrong_point", add_one(z), y, proof, Ok(false)),
+        // y + r is the same element of Fr, so only the range check rejects it
+        fixture("kzg_value_not_reduced", z, add_r(y), proof, Err(Rejection::NotReduced)),
+        fixture("kzg_tampered_proof", z, y, g1(double_g1(&proof)), Ok(false)),
+    ]
+}
+
+fn fr(x: Fr) -> [u64; 4] {
+    x.into_bigint().0
+}
+
+fn g1(p: G1Projective) -> [u64; 8] {
+    let mut res = [0; 8];
+    if let Some((x, y)) = p.into_affine().xy() {
+        res[..4].copy_from_slice(&x.into_bigint().0);
+        res[4..].copy_from_slice(&y.into_bigint().0);
+    }
+    res
+}
+
+fn g2(p: G2Projective) -> [u64; 16] {
+    let mut res = [0; 16];
+    if let Some((x, y)) = p.into_affine().xy() {
+        res[..4].copy_from_slice(&x.c0.into_bigint().0);
+        res[4..8].copy_from_slice(&x.c1.into_bigint().0);
+        res[8..12].copy_from_slice(&y.c0.into_bigint().0);
+        res[12..].copy_from_slice(&y.c1.into_bigint().0);
+    }
+    res
+}
+
+/// [2]P of a point given by its limbs
+fn double_g1(p: &[u64; 8]) -> G1Projective {
+    let coord = |limbs: &[u64]| {
+        ark_bn254::Fq::from_bigint(ark_ff::BigInt(limbs.try_into().unwrap())).unwrap()
+    };
+    let p = ark_bn254::G1Affine::new(coord(&p[..4]), coord(&p[4..]));
+    p + p
+}
+
+fn add_one(x: [u64; 4]) -> [u64; 4] {
+    fr(Fr::from_bigint(ark_ff::BigInt(x)).unwrap() + Fr::from(1u64))
+}
+
+/// x + r as a 256-bit integer, which does not overflow for x < r < 2²⁵⁴
+fn add_r(x: [u64; 4]) -> [u64; 4] {
+    let mut res = [0; 4];
+    let mut carry = 0;
+    for ((res, x), r) in res.iter_mut().zip(x).zip(R) {
+        let sum = x as u128 + r as u128 + carry;
+        *res = sum as u64;
+        carry = sum >> 64;
+    }
+    res
+}
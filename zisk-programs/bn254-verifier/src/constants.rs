@@ -0,0 +1,26 @@
+/// Scalar field size
+pub const R: [u64; 4] =
+    [0x43E1F593F0000001, 0x2833E84879B97091, 0xB85045B68181585D, 0x30644E72E131A029];
+
+/// Generator of G1
+pub const G1: [u64; 8] = [1, 0, 0, 0, 2, 0, 0, 0];
+
+/// Generator of G2
+pub const G2: [u64; 16] = [
+    0x46DEBD5CD992F6ED,
+    0x674322D4F75EDADD,
+    0x426A00665E5C4479,
+    0x1800DEEF121F1E76,
+    0x97E485B7AEF312C2,
+    0xF1AA493335A9E712,
+    0x7260BFB731FB5D25,
+    0x198E9393920D483A,
+    0x4CE6CC0166FA7DAA,
+    0xE3D1E7690C43D37B,
+    0x4AAB71808DCB408F,
+    0x12C85EA5DB8C6DEB,
+    0x55ACDADCD122975B,
+    0xBC4B313370B38EF3,
+    0xEC9E99AD690C3395,
+    0x090689D0585FF075,
+];
@@ -0,0 +1,30 @@
+use ziskos::zisklib::{add_bn254, mul_bn254, neg_bn254, pairing_check_bn254};
+
+use crate::input::{Groth16Proof, Rejection, VerifyingKey};
+use crate::is_reduced;
+
+/// e(A, B) = e(α, β)·e(vk_x, γ)·e(C, δ) with vk_x = IC₀ + Σ xᵢ·ICᵢ, checked as a single pairing
+/// check of (-A, B), (α, β), (vk_x, γ), (C, δ)
+pub fn verify_groth16(
+    vk: &VerifyingKey,
+    proof: &Groth16Proof,
+    public_inputs: &[[u64; 4]],
+) -> Result<bool, Rejection> {
+    if public_inputs.len() + 1 != vk.ic.len() {
+        return Err(Rejection::InputCount);
+    }
+    if !public_inputs.iter().all(is_reduced) {
+        return Err(Rejection::NotReduced);
+    }
+
+    let mut vk_x = vk.ic[0];
+    for (x, ic) in public_inputs.iter().zip(&vk.ic[1..]) {
+        vk_x = add_bn254(&vk_x, &mul_bn254(ic, x));
+    }
+
+    let g1_points = [neg_bn254(&proof.a), vk.alpha_g1, vk_x, proof.c];
+    let g2_points = [proof.b, vk.beta_g2, vk.gamma_g2, vk.delta_g2];
+
+    // Points off the curve or out of G2 are rejected by the pairing check itself
+    pairing_check_bn254(&g1_points, &g2_points).map_err(Rejection::Pairing)
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+// Points are the little-endian limbs of their affine coordinates, as taken by the zisklib bn254
+// functions: (x, y) for G1 and (x.c0, x.c1, y.c0, y.c1) for G2. Scalars are little-endian limbs.
+//
+// This file is also included by `build.rs` to generate the files in `inputs/`.
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VerifyingKey {
+    pub alpha_g1: [u64; 8],
+    pub beta_g2: [u64; 16],
+    pub gamma_g2: [u64; 16],
+    pub delta_g2: [u64; 16],
+    /// [ICᵢ]G1 of the constant term and of each public input
+    pub ic: Vec<[u64; 8]>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Groth16Proof {
+    pub a: [u64; 8],
+    pub b: [u64; 16],
+    pub c: [u64; 8],
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub enum Statement {
+    Groth16 {
+        vk: Box<VerifyingKey>,
+        proof: Groth16Proof,
+        public_inputs: Vec<[u64; 4]>,
+    },
+    /// Opening p(z) = y of the commitment [p(τ)]G1, with proof [(p(τ) - y) / (τ - z)]G1
+    Kzg {
+        tau_g2: [u64; 16],
+        commitment: [u64; 8],
+        z: [u64; 4],
+        y: [u64; 4],
+        proof: [u64; 8],
+    },
+}
+
+/// Why a verifier rejects a statement without a pairing check that does not hold
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Rejection {
+    /// The number of public inputs does not match the verifying key
+    InputCount,
+    /// A scalar is not a canonical element of Fr
+    NotReduced,
+    /// Error code of `pairing_check_bn254` on a point of the statement
+    Pairing(u8),
+}
+
+/// Statement to verify and the verdict the verifier must reach: whether the pairing check holds,
+/// or why the statement is rejected before it can
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Fixture {
+    pub name: String,
+    pub statement: Statement,
+    pub expected: Result<bool, Rejection>,
+}
@@ -0,0 +1,27 @@
+use ziskos::zisklib::{add_bn254, mul_bn254, neg_bn254, pairing_check_bn254};
+
+use crate::constants::{G1, G2};
+use crate::input::Rejection;
+use crate::is_reduced;
+
+/// e(C - [y]G1 + [z]π, G2) = e(π, [τ]G2), checked as a single pairing check of
+/// (C - [y]G1 + [z]π, G2), (-π, [τ]G2)
+pub fn verify_kzg(
+    tau_g2: &[u64; 16],
+    commitment: &[u64; 8],
+    z: &[u64; 4],
+    y: &[u64; 4],
+    proof: &[u64; 8],
+) -> Result<bool, Rejection> {
+    if !is_reduced(z) || !is_reduced(y) {
+        return Err(Rejection::NotReduced);
+    }
+
+    let lhs = add_bn254(commitment, &neg_bn254(&mul_bn254(&G1, y)));
+    let lhs = add_bn254(&lhs, &mul_bn254(proof, z));
+
+    let g1_points = [lhs, neg_bn254(proof)];
+    let g2_points = [G2, *tau_g2];
+
+    pairing_check_bn254(&g1_points, &g2_points).map_err(Rejection::Pairing)
+}
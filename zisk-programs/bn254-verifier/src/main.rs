@@ -0,0 +1,39 @@
+#![no_main]
+ziskos::entrypoint!(main);
+
+mod constants;
+mod groth16;
+mod input;
+mod kzg;
+
+use constants::R;
+use groth16::verify_groth16;
+use input::{Fixture, Statement};
+use kzg::verify_kzg;
+
+fn main() {
+    // Get the fixtures to verify from ziskos
+    let fixtures: Vec<Fixture> = ziskos::io::read();
+
+    for fixture in &fixtures {
+        let res = match &fixture.statement {
+            Statement::Groth16 { vk, proof, public_inputs } => {
+                verify_groth16(vk, proof, public_inputs)
+            }
+            Statement::Kzg { tau_g2, commitment, z, y, proof } => {
+                verify_kzg(tau_g2, commitment, z, y, proof)
+            }
+        };
+        assert_eq!(res, fixture.expected, "{}", fixture.name);
+        match res {
+            Ok(true) => println!("{}: accepted", fixture.name),
+            Ok(false) => println!("{}: rejected", fixture.name),
+            Err(rejection) => println!("{}: rejected ({rejection:?})", fixture.name),
+        }
+    }
+}
+
+/// Whether a scalar is a canonical element of Fr
+fn is_reduced(x: &[u64; 4]) -> bool {
+    x.iter().rev().lt(R.iter().rev())
+}
@@ -2,10 +2,12 @@ use std::fs;
 use std::io;
 use std::path::Path;
 
-const OUTPUT_DIR: &str = "build/";
+const OUTPUT_DIR: &str = "inputs";
 
 fn main() -> io::Result<()> {
-    let num_poseidon2s: u64 = 1;
+    let seed: u64 = 0;
+    let iterations: u64 = 16;
+    let max_pairs: u64 = 4;
 
     // Ensure the output directory exists
     let output_dir = Path::new(OUTPUT_DIR);
@@ -14,11 +16,11 @@ fn main() -> io::Result<()> {
     }
 
     // Create the file and write the inputs
-    let file_name = format!("input_poseidon2_{}.bin", num_poseidon2s);
+    let file_name = format!("input_seed_{}_{}.bin", seed, iterations);
     let file_path = output_dir.join(file_name);
 
     let stdin = zisk_sdk::ZiskStdin::new();
-    stdin.write(&num_poseidon2s);
+    stdin.write(&(seed, iterations, max_pairs));
     stdin.save(&file_path).expect("Failed to write input to file");
 
     Ok(())
@@ -0,0 +1,153 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use ziskos::zisklib::{
+    add_bn254, add_twist_bn254, dbl_twist_bn254, exp_fp12_bn254, mul_bn254, mul_fp12_bn254,
+    neg_bn254, pairing_bn254, pairing_check_bn254,
+};
+
+use crate::constants::{G1, G2, P};
+
+/// Error codes of zisklib's `pairing_check_bn254`, as pinned by tests 6 to 14 of `pairing.rs`
+const G1_INVALID: u8 = 2;
+const G1_NOT_ON_CURVE: u8 = 3;
+const G2_INVALID: u8 = 4;
+const G2_NOT_ON_CURVE: u8 = 5;
+const G2_NOT_IN_SUBGROUP: u8 = 6;
+
+/// Point of the twist that is on the curve but not in G2
+const G2_NOT_IN_SUBGROUP_POINT: [u64; 16] = [
+    0xE642D1780FA77460,
+    0x940C7100CC3B163F,
+    0x9E35DB46F7250AFC,
+    0x1ED91A62C98A6383,
+    0x9E87C23424EE0063,
+    0x12859810070565E9,
+    0x03CE49ABC798B83F,
+    0x181BAE8231C1F263,
+    0x6B283DE32DD4D366,
+    0xFE4EED8EF8036477,
+    0x49D7F9C268537017,
+    0x1B2D40EFDB1326CC,
+    0xE0A118CFA3E0D8D7,
+    0x9A3C9ECF0C83F1D7,
+    0x28D70CD532462E40,
+    0x07D105D0066EC703,
+];
+
+/// [k]Q by double-and-add over the twist, for a non-zero k
+fn scalar_mul_twist(q: &[u64; 16], k: u64) -> [u64; 16] {
+    let mut res = *q;
+    for i in (0..k.ilog2()).rev() {
+        res = dbl_twist_bn254(&res);
+        if (k >> i) & 1 == 1 {
+            res = add_twist_bn254(&res, q);
+        }
+    }
+    res
+}
+
+/// Random point of G1
+fn random_g1(rng: &mut StdRng) -> [u64; 8] {
+    let k: [u64; 4] = rng.gen();
+    mul_bn254(&G1, &k)
+}
+
+/// Random point of G2
+fn random_g2(rng: &mut StdRng) -> [u64; 16] {
+    scalar_mul_twist(&G2, rng.gen_range(1..=u64::MAX))
+}
+
+/// Checks bilinearity and non-degeneracy of the pairing on `iterations` random inputs, and
+/// multi-pair `pairing_check_bn254` calls of up to `max_pairs` pairs, both valid and invalid
+pub fn bilinearity_tests(rng: &mut StdRng, iterations: u64, max_pairs: u64) {
+    let mut one = [0; 48];
+    one[0] = 1;
+
+    for _ in 0..iterations {
+        let (p, q) = (random_g1(rng), random_g2(rng));
+        let e = pairing_bn254(&p, &q);
+
+        // Non-degeneracy
+        assert_ne!(e, one, "e(P, Q) should not be 1");
+
+        // e(aP, bQ) = e(P, Q)^(ab), with a, b < 2^32 so that ab fits the exponent
+        let a = rng.gen_range(1..1u64 << 32);
+        let b = rng.gen_range(1..1u64 << 32);
+        let ap = mul_bn254(&p, &[a, 0, 0, 0]);
+        let bq = scalar_mul_twist(&q, b);
+        assert_eq!(pairing_bn254(&ap, &bq), exp_fp12_bn254(a * b, &e), "e(aP, bQ) = e(P, Q)^(ab)");
+
+        // e(P1 + P2, Q) = e(P1, Q) * e(P2, Q) and e(P, Q1 + Q2) = e(P, Q1) * e(P, Q2)
+        let (p2, q2) = (random_g1(rng), random_g2(rng));
+        assert_eq!(
+            pairing_bn254(&add_bn254(&p, &p2), &q),
+            mul_fp12_bn254(&e, &pairing_bn254(&p2, &q)),
+            "e(P1 + P2, Q) = e(P1, Q) * e(P2, Q)"
+        );
+        assert_eq!(
+            pairing_bn254(&p, &add_twist_bn254(&q, &q2)),
+            mul_fp12_bn254(&e, &pairing_bn254(&p, &q2)),
+            "e(P, Q1 + Q2) = e(P, Q1) * e(P, Q2)"
+        );
+
+        multi_pair_check(rng, max_pairs);
+    }
+}
+
+/// Builds n random pairs ([a_i]G1, [b_i]G2) closed by (-[sum a_i b_i]G1, G2), whose product is 1,
+/// then checks the result and the errors when one of the pairs is corrupted
+fn multi_pair_check(rng: &mut StdRng, max_pairs: u64) {
+    let n = rng.gen_range(2..=max_pairs.max(2)) as usize;
+
+    let mut g1_points = Vec::with_capacity(n);
+    let mut g2_points = Vec::with_capacity(n);
+    let mut sum: u128 = 0;
+    for _ in 0..n - 1 {
+        let a = rng.gen_range(1..1u64 << 32);
+        let b = rng.gen_range(1..1u64 << 32);
+        sum += a as u128 * b as u128;
+        g1_points.push(mul_bn254(&G1, &[a, 0, 0, 0]));
+        g2_points.push(scalar_mul_twist(&G2, b));
+    }
+    let closing = mul_bn254(&G1, &[sum as u64, (sum >> 64) as u64, 0, 0]);
+    g1_points.push(neg_bn254(&closing));
+    g2_points.push(G2);
+
+    let result = pairing_check_bn254(&g1_points, &g2_points).expect("Valid pairs should succeed");
+    assert!(result, "{n} pairs closed by -[sum a_i b_i]G1 should multiply to 1");
+
+    // Closing with +[sum a_i b_i]G1 instead gives e(G1, G2)^(2 sum a_i b_i) != 1
+    let mut wrong = g1_points.clone();
+    wrong[n - 1] = closing;
+    let result = pairing_check_bn254(&wrong, &g2_points).expect("Valid pairs should succeed");
+    assert!(!result, "{n} pairs closed by +[sum a_i b_i]G1 should not multiply to 1");
+
+    // Corrupt a random pair
+    let i = rng.gen_range(0..n);
+
+    let mut bad_g1 = g1_points.clone();
+    bad_g1[i][4] ^= 1;
+    let result = pairing_check_bn254(&bad_g1, &g2_points);
+    assert_eq!(result, Err(G1_NOT_ON_CURVE), "G1 point not on curve at pair {i}");
+
+    let mut bad_g1 = g1_points.clone();
+    bad_g1[i][..4].copy_from_slice(&P);
+    let result = pairing_check_bn254(&bad_g1, &g2_points);
+    assert_eq!(result, Err(G1_INVALID), "G1 coordinate >= P at pair {i}");
+
+    let mut bad_g2 = g2_points.clone();
+    bad_g2[i][8] ^= 1;
+    let result = pairing_check_bn254(&g1_points, &bad_g2);
+    assert_eq!(result, Err(G2_NOT_ON_CURVE), "G2 point not on curve at pair {i}");
+
+    let mut bad_g2 = g2_points.clone();
+    bad_g2[i][12..].copy_from_slice(&P);
+    let result = pairing_check_bn254(&g1_points, &bad_g2);
+    assert_eq!(result, Err(G2_INVALID), "G2 coordinate >= P at pair {i}");
+
+    let mut bad_g2 = g2_points;
+    bad_g2[i] = G2_NOT_IN_SUBGROUP_POINT;
+    let result = pairing_check_bn254(&g1_points, &bad_g2);
+    assert_eq!(result, Err(G2_NOT_IN_SUBGROUP), "G2 point not in subgroup at pair {i}");
+}
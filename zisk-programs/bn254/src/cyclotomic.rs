@@ -95,7 +95,8 @@ pub fn cyclotomic_tests() {
     let res_exp = a;
     assert_eq!(res, res_exp);
 
-    // (decompression with a2 = 0)
+    // Decompression
+    // g₁ = 0, so g₃ is recovered from g₂, g₄ and g₅
     let a = [
         0x0000000000000000,
         0x0000000000000000,
@@ -309,14 +310,17 @@ pub fn cyclotomic_tests() {
     ];
     assert_eq!(res, res_exp);
 
-    // Exponentiation by x using the compression/decompression technique
+    // Exponentiation by |x|
     let a = [0; 48];
     let res = exp_by_x_cyclo_bn254(&a);
     let res_exp = a;
     assert_eq!(res, res_exp);
 
-    let mut a = [0; 48];
-    a[0] = 1;
+    let a = {
+        let mut tmp = [0; 48];
+        tmp[0] = 1;
+        tmp
+    };
     let res = exp_by_x_cyclo_bn254(&a);
     let res_exp = a;
     assert_eq!(res, res_exp);
@@ -1,6 +1,7 @@
 use ziskos::zisklib::final_exp_bn254;
 
 pub fn final_exp_tests() {
+    // Final exponentiation a^{(p¹²-1)/r}
     let a = [
         0x250E289A37680750,
         0x062FC7299CEBB3E8,
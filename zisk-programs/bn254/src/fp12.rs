@@ -67,104 +67,12 @@ pub fn fp12_tests() {
     assert_eq!(res, res_exp);
 
     let a = [
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000004,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000A,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000014,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000001E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000007,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000045,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
+        2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 5, 0, 0, 0, 10, 0,
+        0, 0, 20, 0, 0, 0, 30, 0, 0, 0, 7, 0, 0, 0, 69, 0, 0, 0,
     ];
     let b = [
-        0x000000000000004E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000001,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000A,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000064,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000063,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000058,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000004D,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000014,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
+        78, 0, 0, 0, 14, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 10, 0, 0, 0, 100, 0, 0, 0,
+        99, 0, 0, 0, 88, 0, 0, 0, 77, 0, 0, 0, 20, 0, 0, 0, 2, 0, 0, 0,
     ];
     let res = mul_fp12_bn254(&a, &b);
     let res_exp = [
@@ -221,54 +129,8 @@ pub fn fp12_tests() {
 
     // Squaring
     let a = [
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000004,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000A,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000014,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000001E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000007,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000045,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
+        2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 5, 0, 0, 0, 10, 0,
+        0, 0, 20, 0, 0, 0, 30, 0, 0, 0, 7, 0, 0, 0, 69, 0, 0, 0,
     ];
     let res = square_fp12_bn254(&a);
     let res_exp = [
@@ -433,54 +295,8 @@ pub fn fp12_tests() {
     assert_eq!(res, res_exp);
 
     let a = [
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000004,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000A,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000014,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000001E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000007,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000045,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
+        2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 5, 0, 0, 0, 10, 0,
+        0, 0, 20, 0, 0, 0, 30, 0, 0, 0, 7, 0, 0, 0, 69, 0, 0, 0,
     ];
     let res = inv_fp12_bn254(&a);
     let res_exp = [
@@ -641,54 +457,8 @@ pub fn fp12_tests() {
 
     // Frobenius1
     let a = [
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000004,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000A,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000014,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000001E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000007,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000045,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
+        2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 5, 0, 0, 0, 10, 0,
+        0, 0, 20, 0, 0, 0, 30, 0, 0, 0, 7, 0, 0, 0, 69, 0, 0, 0,
     ];
     let res = frobenius1_fp12_bn254(&a);
     let res_exp = [
@@ -745,54 +515,8 @@ pub fn fp12_tests() {
 
     // Frobenius2
     let a = [
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000004,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000A,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000014,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000001E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000007,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000045,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
+        2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 5, 0, 0, 0, 10, 0,
+        0, 0, 20, 0, 0, 0, 30, 0, 0, 0, 7, 0, 0, 0, 69, 0, 0, 0,
     ];
     let res = frobenius2_fp12_bn254(&a);
     let res_exp = [
@@ -849,54 +573,8 @@ pub fn fp12_tests() {
 
     // Frobenius3
     let a = [
-        0x0000000000000002,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000004,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000005,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000000A,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000014,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x000000000000001E,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000007,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000045,
-        0x0000000000000000,
-        0x0000000000000000,
-        0x0000000000000000,
+        2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 5, 0, 0, 0, 10, 0,
+        0, 0, 20, 0, 0, 0, 30, 0, 0, 0, 7, 0, 0, 0, 69, 0, 0, 0,
     ];
     let res = frobenius3_fp12_bn254(&a);
     let res_exp = [
@@ -964,20 +642,28 @@ pub fn fp12_tests() {
     let res_exp = a;
     assert_eq!(res, res_exp);
 
-    let mut one = [0; 48];
-    one[0] = 1;
-
     let e = 0u64;
-    let a = one;
+    let a = {
+        let mut tmp = [0; 48];
+        tmp[0] = 1;
+        tmp
+    };
     let res = exp_fp12_bn254(e, &a);
     let res_exp = a;
     assert_eq!(res, res_exp);
 
     let e = 0u64;
-    let mut a = [0; 48];
-    a[0] = 2;
+    let a = {
+        let mut tmp = [0; 48];
+        tmp[0] = 2;
+        tmp
+    };
     let res = exp_fp12_bn254(e, &a);
-    let res_exp = one;
+    let res_exp = {
+        let mut tmp = [0; 48];
+        tmp[0] = 1;
+        tmp
+    };
     assert_eq!(res, res_exp);
 
     let e = 0x44E992B44A6909F1;
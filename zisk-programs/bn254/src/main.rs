@@ -1,6 +1,7 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
+mod bilinearity;
 mod constants;
 mod cyclotomic;
 mod final_exp;
@@ -9,8 +10,13 @@ mod fp12;
 mod fp2;
 mod fp6;
 mod pairing;
+mod properties;
 mod twist;
 
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use bilinearity::bilinearity_tests;
 use cyclotomic::cyclotomic_tests;
 use final_exp::final_exp_tests;
 use fp::fp_tests;
@@ -18,9 +24,14 @@ use fp12::fp12_tests;
 use fp2::fp2_tests;
 use fp6::fp6_tests;
 use pairing::{pairing_check_tests, pairing_tests};
+use properties::property_tests;
 use twist::twist_tests;
 
 fn main() {
+    // Get the PRNG seed, the number of random elements per tower level and the maximum number of
+    // pairs per pairing check from ziskos
+    let (seed, iterations, max_pairs): (u64, u64, u64) = ziskos::io::read();
+
     // Fp
     fp_tests();
 
@@ -45,4 +56,13 @@ fn main() {
     // Pairing
     pairing_tests();
     pairing_check_tests();
+
+    // Field tower properties on random elements
+    println!("Running field tower properties with seed {seed} for {iterations} iterations");
+    let mut rng = StdRng::seed_from_u64(seed);
+    property_tests(&mut rng, iterations);
+
+    // Pairing bilinearity on random points
+    println!("Running pairing bilinearity with up to {max_pairs} pairs per check");
+    bilinearity_tests(&mut rng, iterations, max_pairs);
 }
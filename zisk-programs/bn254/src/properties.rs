@@ -0,0 +1,265 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use ziskos::zisklib::{
+    add_fp2_bn254, add_fp6_bn254, add_fp_bn254, conjugate_fp12_bn254, conjugate_fp2_bn254,
+    dbl_fp2_bn254, dbl_fp6_bn254, exp_fp12_bn254, frobenius1_fp12_bn254, frobenius2_fp12_bn254,
+    frobenius3_fp12_bn254, inv_fp12_bn254, inv_fp2_bn254, inv_fp6_bn254, inv_fp_bn254,
+    mul_fp12_bn254, mul_fp2_bn254, mul_fp6_bn254, mul_fp_bn254, neg_fp2_bn254, neg_fp6_bn254,
+    neg_fp_bn254, scalar_mul_fp2_bn254, square_fp12_bn254, square_fp2_bn254, square_fp6_bn254,
+    square_fp_bn254, sub_fp2_bn254, sub_fp6_bn254,
+};
+
+use crate::constants::P;
+
+/// Random element of Fp, sampled uniformly by rejection
+fn random_fp(rng: &mut StdRng) -> [u64; 4] {
+    loop {
+        let a: [u64; 4] = rng.gen();
+        // Compare limbs from the most significant one
+        if a.iter().rev().lt(P.iter().rev()) {
+            return a;
+        }
+    }
+}
+
+fn random_fp2(rng: &mut StdRng) -> [u64; 8] {
+    let mut a = [0u64; 8];
+    for chunk in a.chunks_mut(4) {
+        chunk.copy_from_slice(&random_fp(rng));
+    }
+    a
+}
+
+fn random_fp6(rng: &mut StdRng) -> [u64; 24] {
+    let mut a = [0u64; 24];
+    for chunk in a.chunks_mut(4) {
+        chunk.copy_from_slice(&random_fp(rng));
+    }
+    a
+}
+
+fn random_fp12(rng: &mut StdRng) -> [u64; 48] {
+    let mut a = [0u64; 48];
+    for chunk in a.chunks_mut(4) {
+        chunk.copy_from_slice(&random_fp(rng));
+    }
+    a
+}
+
+/// Addition in Fp12 = Fp6[w], component-wise over Fp6
+fn add_fp12(a: &[u64; 48], b: &[u64; 48]) -> [u64; 48] {
+    let mut res = [0u64; 48];
+    for i in [0, 24] {
+        let a = a[i..i + 24].try_into().unwrap();
+        let b = b[i..i + 24].try_into().unwrap();
+        res[i..i + 24].copy_from_slice(&add_fp6_bn254(a, b));
+    }
+    res
+}
+
+/// Multiplicative identity of a tower level with `N` limbs
+fn one<const N: usize>() -> [u64; N] {
+    let mut one = [0u64; N];
+    one[0] = 1;
+    one
+}
+
+/// Checks the field axioms and the tower-specific identities on `iterations` random elements
+/// of every level of the BN254 tower
+pub fn property_tests(rng: &mut StdRng, iterations: u64) {
+    for _ in 0..iterations {
+        fp_properties(rng);
+        fp2_properties(rng);
+        fp6_properties(rng);
+        fp12_properties(rng);
+    }
+}
+
+fn fp_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_fp(rng), random_fp(rng), random_fp(rng));
+    let zero = [0u64; 4];
+
+    // Commutativity and associativity
+    assert_eq!(add_fp_bn254(&a, &b), add_fp_bn254(&b, &a));
+    assert_eq!(mul_fp_bn254(&a, &b), mul_fp_bn254(&b, &a));
+    assert_eq!(add_fp_bn254(&add_fp_bn254(&a, &b), &c), add_fp_bn254(&a, &add_fp_bn254(&b, &c)));
+    assert_eq!(mul_fp_bn254(&mul_fp_bn254(&a, &b), &c), mul_fp_bn254(&a, &mul_fp_bn254(&b, &c)));
+
+    // Distributivity
+    assert_eq!(
+        mul_fp_bn254(&a, &add_fp_bn254(&b, &c)),
+        add_fp_bn254(&mul_fp_bn254(&a, &b), &mul_fp_bn254(&a, &c))
+    );
+
+    // Additive inverse
+    assert_eq!(add_fp_bn254(&a, &neg_fp_bn254(&a)), zero);
+    assert_eq!(neg_fp_bn254(&neg_fp_bn254(&a)), a);
+
+    // Square is mul by self
+    assert_eq!(square_fp_bn254(&a), mul_fp_bn254(&a, &a));
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp_bn254(&a, &inv_fp_bn254(&a)), one::<4>());
+        assert_eq!(inv_fp_bn254(&inv_fp_bn254(&a)), a);
+    }
+}
+
+fn fp2_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_fp2(rng), random_fp2(rng), random_fp2(rng));
+    let zero = [0u64; 8];
+
+    // Commutativity and associativity
+    assert_eq!(add_fp2_bn254(&a, &b), add_fp2_bn254(&b, &a));
+    assert_eq!(mul_fp2_bn254(&a, &b), mul_fp2_bn254(&b, &a));
+    assert_eq!(
+        add_fp2_bn254(&add_fp2_bn254(&a, &b), &c),
+        add_fp2_bn254(&a, &add_fp2_bn254(&b, &c))
+    );
+    assert_eq!(
+        mul_fp2_bn254(&mul_fp2_bn254(&a, &b), &c),
+        mul_fp2_bn254(&a, &mul_fp2_bn254(&b, &c))
+    );
+
+    // Distributivity
+    assert_eq!(
+        mul_fp2_bn254(&a, &add_fp2_bn254(&b, &c)),
+        add_fp2_bn254(&mul_fp2_bn254(&a, &b), &mul_fp2_bn254(&a, &c))
+    );
+
+    // Additive inverse, subtraction and doubling
+    assert_eq!(add_fp2_bn254(&a, &neg_fp2_bn254(&a)), zero);
+    assert_eq!(neg_fp2_bn254(&neg_fp2_bn254(&a)), a);
+    assert_eq!(add_fp2_bn254(&sub_fp2_bn254(&a, &b), &b), a);
+    assert_eq!(dbl_fp2_bn254(&a), add_fp2_bn254(&a, &a));
+
+    // Square is mul by self
+    assert_eq!(square_fp2_bn254(&a), mul_fp2_bn254(&a, &a));
+
+    // Scalar multiplication is multiplication by an element of the base field
+    let s = random_fp(rng);
+    let mut s_fp2 = zero;
+    s_fp2[..4].copy_from_slice(&s);
+    assert_eq!(scalar_mul_fp2_bn254(&a, &s), mul_fp2_bn254(&a, &s_fp2));
+
+    // Conjugation is a multiplicative involution and the norm lies in the base field
+    assert_eq!(conjugate_fp2_bn254(&conjugate_fp2_bn254(&a)), a);
+    assert_eq!(
+        conjugate_fp2_bn254(&mul_fp2_bn254(&a, &b)),
+        mul_fp2_bn254(&conjugate_fp2_bn254(&a), &conjugate_fp2_bn254(&b))
+    );
+    let norm = mul_fp2_bn254(&a, &conjugate_fp2_bn254(&a));
+    assert_eq!(norm[4..], [0u64; 4]);
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp2_bn254(&a, &inv_fp2_bn254(&a)), one::<8>());
+        assert_eq!(inv_fp2_bn254(&inv_fp2_bn254(&a)), a);
+    }
+}
+
+fn fp6_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_fp6(rng), random_fp6(rng), random_fp6(rng));
+    let zero = [0u64; 24];
+
+    // Commutativity and associativity
+    assert_eq!(add_fp6_bn254(&a, &b), add_fp6_bn254(&b, &a));
+    assert_eq!(mul_fp6_bn254(&a, &b), mul_fp6_bn254(&b, &a));
+    assert_eq!(
+        add_fp6_bn254(&add_fp6_bn254(&a, &b), &c),
+        add_fp6_bn254(&a, &add_fp6_bn254(&b, &c))
+    );
+    assert_eq!(
+        mul_fp6_bn254(&mul_fp6_bn254(&a, &b), &c),
+        mul_fp6_bn254(&a, &mul_fp6_bn254(&b, &c))
+    );
+
+    // Distributivity
+    assert_eq!(
+        mul_fp6_bn254(&a, &add_fp6_bn254(&b, &c)),
+        add_fp6_bn254(&mul_fp6_bn254(&a, &b), &mul_fp6_bn254(&a, &c))
+    );
+
+    // Additive inverse, subtraction and doubling
+    assert_eq!(add_fp6_bn254(&a, &neg_fp6_bn254(&a)), zero);
+    assert_eq!(neg_fp6_bn254(&neg_fp6_bn254(&a)), a);
+    assert_eq!(add_fp6_bn254(&sub_fp6_bn254(&a, &b), &b), a);
+    assert_eq!(dbl_fp6_bn254(&a), add_fp6_bn254(&a, &a));
+
+    // Square is mul by self
+    assert_eq!(square_fp6_bn254(&a), mul_fp6_bn254(&a, &a));
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp6_bn254(&a, &inv_fp6_bn254(&a)), one::<24>());
+        assert_eq!(inv_fp6_bn254(&inv_fp6_bn254(&a)), a);
+    }
+}
+
+fn fp12_properties(rng: &mut StdRng) {
+    let (a, b, c) = (random_fp12(rng), random_fp12(rng), random_fp12(rng));
+    let zero = [0u64; 48];
+
+    // Commutativity and associativity
+    assert_eq!(mul_fp12_bn254(&a, &b), mul_fp12_bn254(&b, &a));
+    assert_eq!(
+        mul_fp12_bn254(&mul_fp12_bn254(&a, &b), &c),
+        mul_fp12_bn254(&a, &mul_fp12_bn254(&b, &c))
+    );
+
+    // Distributivity
+    assert_eq!(
+        mul_fp12_bn254(&a, &add_fp12(&b, &c)),
+        add_fp12(&mul_fp12_bn254(&a, &b), &mul_fp12_bn254(&a, &c))
+    );
+
+    // Square is mul by self, and exponentiation agrees with repeated multiplication
+    assert_eq!(square_fp12_bn254(&a), mul_fp12_bn254(&a, &a));
+    let e1 = rng.gen_range(0..1u64 << 16);
+    let e2 = rng.gen_range(0..1u64 << 16);
+    assert_eq!(exp_fp12_bn254(2, &a), square_fp12_bn254(&a));
+    assert_eq!(
+        exp_fp12_bn254(e1 + e2, &a),
+        mul_fp12_bn254(&exp_fp12_bn254(e1, &a), &exp_fp12_bn254(e2, &a))
+    );
+
+    // Conjugation is a multiplicative involution
+    assert_eq!(conjugate_fp12_bn254(&conjugate_fp12_bn254(&a)), a);
+    assert_eq!(
+        conjugate_fp12_bn254(&mul_fp12_bn254(&a, &b)),
+        mul_fp12_bn254(&conjugate_fp12_bn254(&a), &conjugate_fp12_bn254(&b))
+    );
+
+    // Frobenius: multiplicative, composes as x -> x^(p^k) and has order 12, with
+    // x^(p^6) being the conjugation
+    assert_eq!(
+        frobenius1_fp12_bn254(&mul_fp12_bn254(&a, &b)),
+        mul_fp12_bn254(&frobenius1_fp12_bn254(&a), &frobenius1_fp12_bn254(&b))
+    );
+    assert_eq!(frobenius1_fp12_bn254(&frobenius1_fp12_bn254(&a)), frobenius2_fp12_bn254(&a));
+    assert_eq!(frobenius1_fp12_bn254(&frobenius2_fp12_bn254(&a)), frobenius3_fp12_bn254(&a));
+    assert_eq!(frobenius3_fp12_bn254(&frobenius3_fp12_bn254(&a)), conjugate_fp12_bn254(&a));
+
+    let mut frob = a;
+    for i in 1..=12 {
+        frob = frobenius1_fp12_bn254(&frob);
+        assert_eq!(frob == a, i == 12, "Frobenius order mismatch at power {i}");
+    }
+    let mut frob = a;
+    for _ in 0..6 {
+        frob = frobenius2_fp12_bn254(&frob);
+    }
+    assert_eq!(frob, a);
+    let mut frob = a;
+    for _ in 0..4 {
+        frob = frobenius3_fp12_bn254(&frob);
+    }
+    assert_eq!(frob, a);
+
+    // Inverse round-trip
+    if a != zero {
+        assert_eq!(mul_fp12_bn254(&a, &inv_fp12_bn254(&a)), one::<48>());
+        assert_eq!(inv_fp12_bn254(&inv_fp12_bn254(&a)), a);
+    }
+}
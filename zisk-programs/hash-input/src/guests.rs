@@ -0,0 +1,68 @@
+//! The hash guests as seen by the host tools: where their inputs go, which iteration counts they
+//! are run with, and how their syscall shows up in the emulator and instance statistics.
+
+pub struct Guest {
+    /// Directory of the guest in zisk-programs, also the name of its ELF
+    pub program: &'static str,
+    /// Operation in the input file names
+    pub prefix: &'static str,
+    /// Iteration counts of the random inputs and of the benchmark sweep. Besides the small ones,
+    /// they are the counts of the earlier hand-made inputs, kept so that step measurements stay
+    /// comparable. Those sit around the instance capacity of the ZisK version they were made for:
+    /// `capacity` syscalls fill one instance, `capacity + 1` take a second one and
+    /// `2 * capacity + 1` a third one
+    pub random: &'static [u64],
+    /// Full hash function in the file name of the random message input, if the guest has one
+    pub messages: Option<&'static str>,
+    /// Name of the precompile opcode in the emulator statistics
+    pub opcode: &'static str,
+    /// Name of the precompile AIR in the instance statistics
+    pub air: &'static str,
+    /// Whether `random` has the counts around the capacity, so that `hash-bench` must measure it
+    /// exactly. It fails when a ZisK update moves the capacity away from them
+    pub pins_capacity: bool,
+}
+
+pub const GUESTS: [Guest; 4] = [
+    Guest {
+        program: "keccak",
+        prefix: "keccakf",
+        random: &[1, 2, 30, 60, 5241, 5242, 10483],
+        messages: Some("keccak256"),
+        opcode: "keccak",
+        air: "Keccakf",
+        pins_capacity: true,
+    },
+    Guest {
+        program: "sha256",
+        prefix: "sha256f",
+        random: &[1, 2, 3, 30, 55, 56, 57, 113, 1000, 7448, 7449, 14897],
+        messages: Some("sha256"),
+        opcode: "sha256",
+        air: "Sha256f",
+        pins_capacity: true,
+    },
+    Guest {
+        program: "blake2",
+        prefix: "blake2",
+        random: &[1, 2, 10, 1000, 10921, 10922, 43689, 43690, 87381],
+        messages: Some("blake2b"),
+        opcode: "blake2br",
+        air: "Blake2br",
+        pins_capacity: true,
+    },
+    Guest {
+        program: "poseidon2",
+        prefix: "poseidon2",
+        random: &[1],
+        messages: None,
+        opcode: "poseidon2",
+        air: "Poseidon2",
+        pins_capacity: false,
+    },
+];
+
+/// The guest whose directory is `program`
+pub fn guest(program: &str) -> Option<&'static Guest> {
+    GUESTS.iter().find(|g| g.program == program)
+}
@@ -0,0 +1,155 @@
+//! Input of the keccak, sha256, blake2 and poseidon2 guests, shared with the host tools that
+//! write it (`hash-inputs`, `hash-replay` and `hash-bench`).
+
+pub mod guests;
+
+use std::fmt;
+use std::ops::Range;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mode {
+    /// Random states, each checked against the reference implementation
+    Random,
+    /// Known-answer vectors, once per iteration
+    KnownAnswer,
+    /// Known-answer vectors once, then random states and random messages checked against the
+    /// reference
+    Both,
+    /// Random states through the syscall only, to measure its steps without the reference
+    Benchmark,
+    /// Random messages hashed by the full hash function, each checked against the reference
+    Messages,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Mode::Random => "random",
+            Mode::KnownAnswer => "known-answer",
+            Mode::Both => "known-answer and random",
+            Mode::Benchmark => "benchmark",
+            Mode::Messages => "random message",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HashInput {
+    pub mode: Mode,
+    /// PRNG seed, mixed with the iteration index to draw the state and the message of each
+    /// iteration
+    pub seed: u64,
+    /// Index of the first iteration, non-zero to replay an iteration of a longer run
+    pub first_iteration: u64,
+    pub iterations: u64,
+}
+
+/// Random iteration handed to the guest
+pub struct Iteration {
+    pub seed: u64,
+    pub index: u64,
+    /// Whether the syscall result must be checked against the reference implementation
+    pub check: bool,
+}
+
+/// Largest message of the random full-hash iterations
+pub const MAX_MESSAGE_LEN: usize = 4096;
+
+/// Random message of up to `MAX_MESSAGE_LEN` bytes, stored at a random offset of its buffer so
+/// that the hash functions also read it unaligned
+pub struct Message {
+    buffer: Vec<u8>,
+    offset: usize,
+}
+
+impl Message {
+    pub fn random(rng: &mut StdRng) -> Self {
+        let len = rng.gen_range(0..=MAX_MESSAGE_LEN);
+        let offset = rng.gen_range(0..8);
+        let mut buffer = vec![0u8; offset + len];
+        rng.fill(&mut buffer[offset..]);
+        Message { buffer, offset }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.buffer[self.offset..]
+    }
+
+    /// Offset of the message in its buffer
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl HashInput {
+    /// Runs the known-answer tests and the random iterations selected by the mode, `name` being
+    /// the operation in the log line. `random` checks a random state of the syscall and `message`,
+    /// if the guest has a full hash function, a random message of it
+    pub fn run(
+        &self,
+        name: &str,
+        mut known_answer: impl FnMut(),
+        mut random: impl FnMut(&mut StdRng, &Iteration),
+        message: Option<fn(&mut StdRng, &Iteration)>,
+    ) {
+        println!(
+            "Running {name} {} tests for {} times from iteration {} with seed {}",
+            self.mode, self.iterations, self.first_iteration, self.seed
+        );
+        assert!(
+            self.mode != Mode::Messages || message.is_some(),
+            "{name} has no message hash to test"
+        );
+
+        let known_answer_runs = match self.mode {
+            Mode::KnownAnswer => self.iterations,
+            Mode::Both => 1,
+            Mode::Random | Mode::Benchmark | Mode::Messages => 0,
+        };
+        for _ in 0..known_answer_runs {
+            known_answer();
+        }
+
+        if self.mode == Mode::KnownAnswer {
+            return;
+        }
+        for index in self.iteration_range() {
+            let iteration =
+                Iteration { seed: self.seed, index, check: self.mode != Mode::Benchmark };
+            if self.mode != Mode::Messages {
+                random(&mut iteration_rng(self.seed, index, Stream::State), &iteration);
+            }
+            let has_messages = matches!(self.mode, Mode::Messages | Mode::Both);
+            if let Some(message) = message.filter(|_| has_messages) {
+                message(&mut iteration_rng(self.seed, index, Stream::Message), &iteration);
+            }
+        }
+    }
+
+    fn iteration_range(&self) -> Range<u64> {
+        self.first_iteration..self.first_iteration + self.iterations
+    }
+}
+
+/// What an iteration draws from its PRNG. Each has its own stream, so that the message of a
+/// `Both` iteration is the one that the `Messages` mode replays for the same seed and index
+#[derive(Clone, Copy)]
+enum Stream {
+    State,
+    Message,
+}
+
+/// PRNG of a single iteration, seeded from the input seed, the iteration index and the stream so
+/// that any iteration can be replayed on its own
+fn iteration_rng(seed: u64, index: u64, stream: Stream) -> StdRng {
+    let mut rng_seed = [0u8; 32];
+    rng_seed[..8].copy_from_slice(&seed.to_le_bytes());
+    rng_seed[8..16].copy_from_slice(&index.to_le_bytes());
+    rng_seed[16] = stream as u8;
+    StdRng::from_seed(rng_seed)
+}
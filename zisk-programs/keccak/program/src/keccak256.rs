@@ -0,0 +1,87 @@
+use hash_input::{Iteration, Message};
+use rand::rngs::StdRng;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Rate of Keccak-256 in bytes, the message bytes absorbed per keccakf call
+const RATE: usize = 136;
+
+unsafe extern "C" {
+    // This gets linked to the ziskos keccak256 implementation
+    fn native_keccak256(bytes: *const u8, len: usize, output: *mut u8);
+}
+
+fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    unsafe { native_keccak256(bytes.as_ptr(), bytes.len(), output.as_mut_ptr()) };
+    output
+}
+
+fn reference_keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes);
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+// Hash a random message at a random offset and compare against tiny-keccak
+pub fn keccak256_apply(rng: &mut StdRng, iteration: &Iteration) {
+    let message = Message::random(rng);
+    let hash = keccak256(message.bytes());
+    let expected = reference_keccak256(message.bytes());
+
+    assert!(
+        hash == expected,
+        "KECCAK256 mismatch on {} bytes at offset {} at iteration {} of seed {}: \n  expected: {}\n     found: {}",
+        message.bytes().len(),
+        message.offset(),
+        iteration.index,
+        iteration.seed,
+        hex::encode(expected),
+        hex::encode(hash)
+    );
+}
+
+pub fn run_consistency_test() {
+    // Keccak-256 (the original Keccak padding used by Ethereum, not SHA3-256) of the FIPS 180
+    // short and long messages
+    const VECTORS: [(&[u8], &str); 4] = [
+        (b"", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+        (b"abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "45d3b367a6904e6e8d502ee04999a7c27647f91fa845d456525fd352ae3d7371",
+        ),
+        (
+            b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            "f519747ed599024f3882238e5ab43960132572b7345fbeb9a90769dafd21ad67",
+        ),
+    ];
+    for (message, expected) in VECTORS {
+        let hash = keccak256(message);
+        assert!(
+            hex::encode(hash) == expected,
+            "KECCAK256 mismatch on {:?}: \n  expected: {}\n     found: {}",
+            String::from_utf8_lossy(message),
+            expected,
+            hex::encode(hash)
+        );
+    }
+
+    // Lengths around the rate, where the padding spills into another block, read at every
+    // alignment
+    let buffer: Vec<u8> = (0..3 * RATE + 8).map(|i| i as u8).collect();
+    for len in [RATE - 1, RATE, RATE + 1, 2 * RATE - 1, 2 * RATE, 2 * RATE + 1, 3 * RATE] {
+        for offset in 0..8 {
+            let message = &buffer[offset..offset + len];
+            let hash = keccak256(message);
+            let expected = reference_keccak256(message);
+            assert!(
+                hash == expected,
+                "KECCAK256 mismatch on {len} bytes at offset {offset}: \n  expected: {}\n     found: {}",
+                hex::encode(expected),
+                hex::encode(hash)
+            );
+        }
+    }
+}
@@ -1,37 +1,27 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
+mod keccak256;
+
+use hash_input::{HashInput, Iteration};
+use rand::rngs::StdRng;
 use rand::Rng;
 
 use tiny_keccak::keccakf;
 
 use ziskos::syscalls::syscall_keccak_f;
 
-const ACTIVATE_CONSISTENCY_TEST: bool = false;
+use crate::keccak256::keccak256_apply;
 
 fn main() {
-    // Get the input from ziskos
-    let num_keccakfs: u64 = ziskos::io::read();
-
-    let mut rng = rand::thread_rng();
+    // Get the mode, the PRNG seed and the iterations to run from ziskos
+    let input: HashInput = ziskos::io::read();
 
-    if ACTIVATE_CONSISTENCY_TEST {
-        println!("Running KECCAKF consistency test for {} times", num_keccakfs);
-    } else {
-        println!("Running KECCAKF random tests for {} times", num_keccakfs);
-    }
-
-    for _ in 0..num_keccakfs {
-        if ACTIVATE_CONSISTENCY_TEST {
-            run_consistency_test();
-        } else {
-            keccakf_apply(&mut rng);
-        }
-    }
+    input.run("KECCAKF", run_consistency_test, keccakf_apply, Some(keccak256_apply));
 }
 
 // Generate a random input and apply the keccakf function to it
-fn keccakf_apply(rng: &mut rand::rngs::ThreadRng) {
+fn keccakf_apply(rng: &mut StdRng, iteration: &Iteration) {
     let mut state = [0u64; 25];
     for i in 0..25 {
         state[i] = rng.gen();
@@ -45,12 +35,19 @@ fn keccakf_apply(rng: &mut rand::rngs::ThreadRng) {
         syscall_keccak_f(&mut state);
     }
 
+    // Benchmark runs only measure the syscall
+    if !iteration.check {
+        return;
+    }
+
     // Compare against an audited keccakf implementation
     keccakf(&mut state_copy);
 
     assert!(
         state == state_copy,
-        "KECCAKF state mismatch: \n  expected: {:x?}\n     found: {:x?}",
+        "KECCAKF state mismatch at iteration {} of seed {}: \n  expected: {:x?}\n     found: {:x?}",
+        iteration.index,
+        iteration.seed,
         state_copy,
         state
     );
@@ -121,4 +118,6 @@ fn run_consistency_test() {
         EXPECTED_RESULT,
         state
     );
+
+    keccak256::run_consistency_test();
 }
@@ -2,10 +2,11 @@ use std::fs;
 use std::io;
 use std::path::Path;
 
-const OUTPUT_DIR: &str = "../inputs";
+const OUTPUT_DIR: &str = "inputs";
 
 fn main() -> io::Result<()> {
-    let num_blake2s: u64 = 1;
+    let seed: u64 = 0;
+    let iterations: u64 = 16;
 
     // Ensure the output directory exists
     let output_dir = Path::new(OUTPUT_DIR);
@@ -14,11 +15,11 @@ fn main() -> io::Result<()> {
     }
 
     // Create the file and write the inputs
-    let file_name = format!("input_blake2_{}.bin", num_blake2s);
+    let file_name = format!("input_seed_{}_{}.bin", seed, iterations);
     let file_path = output_dir.join(file_name);
 
     let stdin = zisk_sdk::ZiskStdin::new();
-    stdin.write(&num_blake2s);
+    stdin.write(&(seed, iterations));
     stdin.save(&file_path).expect("Failed to write input to file");
 
     Ok(())
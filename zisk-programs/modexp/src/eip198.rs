@@ -0,0 +1,37 @@
+use guest_reth::CustomEvmCrypto;
+use revm::precompile::{install_crypto, modexp};
+use serde::Deserialize;
+
+/// EIP-198 inputs around the length boundaries, with the expected outputs computed on the host
+const FIXTURE: &str = include_str!("testdata/modexp_eip198.json");
+
+#[derive(Deserialize)]
+struct Eip198Test {
+    #[serde(rename = "Input")]
+    input: String,
+    #[serde(rename = "Expected")]
+    expected: String,
+    #[serde(rename = "Name")]
+    name: String,
+}
+
+/// Runs the raw inputs through revm's modexp precompile, which parses the lengths, pads the
+/// operands and the result, and calls the installed `CustomEvmCrypto`
+pub fn eip198_tests() {
+    assert!(install_crypto(CustomEvmCrypto::default()), "a crypto provider is already installed");
+
+    let tests: Vec<Eip198Test> = serde_json::from_str(FIXTURE).expect("valid JSON");
+    for test in &tests {
+        let input = hex::decode(&test.input).expect("valid hex");
+        let expected = hex::decode(&test.expected).expect("valid hex");
+        let output = modexp::osaka_run(&input, u64::MAX)
+            .unwrap_or_else(|e| panic!("EIP-198 modexp {} failed: {e:?}", test.name));
+        assert_eq!(
+            hex::encode(&output.bytes),
+            hex::encode(&expected),
+            "EIP-198 modexp {} mismatch",
+            test.name
+        );
+    }
+    println!("  - EIP-198 tests passed.");
+}
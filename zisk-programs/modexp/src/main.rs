@@ -4,17 +4,35 @@ ziskos::entrypoint!(main);
 mod constants;
 
 mod array_arith;
+mod eip198;
 mod modexp;
+mod random;
 mod square;
 
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
 use array_arith::array_arith_tests;
+use eip198::eip198_tests;
 use modexp::modexp_tests;
+use random::random_tests;
 use square::square_tests;
 
 fn main() {
+    // Get the PRNG seed and the number of random cases per operation from ziskos
+    let (seed, iterations): (u64, u64) = ziskos::io::read();
+
     array_arith_tests();
 
     square_tests();
 
     modexp_tests();
+
+    // revm's modexp precompile on CustomEvmCrypto, with the EIP-198 length edge cases
+    eip198_tests();
+
+    // Multi-limb arithmetic and modexp on random operands, checked against num-bigint
+    println!("Running random tests with seed {seed} for {iterations} iterations");
+    let mut rng = StdRng::seed_from_u64(seed);
+    random_tests(&mut rng, iterations);
 }
@@ -0,0 +1,159 @@
+use num_bigint::BigUint;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use ziskos::zisklib::{div_long, modexp, mul_long, square_long, U256};
+
+/// Largest base and modulus, in U256 limbs
+const MAX_LIMBS: usize = 64;
+
+/// Largest random exponent, in u64 words
+const MAX_EXP_WORDS: usize = 32;
+
+/// Random u64 words of a number of `min_limbs..=MAX_LIMBS` U256 limbs, the most significant one
+/// non-zero. The number of limbs is spread over the orders of magnitude so that short operands
+/// stay frequent, and words are all-ones or zero now and then, to exercise the carries
+fn random_words(rng: &mut StdRng, min_limbs: usize) -> Vec<u64> {
+    let magnitude = rng.gen_range(0..=MAX_LIMBS.ilog2());
+    let limbs = rng.gen_range(1..=1 << magnitude).max(min_limbs);
+    let mut words: Vec<u64> = (0..4 * limbs)
+        .map(|_| match rng.gen_range(0..8) {
+            0 => 0,
+            1 => u64::MAX,
+            _ => rng.gen(),
+        })
+        .collect();
+    if words[4 * limbs - 4..].iter().all(|&w| w == 0) {
+        words[4 * limbs - 1] = rng.gen_range(1..=u64::MAX);
+    }
+    words
+}
+
+fn to_u256s(words: &[u64]) -> Vec<U256> {
+    words.chunks(4).map(|limb| U256::from_u64s(limb.try_into().unwrap())).collect()
+}
+
+fn to_biguint(words: &[u64]) -> BigUint {
+    let digits: Vec<u32> = words.iter().flat_map(|&w| [w as u32, (w >> 32) as u32]).collect();
+    BigUint::from_slice(&digits)
+}
+
+/// `value` in `limbs` U256 limbs, or in as many as it needs (at least one) if `limbs` is None
+fn to_limbs(value: &BigUint, limbs: Option<usize>) -> Vec<U256> {
+    let mut words = value.to_u64_digits();
+    let limbs = limbs.unwrap_or(words.len().div_ceil(4).max(1));
+    words.resize(4 * limbs.max(words.len().div_ceil(4)), 0);
+    to_u256s(&words)
+}
+
+pub fn random_tests(rng: &mut StdRng, iterations: u64) {
+    for i in 0..iterations {
+        mul_long_random(rng, i);
+        square_long_random(rng, i);
+        div_long_random(rng, i);
+        modexp_random(rng, i);
+    }
+    println!("  - Random tests passed.");
+}
+
+fn mul_long_random(rng: &mut StdRng, i: u64) {
+    let a = random_words(rng, 2);
+    let b = random_words(rng, 2);
+
+    let (a_limbs, b_limbs) = (to_u256s(&a), to_u256s(&b));
+    let mut res = vec![U256::ZERO; a_limbs.len() + b_limbs.len()];
+    let len_res = mul_long(&a_limbs, &b_limbs, &mut res);
+
+    let expected = to_limbs(&(to_biguint(&a) * to_biguint(&b)), None);
+    assert_eq!(
+        &res[..len_res],
+        expected,
+        "mul_long mismatch at iteration {i} for {} x {} limbs",
+        a_limbs.len(),
+        b_limbs.len()
+    );
+}
+
+fn square_long_random(rng: &mut StdRng, i: u64) {
+    let a = random_words(rng, 2);
+
+    let a_limbs = to_u256s(&a);
+    let mut res = vec![U256::ZERO; 2 * a_limbs.len()];
+    let len_res = square_long(&a_limbs, &mut res);
+
+    let expected = to_limbs(&(to_biguint(&a) * to_biguint(&a)), None);
+    assert_eq!(
+        &res[..len_res],
+        expected,
+        "square_long mismatch at iteration {i} for {} limbs",
+        a_limbs.len()
+    );
+}
+
+fn div_long_random(rng: &mut StdRng, i: u64) {
+    let a = random_words(rng, 1);
+    let b = random_words(rng, 2);
+
+    let (a_limbs, b_limbs) = (to_u256s(&a), to_u256s(&b));
+    let (q, r) = div_long(&a_limbs, &b_limbs);
+
+    let (a, b) = (to_biguint(&a), to_biguint(&b));
+    let context = format!("at iteration {i} for {} / {} limbs", a_limbs.len(), b_limbs.len());
+    assert_eq!(q, to_limbs(&(&a / &b), None), "div_long quotient mismatch {context}");
+    assert_eq!(r, to_limbs(&(&a % &b), None), "div_long remainder mismatch {context}");
+}
+
+/// Exponent words: zero, one, a single word, or up to MAX_EXP_WORDS words, all-ones now and then
+fn random_exponent(rng: &mut StdRng) -> Vec<u64> {
+    match rng.gen_range(0..8) {
+        0 => vec![0],
+        1 => vec![1],
+        2 | 3 => vec![rng.gen_range(2..=u64::MAX)],
+        4 => vec![u64::MAX; rng.gen_range(1..=MAX_EXP_WORDS)],
+        _ => {
+            let mut exp: Vec<u64> =
+                (0..rng.gen_range(2..=MAX_EXP_WORDS)).map(|_| rng.gen()).collect();
+            *exp.last_mut().unwrap() |= 1;
+            exp
+        }
+    }
+}
+
+/// Modulus words: odd, even (with a random power of two factor), or with up to three zero limbs
+/// above its most significant limb
+fn random_modulus(rng: &mut StdRng) -> Vec<u64> {
+    let mut modulus = random_words(rng, 1);
+    match rng.gen_range(0..3) {
+        0 => modulus[0] |= 1,
+        1 => {
+            let shift = rng.gen_range(1..64);
+            modulus[0] = (modulus[0] | 1) << shift;
+        }
+        _ => modulus.resize(modulus.len() + 4 * rng.gen_range(1..=3), 0),
+    }
+    // M = 1 is an early return, covered by the fixed tests
+    if modulus.iter().skip(1).all(|&w| w == 0) && modulus[0] <= 1 {
+        modulus[0] = 2;
+    }
+    modulus
+}
+
+fn modexp_random(rng: &mut StdRng, i: u64) {
+    let base = random_words(rng, 1);
+    let exp = random_exponent(rng);
+    let modulus = random_modulus(rng);
+
+    let (base_limbs, modulus_limbs) = (to_u256s(&base), to_u256s(&modulus));
+    let res = modexp(&base_limbs, &exp, &modulus_limbs);
+
+    let context = format!(
+        "at iteration {i} for base of {} limbs, exponent of {} words, modulus of {} limbs",
+        base_limbs.len(),
+        exp.len(),
+        modulus_limbs.len()
+    );
+    assert!(res.len() <= modulus_limbs.len(), "modexp result of {} limbs {context}", res.len());
+
+    let expected = to_biguint(&base).modpow(&to_biguint(&exp), &to_biguint(&modulus));
+    assert_eq!(res, to_limbs(&expected, Some(res.len())), "modexp mismatch {context}");
+}
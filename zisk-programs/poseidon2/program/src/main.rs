@@ -1,46 +1,104 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
+mod constants;
+mod poseidon2;
+
+use hash_input::{HashInput, Iteration};
+use rand::rngs::StdRng;
 use rand::Rng;
+use serde::Deserialize;
 
 use ziskos::syscalls::syscall_poseidon2;
 
-fn main() {
-    // Get the input from ziskos
-    let num_poseidon2s: u64 = ziskos::io::read();
-
-    let mut rng = rand::thread_rng();
+use constants::{P, WIDTH};
+use poseidon2::poseidon2;
 
-    println!("Running POSEIDON2 random tests for {} times", num_poseidon2s);
+fn main() {
+    // Get the mode, the PRNG seed and the iterations to run from ziskos
+    let input: HashInput = ziskos::io::read();
 
-    for _ in 0..num_poseidon2s {
-        poseidon2_apply(&mut rng);
-    }
+    // Poseidon2 is only tested as a permutation, there is no message hash on top of it
+    input.run("POSEIDON2", run_consistency_test, poseidon2_apply, None);
 }
 
 // Generate a random input and apply the poseidon2 function to it
-fn poseidon2_apply(rng: &mut rand::rngs::ThreadRng) {
-    let mut state = [0u64; 16];
-    for i in 0..16 {
-        state[i] = rng.gen();
+fn poseidon2_apply(rng: &mut StdRng, iteration: &Iteration) {
+    let mut state = [0u64; WIDTH];
+    for x in state.iter_mut() {
+        *x = rng.gen_range(0..P);
     }
 
-    // // Make a copy of the state to compare results later
-    // let mut state_copy = state.clone();
+    // Make a copy of the state to compare results later
+    let mut state_copy = state;
 
     // Call the syscall implementation of poseidon2
     unsafe {
         syscall_poseidon2(&mut state);
     }
 
-    // TODO!
-    // // Compare against an audited poseidon2 implementation
-    // poseidon2(&mut state_copy);
+    // Benchmark runs only measure the syscall
+    if !iteration.check {
+        return;
+    }
+
+    // Compare against the software poseidon2 implementation
+    poseidon2(&mut state_copy);
+
+    assert!(
+        state == state_copy,
+        "POSEIDON2 state mismatch at iteration {} of seed {}: \n  expected: {:x?}\n     found: {:x?}",
+        iteration.index,
+        iteration.seed,
+        state_copy,
+        state
+    );
+}
 
-    // assert!(
-    //     state == state_copy,
-    //     "POSEIDON2 state mismatch: \n  expected: {:x?}\n     found: {:x?}",
-    //     state_copy,
-    //     state
-    // );
+fn run_consistency_test() {
+    let file: TestFile = serde_json::from_str(include_str!(
+        "../../../../tools/testgen/src/tests/test_data/poseidon2_tests.json"
+    ))
+    .expect("valid JSON");
+
+    for (i, test) in file.data.iter().enumerate() {
+        let state_in = parse_state(&test.state_in);
+        let expected = parse_state(&test.state_out);
+
+        // Check the reference first, so that a mismatch of the syscall is not blamed on it
+        let mut state = state_in;
+        poseidon2(&mut state);
+        assert!(
+            state == expected,
+            "POSEIDON2 reference mismatch in vector #{i}: \n  expected: {:x?}\n     found: {:x?}",
+            expected,
+            state
+        );
+
+        let mut state = state_in;
+        unsafe {
+            syscall_poseidon2(&mut state);
+        }
+        assert!(
+            state == expected,
+            "POSEIDON2 state mismatch in vector #{i}: \n  expected: {:x?}\n     found: {:x?}",
+            expected,
+            state
+        );
+    }
+}
+
+fn parse_state(hex: &str) -> [u64; WIDTH] {
+    let hex = hex.trim_start_matches("0x");
+    let hex = if hex.len() % 2 == 1 { format!("0{hex}") } else { hex.to_string() };
+    let bytes = hex::decode(hex).expect("valid hex");
+    assert!(bytes.len() <= WIDTH * 8, "state larger than {WIDTH} elements");
+
+    let mut state = [0; WIDTH];
+    for (x, chunk) in state.iter_mut().zip(bytes.rchunks(8)) {
+        let mut limb = [0; 8];
+        limb[8 - chunk.len()..].copy_from_slice(chunk);
+        *x = u64::from_be_bytes(limb);
+    }
+    state
 }
@@ -0,0 +1,87 @@
+use crate::constants::{
+    FINAL_ROUND_CONSTANTS, INITIAL_ROUND_CONSTANTS, MATRIX_DIAG_M_1, P, PARTIAL_ROUND_CONSTANTS,
+    WIDTH,
+};
+
+// Software Poseidon2 permutation over Goldilocks with width 16 and S-box x^7, with the same
+// constants as the ZisK precompile. It is the reference the syscall is checked against
+
+pub fn poseidon2(state: &mut [u64; WIDTH]) {
+    external_layer(state);
+
+    for round_constants in &INITIAL_ROUND_CONSTANTS {
+        full_round(state, round_constants);
+    }
+
+    for round_constant in PARTIAL_ROUND_CONSTANTS {
+        state[0] = sbox(add(state[0], round_constant));
+        internal_layer(state);
+    }
+
+    for round_constants in &FINAL_ROUND_CONSTANTS {
+        full_round(state, round_constants);
+    }
+}
+
+fn full_round(state: &mut [u64; WIDTH], round_constants: &[u64; WIDTH]) {
+    for (x, c) in state.iter_mut().zip(round_constants) {
+        *x = sbox(add(*x, *c));
+    }
+    external_layer(state);
+}
+
+/// circ(2·M4, M4, M4, M4): M4 on each chunk of 4 elements, then the sum of the chunks added to
+/// each of them
+fn external_layer(state: &mut [u64; WIDTH]) {
+    for chunk in state.chunks_exact_mut(4) {
+        matmul_m4(chunk);
+    }
+
+    let mut sums = [0; 4];
+    for chunk in state.chunks_exact(4) {
+        for (sum, x) in sums.iter_mut().zip(chunk) {
+            *sum = add(*sum, *x);
+        }
+    }
+    for chunk in state.chunks_exact_mut(4) {
+        for (x, sum) in chunk.iter_mut().zip(sums) {
+            *x = add(*x, sum);
+        }
+    }
+}
+
+/// M4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+fn matmul_m4(x: &mut [u64]) {
+    let t0 = add(x[0], x[1]);
+    let t1 = add(x[2], x[3]);
+    let t2 = add(add(x[1], x[1]), t1);
+    let t3 = add(add(x[3], x[3]), t0);
+    let t4 = add(mul(4, t1), t3);
+    let t5 = add(mul(4, t0), t2);
+    let t6 = add(t3, t5);
+    let t7 = add(t2, t4);
+    x.copy_from_slice(&[t6, t5, t7, t4]);
+}
+
+/// 1 + diag(MATRIX_DIAG_M_1): each element becomes x·d + Σ state
+fn internal_layer(state: &mut [u64; WIDTH]) {
+    let sum = state.iter().fold(0, |acc, x| add(acc, *x));
+    for (x, d) in state.iter_mut().zip(MATRIX_DIAG_M_1) {
+        *x = add(mul(*x, d), sum);
+    }
+}
+
+fn sbox(x: u64) -> u64 {
+    let x2 = mul(x, x);
+    let x3 = mul(x2, x);
+    let x4 = mul(x2, x2);
+    mul(x4, x3)
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    ((a as u128 + b as u128) % P as u128) as u64
+}
+
+fn mul(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % P as u128) as u64
+}
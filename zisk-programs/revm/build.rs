@@ -0,0 +1,89 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[path = "src/input.rs"]
+mod input;
+
+use input::{Chunk, SuiteSelection};
+
+const OUTPUT_DIR: &str = "inputs";
+
+/// Pairing and MSM suites, whose fixtures are split in chunks when running with hints
+const HEAVY_SUITES: &[&str] =
+    &["bn254_pairing", "bls12_381_g1_msm", "bls12_381_g2_msm", "bls12_381_pairing"];
+const HEAVY_CHUNKS: usize = 4;
+
+/// Pairs or points per call of the hint capacity probes of the heavy suites
+const HINT_PROBE_SIZES: &[usize] = &[2, 4, 8, 16, 32, 64];
+
+/// Suites of the u256 wrappers, run by `tools/u256-cost` on the zisklib and the ruint builds
+const U256_SUITES: &[&str] = &["u256_add", "u256_div", "u256_modular", "u256_mul", "u256_pow"];
+
+fn main() -> io::Result<()> {
+    // Ensure the output directory exists
+    let output_dir = Path::new(OUTPUT_DIR);
+    if !output_dir.exists() {
+        fs::create_dir_all(output_dir)?;
+    }
+
+    // Every suite
+    write_input(output_dir, "input_all.bin", &SuiteSelection::default());
+
+    // Only the u256 suites
+    write_input(
+        output_dir,
+        "input_u256.bin",
+        &SuiteSelection {
+            suites: U256_SUITES.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        },
+    );
+
+    // Only the suites that work with hints
+    write_input(
+        output_dir,
+        "input_hints.bin",
+        &SuiteSelection { hints_compatible_only: true, ..Default::default() },
+    );
+
+    // The pairing and MSM suites with hints, one chunk of their fixtures per input
+    for index in 0..HEAVY_CHUNKS {
+        write_input(
+            output_dir,
+            &format!("input_hints_heavy_{}_of_{}.bin", index + 1, HEAVY_CHUNKS),
+            &SuiteSelection {
+                suites: HEAVY_SUITES.iter().map(|s| s.to_string()).collect(),
+                hints_compatible_only: true,
+                chunk: Some(Chunk { index, count: HEAVY_CHUNKS }),
+                ..Default::default()
+            },
+        );
+    }
+
+    // One hint limit test per heavy suite and size, to measure the hint capacity of each suite
+    for suite in HEAVY_SUITES {
+        for &size in HINT_PROBE_SIZES {
+            write_input(
+                output_dir,
+                &format!("input_hints_probe_{suite}_{size}.bin"),
+                &SuiteSelection {
+                    suites: vec![suite.to_string()],
+                    hints_compatible_only: true,
+                    hint_probe: Some(size),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn write_input(output_dir: &Path, file_name: &str, selection: &SuiteSelection) {
+    let file_path = output_dir.join(file_name);
+
+    let stdin = zisk_sdk::ZiskStdin::new();
+    stdin.write(selection);
+    stdin.save(&file_path).expect("Failed to write input to file");
+}
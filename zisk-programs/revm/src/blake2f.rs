@@ -4,6 +4,7 @@ use revm::precompile::Crypto;
 use crate::common::{
     parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
 
 struct Blake2fTestCase {
     name: String,
@@ -73,7 +74,12 @@ pub fn blake2f_tests(crypto: &CustomEvmCrypto) {
         match parse_blake2f_test(test) {
             Ok(parsed) => {
                 let mut h = parsed.h;
-                crypto.blake2_compress(parsed.rounds, &mut h, parsed.m, parsed.t, parsed.f);
+                profile_call!(
+                    blake2f,
+                    "blake2f_tests",
+                    &parsed.name,
+                    crypto.blake2_compress(parsed.rounds, &mut h, parsed.m, parsed.t, parsed.f)
+                );
                 match parsed.expected {
                     Some(expected_h) => {
                         assert_eq!(
@@ -58,3 +58,61 @@ pub fn pad_g2_result(unpadded: &[u8; 192]) -> [u8; 256] {
     padded[208..256].copy_from_slice(&unpadded[144..192]);
     padded
 }
+
+/// Split a 96-byte unpadded result into a G1Point, to feed it back to a precompile
+pub fn g1_point_from_result(unpadded: &[u8; 96]) -> G1Point {
+    (unpadded[0..48].try_into().unwrap(), unpadded[48..96].try_into().unwrap())
+}
+
+/// Split a 192-byte unpadded result into a G2Point, to feed it back to a precompile
+pub fn g2_point_from_result(unpadded: &[u8; 192]) -> G2Point {
+    (
+        unpadded[0..48].try_into().unwrap(),
+        unpadded[48..96].try_into().unwrap(),
+        unpadded[96..144].try_into().unwrap(),
+        unpadded[144..192].try_into().unwrap(),
+    )
+}
+
+/// Decode a big-endian hex field element into 48 bytes
+pub fn fp_from_hex(hex: &str) -> [u8; 48] {
+    hex::decode(hex).expect("valid hex").try_into().expect("48-byte field element")
+}
+
+/// Generator of G1
+pub fn g1_generator() -> G1Point {
+    (
+        fp_from_hex("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+        fp_from_hex("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+    )
+}
+
+/// Negation of the generator of G1
+pub fn g1_generator_neg() -> G1Point {
+    (
+        fp_from_hex("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+        fp_from_hex("114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"),
+    )
+}
+
+/// Generator of G2
+pub fn g2_generator() -> G2Point {
+    (
+        fp_from_hex("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+        fp_from_hex("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+        fp_from_hex("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+        fp_from_hex("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+    )
+}
+
+/// Scalars `1` and `r - 1`, whose multiples of any point add up to the point at infinity
+pub fn cancelling_scalars() -> ([u8; 32], [u8; 32]) {
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let r_minus_one: [u8; 32] =
+        hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000")
+            .expect("valid hex")
+            .try_into()
+            .unwrap();
+    (one, r_minus_one)
+}
@@ -5,6 +5,7 @@ use super::common::{pad_g1_result, parse_g1_point_padded, G1Point};
 use crate::common::{
     parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
 
 struct BlsG1AddTestCase {
     pub name: String,
@@ -40,7 +41,12 @@ pub fn bls12_381_g1_add_tests(crypto: &CustomEvmCrypto) {
     for test in &tests {
         match parse_bls_g1_add_test(test) {
             Ok(t) => {
-                let result = crypto.bls12_381_g1_add(t.p1, t.p2);
+                let result = profile_call!(
+                    bls12_381_g1_add,
+                    "bls12_381_g1_add_tests",
+                    &t.name,
+                    crypto.bls12_381_g1_add(t.p1, t.p2)
+                );
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "G1 Add {} should succeed", t.name);
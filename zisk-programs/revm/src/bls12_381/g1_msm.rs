@@ -1,10 +1,20 @@
 use guest_reth::CustomEvmCrypto;
-use revm::precompile::Crypto;
+use revm::precompile::{Crypto, PrecompileError};
 
-use super::common::{pad_g1_result, parse_g1_point_padded, G1PointScalar};
+use super::common::{
+    cancelling_scalars, g1_generator, g1_point_from_result, pad_g1_result, parse_g1_point_padded,
+    G1PointScalar,
+};
 use crate::common::{
-    parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
+    hint_chunk_size, hint_probe, parse_precompile_fail_json, parse_precompile_json,
+    runs_fixed_tests, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
+
+/// Largest number of point-scalar pairs per MSM call with hints. Provisional: not measured yet.
+/// Set it to the largest size of the `input_hints_probe_bls12_381_g1_msm_<n>.bin` inputs that
+/// runs with hints. `bls12_381_g1_msm_hint_limit_test` checks exactly this size.
+const MAX_HINTS_POINTS: usize = 16;
 
 pub struct BlsG1MsmTestCase {
     pub name: String,
@@ -48,11 +58,19 @@ pub fn bls12_381_g1_msm_tests(crypto: &CustomEvmCrypto) {
         "../testdata/precompiles/fail-blsG1MultiExp.json"
     )));
 
+    // A probe of the hint capacity only runs the hint limit test, at the probed size
+    if let Some(points) = hint_probe() {
+        bls12_381_g1_msm_hint_limit_test(crypto, points);
+        return;
+    }
+    if runs_fixed_tests() {
+        bls12_381_g1_msm_hint_limit_test(crypto, MAX_HINTS_POINTS);
+    }
+
     for test in &tests {
         match parse_bls_g1_msm_test(test) {
             Ok(t) => {
-                let mut iter = t.pairs.into_iter().map(Ok);
-                let result = crypto.bls12_381_g1_msm(&mut iter);
+                let result = chunked_g1_msm(crypto, &t.name, &t.pairs);
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "G1 MSM {} should succeed", t.name);
@@ -77,3 +95,46 @@ pub fn bls12_381_g1_msm_tests(crypto: &CustomEvmCrypto) {
 
     println!("All BLS12-381 G1 MSM tests passed!");
 }
+
+/// MSM of `pairs` as MSM calls of at most the hint budget, whose results are summed with G1
+/// additions. A single call without hints or when `pairs` fits the budget.
+fn chunked_g1_msm(
+    crypto: &CustomEvmCrypto,
+    name: &str,
+    pairs: &[G1PointScalar],
+) -> Result<[u8; 96], PrecompileError> {
+    let mut sum: Option<[u8; 96]> = None;
+    for chunk in pairs.chunks(hint_chunk_size(MAX_HINTS_POINTS)) {
+        let mut iter = chunk.iter().copied().map(Ok);
+        let partial = profile_call!(
+            bls12_381_g1_msm,
+            "bls12_381_g1_msm_tests",
+            name,
+            crypto.bls12_381_g1_msm(&mut iter)
+        )?;
+        sum = Some(match sum {
+            Some(sum) => crypto
+                .bls12_381_g1_add(g1_point_from_result(&sum), g1_point_from_result(&partial))?,
+            None => partial,
+        });
+    }
+    Ok(sum.expect("MSM vectors have at least one pair"))
+}
+
+/// [1]G + [r - 1]G + ... with an even number of pairs, which is the point at infinity
+fn bls12_381_g1_msm_hint_limit_test(crypto: &CustomEvmCrypto, points: usize) {
+    assert!(points % 2 == 0, "G1 MSM hint limit needs an even number of points, got {points}");
+    let (one, r_minus_one) = cancelling_scalars();
+    let pairs: Vec<G1PointScalar> =
+        (0..points).map(|i| (g1_generator(), if i % 2 == 0 { one } else { r_minus_one })).collect();
+
+    let mut iter = pairs.into_iter().map(Ok);
+    let result = profile_call!(
+        bls12_381_g1_msm,
+        "bls12_381_g1_msm_hint_limit_test",
+        &format!("Hint limit {points}"),
+        crypto.bls12_381_g1_msm(&mut iter)
+    );
+    assert!(result.is_ok(), "G1 MSM hint limit should succeed");
+    assert_eq!(pad_g1_result(&result.unwrap()), [0u8; 128], "G1 MSM hint limit mismatch");
+}
@@ -5,6 +5,7 @@ use super::common::{pad_g1_result, parse_g1_point_padded, G1Point};
 use crate::common::{
     parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
 
 pub struct BlsG1MulTestCase {
     pub name: String,
@@ -43,7 +44,12 @@ pub fn bls12_381_g1_mul_tests(crypto: &CustomEvmCrypto) {
             Ok(t) => {
                 let pairs = vec![(t.point, t.scalar)];
                 let mut iter = pairs.into_iter().map(Ok);
-                let result = crypto.bls12_381_g1_msm(&mut iter);
+                let result = profile_call!(
+                    bls12_381_g1_msm,
+                    "bls12_381_g1_mul_tests",
+                    &t.name,
+                    crypto.bls12_381_g1_msm(&mut iter)
+                );
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "G1 Mul {} should succeed", t.name);
@@ -5,6 +5,7 @@ use super::common::{pad_g2_result, parse_g2_point_padded, G2Point};
 use crate::common::{
     parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
 
 struct BlsG2AddTestCase {
     pub name: String,
@@ -40,7 +41,12 @@ pub fn bls12_381_g2_add_tests(crypto: &CustomEvmCrypto) {
     for test in &tests {
         match parse_bls_g2_add_test(test) {
             Ok(t) => {
-                let result = crypto.bls12_381_g2_add(t.p1, t.p2);
+                let result = profile_call!(
+                    bls12_381_g2_add,
+                    "bls12_381_g2_add_tests",
+                    &t.name,
+                    crypto.bls12_381_g2_add(t.p1, t.p2)
+                );
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "G2 Add {} should succeed", t.name);
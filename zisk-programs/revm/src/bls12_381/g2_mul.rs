@@ -5,6 +5,7 @@ use super::common::{pad_g2_result, parse_g2_point_padded, G2Point};
 use crate::common::{
     parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
 
 struct BlsG2MulTestCase {
     pub name: String,
@@ -43,7 +44,12 @@ pub fn bls12_381_g2_mul_tests(crypto: &CustomEvmCrypto) {
             Ok(t) => {
                 let pairs = vec![(t.point, t.scalar)];
                 let mut iter = pairs.into_iter().map(Ok);
-                let result = crypto.bls12_381_g2_msm(&mut iter);
+                let result = profile_call!(
+                    bls12_381_g2_msm,
+                    "bls12_381_g2_mul_tests",
+                    &t.name,
+                    crypto.bls12_381_g2_msm(&mut iter)
+                );
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "G2 Mul {} should succeed", t.name);
@@ -5,6 +5,7 @@ use super::common::{pad_g2_result, parse_fp_padded};
 use crate::common::{
     parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
 
 struct BlsMapFp2ToG2TestCase {
     pub name: String,
@@ -42,7 +43,12 @@ pub fn bls12_381_map_fp2_to_g2_tests(crypto: &CustomEvmCrypto) {
     for test in &tests {
         match parse_bls_map_fp2_to_g2_test(test) {
             Ok(t) => {
-                let result = crypto.bls12_381_fp2_to_g2((t.fe0, t.fe1));
+                let result = profile_call!(
+                    bls12_381_map_fp2_to_g2,
+                    "bls12_381_map_fp2_to_g2_tests",
+                    &t.name,
+                    crypto.bls12_381_fp2_to_g2((t.fe0, t.fe1))
+                );
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "Map FP2 to G2 {} should succeed", t.name);
@@ -5,6 +5,7 @@ use super::common::{pad_g1_result, parse_fp_padded};
 use crate::common::{
     parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
 
 struct BlsMapFpToG1TestCase {
     pub name: String,
@@ -38,7 +39,12 @@ pub fn bls12_381_map_fp_to_g1_tests(crypto: &CustomEvmCrypto) {
     for test in &tests {
         match parse_bls_map_fp_to_g1_test(test) {
             Ok(t) => {
-                let result = crypto.bls12_381_fp_to_g1(&t.fe);
+                let result = profile_call!(
+                    bls12_381_map_fp_to_g1,
+                    "bls12_381_map_fp_to_g1_tests",
+                    &t.name,
+                    crypto.bls12_381_fp_to_g1(&t.fe)
+                );
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "Map FP to G1 {} should succeed", t.name);
@@ -1,10 +1,20 @@
 use guest_reth::CustomEvmCrypto;
 use revm::precompile::Crypto;
 
-use super::common::{parse_g1_point_padded, parse_g2_point_padded, G1Point, G2Point};
+use super::common::{
+    g1_generator, g1_generator_neg, g2_generator, parse_g1_point_padded, parse_g2_point_padded,
+    G1Point, G2Point,
+};
 use crate::common::{
-    parse_precompile_fail_json, parse_precompile_json, ExpectedOutcome, PrecompileTestCase,
+    fits_hint_budget, hint_probe, parse_precompile_fail_json, parse_precompile_json,
+    runs_fixed_tests, ExpectedOutcome, PrecompileTestCase,
 };
+use crate::profile::profile_call;
+
+/// Largest number of pairs per pairing check that is run with hints. Provisional: not measured yet.
+/// Set it to the largest size of the `input_hints_probe_bls12_381_pairing_<n>.bin` inputs that runs
+/// with hints. `bls12_381_pairing_hint_limit_test` checks exactly this size.
+const MAX_HINTS_PAIRS: usize = 2;
 
 struct BlsPairingTestCase {
     pub name: String,
@@ -42,10 +52,27 @@ pub fn bls12_381_pairing_tests(crypto: &CustomEvmCrypto) {
         "../testdata/precompiles/fail-blsPairing.json"
     )));
 
+    // A probe of the hint capacity only runs the hint limit test, at the probed size
+    if let Some(pairs) = hint_probe() {
+        bls12_381_pairing_hint_limit_test(crypto, pairs);
+        return;
+    }
+    if runs_fixed_tests() {
+        bls12_381_pairing_hint_limit_test(crypto, MAX_HINTS_PAIRS);
+    }
+
     for test in &tests {
         match parse_bls_pairing_test(test) {
             Ok(t) => {
-                let result = crypto.bls12_381_pairing_check(&t.pairs);
+                if !fits_hint_budget("bls12_381_pairing", &t.name, t.pairs.len(), MAX_HINTS_PAIRS) {
+                    continue;
+                }
+                let result = profile_call!(
+                    bls12_381_pairing,
+                    "bls12_381_pairing_tests",
+                    &t.name,
+                    crypto.bls12_381_pairing_check(&t.pairs)
+                );
                 match t.expected {
                     Some(expected) => {
                         assert!(result.is_ok(), "Pairing {} should succeed", t.name);
@@ -69,3 +96,23 @@ pub fn bls12_381_pairing_tests(crypto: &CustomEvmCrypto) {
 
     println!("All BLS12-381 Pairing tests passed!");
 }
+
+/// e(G, Q) * e(-G, Q) * ... with an even number of pairs, which is always true
+fn bls12_381_pairing_hint_limit_test(crypto: &CustomEvmCrypto, size: usize) {
+    assert!(size % 2 == 0, "Pairing hint limit needs an even number of pairs, got {size}");
+    let pairs: Vec<(G1Point, G2Point)> = (0..size)
+        .map(|i| {
+            let g1 = if i % 2 == 0 { g1_generator() } else { g1_generator_neg() };
+            (g1, g2_generator())
+        })
+        .collect();
+
+    let result = profile_call!(
+        bls12_381_pairing,
+        "bls12_381_pairing_hint_limit_test",
+        &format!("Hint limit {size}"),
+        crypto.bls12_381_pairing_check(&pairs)
+    );
+    assert!(result.is_ok(), "Pairing hint limit should succeed");
+    assert!(result.unwrap(), "Pairing hint limit should be true");
+}
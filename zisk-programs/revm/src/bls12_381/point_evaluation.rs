@@ -2,6 +2,7 @@ use guest_reth::CustomEvmCrypto;
 use revm::precompile::Crypto;
 
 use crate::common::{parse_precompile_json, ExpectedOutcome, PrecompileTestCase};
+use crate::profile::profile_call;
 
 const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
 
@@ -79,12 +80,17 @@ fn geth_tests(crypto: &CustomEvmCrypto) {
             .unwrap_or_else(|e| panic!("failed to parse {}: {}", test.name, e));
 
         // Verify versioned hash matches commitment
-        let mut hash = crypto.sha256(&t.commitment);
+        let mut hash = profile_call!(sha256, "geth_tests", &t.name, crypto.sha256(&t.commitment));
         hash[0] = VERSIONED_HASH_VERSION_KZG;
         assert_eq!(hash, t.versioned_hash, "pointEvaluation {} versioned hash mismatch", t.name);
 
         // Verify KZG proof
-        let result = crypto.verify_kzg_proof(&t.z, &t.y, &t.commitment, &t.proof);
+        let result = profile_call!(
+            point_evaluation,
+            "geth_tests",
+            &t.name,
+            crypto.verify_kzg_proof(&t.z, &t.y, &t.commitment, &t.proof)
+        );
         assert!(result.is_ok(), "pointEvaluation {} should succeed", t.name);
 
         // The output is always the fixed FIELD_ELEMENTS_PER_BLOB ++ BLS_MODULUS
@@ -141,7 +147,12 @@ fn tests_basic(crypto: &CustomEvmCrypto) {
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
 
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_basic",
+        "Test 1",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "Test 1: Incorrect proof should fail verification");
 
     // Test 2: Commitment has invalid serialization - should fail
@@ -158,7 +169,12 @@ fn tests_basic(crypto: &CustomEvmCrypto) {
         "0xb0c829a8d2d3405304fecbea193e6c67",
     );
 
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_basic",
+        "Test 2",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "Test 2: Invalid commitment serialization should fail");
 
     // Test 3: Edge case - [y] = 0 and proof = 𝒪 (point at infinity)
@@ -176,7 +192,12 @@ fn tests_basic(crypto: &CustomEvmCrypto) {
         "0xc0000000000000000000000000000000",
     );
 
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_basic",
+        "Test 3",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "Test 3: Edge case with point at infinity should pass");
 
     // Test 4: Standard test case 1
@@ -193,7 +214,12 @@ fn tests_basic(crypto: &CustomEvmCrypto) {
         "0xb07fece886f5ed974445971028e184c7",
     );
 
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_basic",
+        "Test 4",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "Test 4: Standard test case 1 should pass");
 
     // Test 5: Standard test case 2
@@ -210,7 +236,12 @@ fn tests_basic(crypto: &CustomEvmCrypto) {
         "0x930d5e1b4f053e7a633d8346a46d624b",
     );
 
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_basic",
+        "Test 5",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "Test 5: Standard test case 2 should pass");
 
     // Test 6: Standard test case 3
@@ -227,7 +258,12 @@ fn tests_basic(crypto: &CustomEvmCrypto) {
         "0x89eed617c24d8a8006b2e58884a93deb",
     );
 
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_basic",
+        "Test 6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "Test 6: Standard test case 3 should pass");
 }
 
@@ -248,7 +284,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_02e696ada7d4631d",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_02e696ada7d4631d should pass");
 
     // verify_kzg_proof_case_correct_proof_05c1f3685f3393f0
@@ -264,7 +305,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_05c1f3685f3393f0",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_05c1f3685f3393f0 should pass");
 
     // verify_kzg_proof_case_correct_proof_08f9e2f1cb3d39db
@@ -280,7 +326,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_08f9e2f1cb3d39db",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_08f9e2f1cb3d39db should pass");
 
     // verify_kzg_proof_case_correct_proof_0cf79b17cb5f4ea2
@@ -296,7 +347,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_0cf79b17cb5f4ea2",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_0cf79b17cb5f4ea2 should pass");
 
     // verify_kzg_proof_case_correct_proof_177b58dc7a46b08f
@@ -312,7 +368,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_177b58dc7a46b08f",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_177b58dc7a46b08f should pass");
 
     // verify_kzg_proof_case_correct_proof_1ce8e4f69d5df899
@@ -328,7 +389,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x4b7ba36a0f40e2dc086bc4061c7f63249877db23297212991fd63e07b7ebc348",
         "0x92c51ff81dd71dab71cefecd79e8274b",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_1ce8e4f69d5df899",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_1ce8e4f69d5df899 should pass");
 
     // verify_kzg_proof_case_correct_proof_26b753dec0560daa
@@ -344,7 +410,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xf06d936551667c82f659b75f99d2da2068b81340823ee4e829a93c9fbed7810d",
         "0xb82ded761997f2c6f1bb3db1e1dada2e",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_26b753dec0560daa",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_26b753dec0560daa should pass");
 
     // verify_kzg_proof_case_correct_proof_2b76dc9e3abf42f3
@@ -360,7 +431,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_2b76dc9e3abf42f3",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_2b76dc9e3abf42f3 should pass");
 
     // verify_kzg_proof_case_correct_proof_31ebd010e6098750
@@ -376,7 +452,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x75bf3a00f0aa3f7b8dd99a9abc2160744faf0070725e00b60ad9a026a15b1a8c",
         "0xa62ad71d14c5719385c0686f18714304",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_31ebd010e6098750",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_31ebd010e6098750 should pass");
 
     // verify_kzg_proof_case_correct_proof_3208425794224c3f
@@ -392,7 +473,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_3208425794224c3f",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_3208425794224c3f should pass");
 
     // verify_kzg_proof_case_correct_proof_36817bfd67de97a8
@@ -408,7 +494,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_36817bfd67de97a8",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_36817bfd67de97a8 should pass");
 
     // verify_kzg_proof_case_correct_proof_392169c16a2e5ef6
@@ -424,7 +515,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x499561f482419a3a372c42a636dad98262a2ce926d142fd7cfe26ca148efe8b4",
         "0xaa86c458b3065e7ec244033a2ade91a7",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_392169c16a2e5ef6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_392169c16a2e5ef6 should pass");
 
     // verify_kzg_proof_case_correct_proof_395cf6d697d1a743
@@ -440,7 +536,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_395cf6d697d1a743",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_395cf6d697d1a743 should pass");
 
     // verify_kzg_proof_case_correct_proof_3ac8dc31e9aa6a70
@@ -456,7 +557,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_3ac8dc31e9aa6a70",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_3ac8dc31e9aa6a70 should pass");
 
     // verify_kzg_proof_case_correct_proof_3c1e8b38219e3e12
@@ -472,7 +578,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x8876b2b207f1d5e54dd62a14e3242d123b5a6db066181ff01a51c26c9d2f400b",
         "0xb72d80393dc39beea3857cb371927713",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_3c1e8b38219e3e12",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_3c1e8b38219e3e12 should pass");
 
     // verify_kzg_proof_case_correct_proof_3c87ec986c2656c2
@@ -488,7 +599,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xbfe529f59247987cd1ab848d19de599a9052f1835fb0d0d44cf70183e19a68c9",
         "0xa444d6bb5aadc3ceb615b50d6606bd54",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_3c87ec986c2656c2",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_3c87ec986c2656c2 should pass");
 
     // verify_kzg_proof_case_correct_proof_3cd183d0bab85fb7
@@ -504,7 +620,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_3cd183d0bab85fb7",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_3cd183d0bab85fb7 should pass");
 
     // verify_kzg_proof_case_correct_proof_420f2a187ce77035
@@ -520,7 +641,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x444b83f54df1f5f274fb4312800a6505dd000ee8ec7b0ea6d72092a3daf0bffb",
         "0x89012990b0ca02775bd9df8145f6c936",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_420f2a187ce77035",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_420f2a187ce77035 should pass");
 
     // verify_kzg_proof_case_correct_proof_444b73ff54a19b44
@@ -536,7 +662,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xcaf781080222e0209b4a0b074decca874afc5c41de3313d8ed217d905e6ada43",
         "0xa060b350ad63d61979b80b25258e7cc6",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_444b73ff54a19b44",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_444b73ff54a19b44 should pass");
 
     // verify_kzg_proof_case_correct_proof_53a9bdf4f75196da
@@ -552,7 +683,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_53a9bdf4f75196da",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_53a9bdf4f75196da should pass");
 
     // verify_kzg_proof_case_correct_proof_585454b31673dd62
@@ -568,7 +704,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_585454b31673dd62",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_585454b31673dd62 should pass");
 
     // verify_kzg_proof_case_correct_proof_7db4f140a955dd1a
@@ -584,7 +725,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x8596854bac66b9cb2d6d361704f1735442d47ea09fda5e0984f0928ce7d2f5f6",
         "0x9506a8dc7f3f720a592a79a4e711e28d",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_7db4f140a955dd1a",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_7db4f140a955dd1a should pass");
 
     // verify_kzg_proof_case_correct_proof_83e53423a2dd93fe
@@ -600,7 +746,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xf7c3912a6adc7c3737ad3f8a3b750425c1531a7426f03033a3994bc82a10609f",
         "0xb0c829a8d2d3405304fecbea193e6c67",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_83e53423a2dd93fe",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_83e53423a2dd93fe should pass");
 
     // verify_kzg_proof_case_correct_proof_9b24f8997145435c
@@ -616,7 +767,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x2a6e3d47f96c0257bce642b70e8e375839a880864638669c6a709b414ab8bffc",
         "0xb9241c6816af6388d1014cd4d7dd2166",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_9b24f8997145435c",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_9b24f8997145435c should pass");
 
     // verify_kzg_proof_case_correct_proof_9b754afb690c47e1
@@ -632,7 +788,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_9b754afb690c47e1",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_9b754afb690c47e1 should pass");
 
     // verify_kzg_proof_case_correct_proof_a0be66af9a97ea52
@@ -648,7 +809,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_a0be66af9a97ea52",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_a0be66af9a97ea52 should pass");
 
     // verify_kzg_proof_case_correct_proof_af669445747d2585
@@ -664,7 +830,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xc24e21d42b1df2bfe1c8e28431c6221a3f1d09808042f5624e857710cb24fb69",
         "0x8a46b67dcba4e3aa66f9952be69e1ecb",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_af669445747d2585",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_af669445747d2585 should pass");
 
     // verify_kzg_proof_case_correct_proof_af8b75f664ed7d43
@@ -680,7 +851,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x588f2c61031781367cfea2a2be4ef3090035623338711b3cf7eff4b4524df742",
         "0x893acd46552b81cc9e5ff6ca03dad873",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_af8b75f664ed7d43",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_af8b75f664ed7d43 should pass");
 
     // verify_kzg_proof_case_correct_proof_b6cb6698327d9835
@@ -696,7 +872,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x340c809baa0e1fed9deaabb11aa503062acbbe23fcbe620a21b40a83bfa71b89",
         "0xa38758fca85407078c0a7e5fd6d38b34",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_b6cb6698327d9835",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_b6cb6698327d9835 should pass");
 
     // verify_kzg_proof_case_correct_proof_b6ec3736f9ff2c62
@@ -712,7 +893,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xc85b01076423a92c3335b93d10bf2fcb99b943a53adc1ab8feb6b475c4688948",
         "0xa256a681861974cdf6b116467044aa75",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_b6ec3736f9ff2c62",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_b6ec3736f9ff2c62 should pass");
 
     // verify_kzg_proof_case_correct_proof_becf2e1641bbd4e6
@@ -728,7 +914,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_becf2e1641bbd4e6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_becf2e1641bbd4e6 should pass");
 
     // verify_kzg_proof_case_correct_proof_c3d4322ec17fe7cd
@@ -744,7 +935,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_c3d4322ec17fe7cd",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_c3d4322ec17fe7cd should pass");
 
     // verify_kzg_proof_case_correct_proof_c5e1490d672d026d
@@ -760,7 +956,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xfa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a",
         "0x873033e038326e87ed3e1276fd140253",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_c5e1490d672d026d",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_c5e1490d672d026d should pass");
 
     // verify_kzg_proof_case_correct_proof_cae5d3491190b777
@@ -776,7 +977,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x951b64b5f31bfe2fa825e18ff49a259953e734b3d57119ae66f7bd79de3027f6",
         "0xb059c60125debbbf29d041bac20fd853",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_cae5d3491190b777",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_cae5d3491190b777 should pass");
 
     // verify_kzg_proof_case_correct_proof_d0992bc0387790a4
@@ -792,7 +998,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0824ba7fea5af812721b2393354b0810a9dba2c231ea7ae30f26c412c7ea6e3a",
         "0x987ea6df69bbe97c23e0dd948cf2d449",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_d0992bc0387790a4",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_d0992bc0387790a4 should pass");
 
     // verify_kzg_proof_case_correct_proof_d736268229bd87ec
@@ -808,7 +1019,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0d3707a655718f968c57e225f0e4b8d5fd61878234f25ec59d090c07ea725cf4",
         "0x94425f5cf336685a6a4e806ad4601f4b",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_d736268229bd87ec",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_d736268229bd87ec should pass");
 
     // verify_kzg_proof_case_correct_proof_e68d7111a2364a49
@@ -824,7 +1040,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x6548a14bc4af7127690a411f5e1cde2f73157365212dbcea6432e0e7869cb006",
         "0xa35c4f136a09a33c6437c26dc0c617ce",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_e68d7111a2364a49",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_e68d7111a2364a49 should pass");
 
     // verify_kzg_proof_case_correct_proof_ed6b180ec759bcf6
@@ -840,7 +1061,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xb0738f6e15a3e0755057e7d5460406c7e148adb0e2d608982140d0ae42fe0b3b",
         "0xa1fcd37a924af9ec04143b44853c26f6",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_ed6b180ec759bcf6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_ed6b180ec759bcf6 should pass");
 
     // verify_kzg_proof_case_correct_proof_f0ed3dc11cdeb130
@@ -856,7 +1082,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x690d88d9629927dc80b0856093e08a372820248df5b8a43b6d98fd52a62fa376",
         "0xa71f21ca51b443ad35bb8a26d274223a",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_f0ed3dc11cdeb130",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_f0ed3dc11cdeb130 should pass");
 
     // verify_kzg_proof_case_correct_proof_f47eb9fc139f6bfd
@@ -872,7 +1103,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_f47eb9fc139f6bfd",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_f47eb9fc139f6bfd should pass");
 
     // verify_kzg_proof_case_correct_proof_f7f44e1e864aa967
@@ -888,7 +1124,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0xc2d5337109016f36a766886eade28d32f205311ff5def247c3ddba91896fae97",
         "0x809adfa8b078b0921cdb8696ca017a0c",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_f7f44e1e864aa967",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_f7f44e1e864aa967 should pass");
 
     // verify_kzg_proof_case_correct_proof_ffa6e97b97146517
@@ -904,7 +1145,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "correct_proof_ffa6e97b97146517",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "correct_proof_ffa6e97b97146517 should pass");
 
     // =========================================================================
@@ -924,7 +1170,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_twos_poly_05c1f3685f3393f0",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_twos_poly_05c1f3685f3393f0 should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_twos_poly_177b58dc7a46b08f
@@ -940,7 +1191,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_twos_poly_177b58dc7a46b08f",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_twos_poly_177b58dc7a46b08f should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_twos_poly_2b76dc9e3abf42f3
@@ -956,7 +1212,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_twos_poly_2b76dc9e3abf42f3",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_twos_poly_2b76dc9e3abf42f3 should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_twos_poly_395cf6d697d1a743
@@ -972,7 +1233,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_twos_poly_395cf6d697d1a743",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_twos_poly_395cf6d697d1a743 should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_twos_poly_585454b31673dd62
@@ -988,7 +1254,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_twos_poly_585454b31673dd62",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_twos_poly_585454b31673dd62 should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_twos_poly_a0be66af9a97ea52
@@ -1004,7 +1275,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_twos_poly_a0be66af9a97ea52",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_twos_poly_a0be66af9a97ea52 should pass");
 
     // =========================================================================
@@ -1024,7 +1300,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_zero_poly_02e696ada7d4631d",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_zero_poly_02e696ada7d4631d should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_zero_poly_0cf79b17cb5f4ea2
@@ -1040,7 +1321,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_zero_poly_0cf79b17cb5f4ea2",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_zero_poly_0cf79b17cb5f4ea2 should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_zero_poly_3208425794224c3f
@@ -1056,7 +1342,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_zero_poly_3208425794224c3f",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_zero_poly_3208425794224c3f should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_zero_poly_3ac8dc31e9aa6a70
@@ -1072,7 +1363,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_zero_poly_3ac8dc31e9aa6a70",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_zero_poly_3ac8dc31e9aa6a70 should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_zero_poly_c3d4322ec17fe7cd
@@ -1088,7 +1384,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_zero_poly_c3d4322ec17fe7cd",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_zero_poly_c3d4322ec17fe7cd should pass");
 
     // verify_kzg_proof_case_correct_proof_point_at_infinity_for_zero_poly_ffa6e97b97146517
@@ -1104,7 +1405,12 @@ fn tests_correct(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_correct",
+        "point_at_infinity_for_zero_poly_ffa6e97b97146517",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_ok(), "point_at_infinity_for_zero_poly_ffa6e97b97146517 should pass");
 }
 
@@ -1125,7 +1431,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_02e696ada7d4631d",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_02e696ada7d4631d should fail");
 
     // verify_kzg_proof_case_incorrect_proof_05c1f3685f3393f0
@@ -1141,7 +1452,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_05c1f3685f3393f0",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_05c1f3685f3393f0 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_08f9e2f1cb3d39db
@@ -1157,7 +1473,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_08f9e2f1cb3d39db",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_08f9e2f1cb3d39db should fail");
 
     // verify_kzg_proof_case_incorrect_proof_0cf79b17cb5f4ea2
@@ -1173,7 +1494,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_0cf79b17cb5f4ea2",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_0cf79b17cb5f4ea2 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_177b58dc7a46b08f
@@ -1189,7 +1515,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_177b58dc7a46b08f",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_177b58dc7a46b08f should fail");
 
     // verify_kzg_proof_case_incorrect_proof_1ce8e4f69d5df899
@@ -1205,7 +1536,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xb2fe95bc3127ad9e6440d9e4d1e785b455f55fcfe80a3434dc40f8e6df85be88",
         "0x9779b8337f00de6aeac881256198bd2d",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_1ce8e4f69d5df899",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_1ce8e4f69d5df899 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_26b753dec0560daa
@@ -1221,7 +1557,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x9ab03a78342c221cf6b2d6e465d01a3d47585a808c9d8d25dee885007deeb107",
         "0x90f53a4837bbde6ab0838fef0c0be533",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_26b753dec0560daa",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_26b753dec0560daa should fail");
 
     // verify_kzg_proof_case_incorrect_proof_2b76dc9e3abf42f3
@@ -1237,7 +1578,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_2b76dc9e3abf42f3",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_2b76dc9e3abf42f3 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_31ebd010e6098750
@@ -1253,7 +1599,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xe9c958edbebe9ead62e97e95e2dcdc4972729fb9661f0cae3532b71b2664a8c1",
         "0xb9b65c2ebc89e669cf19e82fb178f0d1",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_31ebd010e6098750",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_31ebd010e6098750 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_3208425794224c3f
@@ -1269,7 +1620,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_3208425794224c3f",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_3208425794224c3f should fail");
 
     // verify_kzg_proof_case_incorrect_proof_36817bfd67de97a8
@@ -1285,7 +1641,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_36817bfd67de97a8",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_36817bfd67de97a8 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_392169c16a2e5ef6
@@ -1301,7 +1662,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x8e8851d8cfd9ea71da1ab4233ad4217cffabd669dfa89c3ebf4c44f91694a2f4",
         "0xb08a5afbb1717334e08e05576b07bff5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_392169c16a2e5ef6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_392169c16a2e5ef6 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_395cf6d697d1a743
@@ -1317,7 +1683,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_395cf6d697d1a743",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_395cf6d697d1a743 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_3ac8dc31e9aa6a70
@@ -1333,7 +1704,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_3ac8dc31e9aa6a70",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_3ac8dc31e9aa6a70 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_3c1e8b38219e3e12
@@ -1349,7 +1725,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xa58607777e09893f088e404eb2dc47c0269ed8e47c1be79ea07ae726abd921a8",
         "0x90559bfd8e58f5d144588a1a959c93ab",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_3c1e8b38219e3e12",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_3c1e8b38219e3e12 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_3c87ec986c2656c2
@@ -1365,7 +1746,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xced2ea6b622ebb6e289c7e05d85cc715b93eca244123c84a60b3ecbf33373903",
         "0x8d72dc4eec977090f452b412a6b0a3cd",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_3c87ec986c2656c2",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_3c87ec986c2656c2 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_3cd183d0bab85fb7
@@ -1381,7 +1767,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_3cd183d0bab85fb7",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_3cd183d0bab85fb7 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_420f2a187ce77035
@@ -1397,7 +1788,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc43df1ddbd1dbd9d5b71f3c1798ef482f5e1fd84500b0e47c82f72a189ecd526",
         "0x99c282db3a79a9ec1553306515e6a71d",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_420f2a187ce77035",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_420f2a187ce77035 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_444b73ff54a19b44
@@ -1413,7 +1809,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x88317299333f091dd88675e84a550577bfa564b2f57cd2498e2acf875e0aaa40",
         "0xa7de1e32bb336b85e42ff50281670421",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_444b73ff54a19b44",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_444b73ff54a19b44 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_53a9bdf4f75196da
@@ -1429,7 +1830,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_53a9bdf4f75196da",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_53a9bdf4f75196da should fail");
 
     // verify_kzg_proof_case_incorrect_proof_585454b31673dd62
@@ -1445,7 +1851,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_585454b31673dd62",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_585454b31673dd62 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_7db4f140a955dd1a
@@ -1461,7 +1872,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x8c127356567da1c456b9c38468909d4effe6b7faa11177e1f96ee5d2834df001",
         "0xb0ac600174134691bf9d91fee448b4d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_7db4f140a955dd1a",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_7db4f140a955dd1a should fail");
 
     // verify_kzg_proof_case_incorrect_proof_83e53423a2dd93fe
@@ -1477,7 +1893,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x17d91cfc59be47cfaa7d09ef626242517541992c0f76091ddabf271682cc7c2c",
         "0x8e3069b19e6e71aed9b7dc8fbba13e42",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_83e53423a2dd93fe",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_83e53423a2dd93fe should fail");
 
     // verify_kzg_proof_case_incorrect_proof_9b24f8997145435c
@@ -1493,7 +1914,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x5664bd4b6d52080460dd404dc2cb26269c24826d2bcd0152d0b55ee0a9e90289",
         "0xafc13cef6ed41f7abe142d32d7b5354e",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_9b24f8997145435c",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_9b24f8997145435c should fail");
 
     // verify_kzg_proof_case_incorrect_proof_9b754afb690c47e1
@@ -1509,7 +1935,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_9b754afb690c47e1",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_9b754afb690c47e1 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_a0be66af9a97ea52
@@ -1525,7 +1956,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_a0be66af9a97ea52",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_a0be66af9a97ea52 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_af669445747d2585
@@ -1541,7 +1977,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x804bf7096dae003d821cc01c3b7d35c6d1fdae14e2db3c05e1cdcea7c7b7f262",
         "0xa88d68fe3ad0d09b07f4605b1364c8d4",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_af669445747d2585",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_af669445747d2585 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_af8b75f664ed7d43
@@ -1557,7 +1998,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x30f238fc3cb2ecdbdc0bbb6419e3e60507e823ff7dcbd17394cea55bc514716c",
         "0xaf08cbca9deec336f2a56ca0b2029958",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_af8b75f664ed7d43",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_af8b75f664ed7d43 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_b6cb6698327d9835
@@ -1573,7 +2019,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xecaf1db28384925d5007bcf7dff1a53b72bdf522610303075aeecab41685d720",
         "0x861a2aef7aa82db033bfa125b9f756af",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_b6cb6698327d9835",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_b6cb6698327d9835 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_b6ec3736f9ff2c62
@@ -1589,7 +2040,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x5b03a872a10829236d184fe1872767c391c2aa7e3b85babb1e6093b7224e7732",
         "0x82f1cd05471ab6ff21bcfd5c3369cba0",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_b6ec3736f9ff2c62",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_b6ec3736f9ff2c62 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_becf2e1641bbd4e6
@@ -1605,7 +2061,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_becf2e1641bbd4e6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_becf2e1641bbd4e6 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_c3d4322ec17fe7cd
@@ -1621,7 +2082,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_c3d4322ec17fe7cd",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_c3d4322ec17fe7cd should fail");
 
     // verify_kzg_proof_case_incorrect_proof_c5e1490d672d026d
@@ -1637,7 +2103,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x993da2646e87140e12631e2914d9e6c676466aa3adfc91b61f84255544cab544",
         "0xacd56791e0ab0d1b3802021862013418",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_c5e1490d672d026d",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_c5e1490d672d026d should fail");
 
     // verify_kzg_proof_case_incorrect_proof_cae5d3491190b777
@@ -1653,7 +2124,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x6da3ab8d2c15070f323e5a13a8178fe07c8f89686e5fd16565247b520028251b",
         "0xa4cc8c419ade0cf043cbf30f43c8f7ee",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_cae5d3491190b777",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_cae5d3491190b777 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_d0992bc0387790a4
@@ -1669,7 +2145,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x0e933e3a881b208de54149714ece74a599503f84c6249b5fd8a7c70189882a6b",
         "0xb8f731ba6a52e419ffc843c50d2947d3",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_d0992bc0387790a4",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_d0992bc0387790a4 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_d736268229bd87ec
@@ -1685,7 +2166,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x6dedc08fd467f41fabae6bb042c2d0dbdbcd5f7532c475e479588eec5820fd37",
         "0x84c349506215a2d55f9d06f475b8229c",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_d736268229bd87ec",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_d736268229bd87ec should fail");
 
     // verify_kzg_proof_case_incorrect_proof_e68d7111a2364a49
@@ -1701,7 +2187,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xe96f7d25f8b4fe885059ec24af36f801ffbf68ec4604ef6e5f5f800f5cf31238",
         "0x94fce36bf7e9f0ed981728fcd829013d",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_e68d7111a2364a49",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_e68d7111a2364a49 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_ed6b180ec759bcf6
@@ -1717,7 +2208,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x6d52613c59502a3d2df58217f4e366cd9ef37dee55bf2c705a2b08e7808b6fa0",
         "0xb3477fc9a5bfab5fdb5523251818ee5a",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_ed6b180ec759bcf6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_ed6b180ec759bcf6 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_f0ed3dc11cdeb130
@@ -1733,7 +2229,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc7743f7e5a19ee4b557471c005600f56d78e3dd887b2f5b87d76405b80dd2115",
         "0x98e15cbf800b69b90bfcaf1d907a9889",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_f0ed3dc11cdeb130",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_f0ed3dc11cdeb130 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_f47eb9fc139f6bfd
@@ -1749,7 +2250,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xf52870e6565307ff9e32327196d7a03c428fc51a9abedc97de2a68daa1274b50",
         "0x98613e9e1b1ed52fc2fdc54e945b863f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_f47eb9fc139f6bfd",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_f47eb9fc139f6bfd should fail");
 
     // verify_kzg_proof_case_incorrect_proof_f7f44e1e864aa967
@@ -1765,7 +2271,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xde681b51b312bf718821937e5088cd8ee002b718264027d10c5c5855dabe0353",
         "0xa1d8f2a5ab22acdfc1a9492ee2e1c2cb",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_f7f44e1e864aa967",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_f7f44e1e864aa967 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_ffa6e97b97146517
@@ -1781,7 +2292,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0xc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
         "0x97f1d3a73197d7942695638c4fa9ac0f",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_ffa6e97b97146517",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_ffa6e97b97146517 should fail");
 
     // =========================================================================
@@ -1801,7 +2317,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_point_at_infinity_392169c16a2e5ef6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_point_at_infinity_392169c16a2e5ef6 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_point_at_infinity_3c1e8b38219e3e12
@@ -1817,7 +2338,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_point_at_infinity_3c1e8b38219e3e12",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_point_at_infinity_3c1e8b38219e3e12 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_point_at_infinity_3c87ec986c2656c2
@@ -1833,7 +2359,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_point_at_infinity_3c87ec986c2656c2",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_point_at_infinity_3c87ec986c2656c2 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_point_at_infinity_420f2a187ce77035
@@ -1849,7 +2380,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_point_at_infinity_420f2a187ce77035",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_point_at_infinity_420f2a187ce77035 should fail");
 
     // verify_kzg_proof_case_incorrect_proof_point_at_infinity_83e53423a2dd93fe
@@ -1865,7 +2401,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_point_at_infinity_83e53423a2dd93fe",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_point_at_infinity_83e53423a2dd93fe should fail");
 
     // verify_kzg_proof_case_incorrect_proof_point_at_infinity_ed6b180ec759bcf6
@@ -1881,7 +2422,12 @@ fn tests_incorrect(crypto: &CustomEvmCrypto) {
         "0x0000000000000000000000000000000000000000000000000000000000000000",
         "0xc0000000000000000000000000000000",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_incorrect",
+        "incorrect_proof_point_at_infinity_ed6b180ec759bcf6",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "incorrect_proof_point_at_infinity_ed6b180ec759bcf6 should fail");
 }
 
@@ -1920,7 +2466,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0xf7c3912a6adc7c3737ad3f8a3b750425c1531a7426f03033a3994bc82a10609f",
         "0xb0c829a8d2d3405304fecbea193e6c67",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_commitment_1b44e341d56c757d: invalid serialization",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(
         result.is_err(),
         "invalid_commitment_1b44e341d56c757d: invalid serialization should fail"
@@ -1940,7 +2491,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0xf7c3912a6adc7c3737ad3f8a3b750425c1531a7426f03033a3994bc82a10609f",
         "0xb0c829a8d2d3405304fecbea193e6c67",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_commitment_e9d3e9ec16fbc15f: invalid serialization",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(
         result.is_err(),
         "invalid_commitment_e9d3e9ec16fbc15f: invalid serialization should fail"
@@ -1960,7 +2516,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0xf7c3912a6adc7c3737ad3f8a3b750425c1531a7426f03033a3994bc82a10609f",
         "0xb0c829a8d2d3405304fecbea193e6c67",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_commitment_32afa9561a4b3b91: commit not in G1",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_commitment_32afa9561a4b3b91: commit not in G1 should fail");
 
     // // verify_kzg_proof_case_invalid_proof_3e55802a5ed3c757
@@ -1994,7 +2555,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6",
         "0x97f1d3a73197d7942695638c4fa9ac",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_proof_1b44e341d56c757d: invalid serialization",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_proof_1b44e341d56c757d: invalid serialization should fail");
 
     // verify_kzg_proof_case_invalid_proof_e9d3e9ec16fbc15f
@@ -2011,7 +2577,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcde0",
         "0x8123456789abcdef0123456789abcdef",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_proof_e9d3e9ec16fbc15f: invalid serialization",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_proof_e9d3e9ec16fbc15f: invalid serialization should fail");
 
     // verify_kzg_proof_case_invalid_proof_32afa9561a4b3b91
@@ -2028,7 +2599,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
         "0x8123456789abcdef0123456789abcdef",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_proof_32afa9561a4b3b91: proof not in G1",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_proof_32afa9561a4b3b91: proof not in G1 should fail");
 
     // verify_kzg_proof_case_invalid_y_35d08d612aad2197
@@ -2045,7 +2621,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_y_35d08d612aad2197: y > r",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_y_35d08d612aad2197: y > r should fail");
 
     // verify_kzg_proof_case_invalid_y_4aa6def8c35c9097
@@ -2062,7 +2643,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_y_4aa6def8c35c9097: y > r",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_y_4aa6def8c35c9097: y > r should fail");
 
     // verify_kzg_proof_case_invalid_y_64b9ff2b8f7dddee
@@ -2079,7 +2665,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_y_64b9ff2b8f7dddee: y = r + 1",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_y_64b9ff2b8f7dddee: y = r + 1 should fail");
 
     // verify_kzg_proof_case_invalid_y_eb0601fec84cc5e9
@@ -2096,7 +2687,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_y_eb0601fec84cc5e9: y = r",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_y_eb0601fec84cc5e9: y = r should fail");
 
     // verify_kzg_proof_case_invalid_z_35d08d612aad2197
@@ -2113,7 +2709,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_z_35d08d612aad2197: z > r",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_z_35d08d612aad2197: z > r should fail");
 
     // verify_kzg_proof_case_invalid_z_4aa6def8c35c9097
@@ -2130,7 +2731,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_z_4aa6def8c35c9097: z > r",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_z_4aa6def8c35c9097: z > r should fail");
 
     // verify_kzg_proof_case_invalid_z_64b9ff2b8f7dddee
@@ -2147,7 +2753,12 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_z_64b9ff2b8f7dddee: z = r + 1",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_z_64b9ff2b8f7dddee: z = r + 1 should fail");
 
     // verify_kzg_proof_case_invalid_z_eb0601fec84cc5e9
@@ -2164,6 +2775,11 @@ fn tests_invalid(crypto: &CustomEvmCrypto) {
         "0x8fa286f5f75fea48870585393f890909cd3c53cfe4897e799fb211b4be531e43",
         "0xb30b3d1e4faccc380557792c9a0374d5",
     );
-    let result = crypto.verify_kzg_proof(&z, &y, &commitment, &proof);
+    let result = profile_call!(
+        point_evaluation,
+        "tests_invalid",
+        "invalid_z_eb0601fec84cc5e9: z = r",
+        crypto.verify_kzg_proof(&z, &y, &commitment, &proof)
+    );
     assert!(result.is_err(), "invalid_z_eb0601fec84cc5e9: z = r should fail");
 }
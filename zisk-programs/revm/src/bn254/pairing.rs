@@ -2,7 +2,15 @@ use guest_reth::CustomEvmCrypto;
 use revm::precompile::Crypto;
 
 use super::common::{build_g1_point, build_g2_point};
-use crate::common::{parse_precompile_json, PrecompileTestCase};
+use crate::common::{
+    fits_hint_budget, hint_probe, parse_precompile_json, runs_fixed_tests, PrecompileTestCase,
+};
+use crate::profile::profile_call;
+
+/// Largest number of pairs per pairing check that is run with hints. Provisional: not measured yet.
+/// Set it to the largest size of the `input_hints_probe_bn254_pairing_<n>.bin` inputs that runs
+/// with hints. `ecpairing_hint_limit_test` checks exactly this size.
+const MAX_HINTS_PAIRS: usize = 2;
 
 struct EcPairingTestCase {
     name: String,
@@ -31,9 +39,55 @@ fn parse_ecpairing_test(test: &PrecompileTestCase) -> EcPairingTestCase {
 }
 
 pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
+    // A probe of the hint capacity only runs the hint limit test, at the probed size
+    if let Some(pairs) = hint_probe() {
+        ecpairing_hint_limit_test(crypto, pairs);
+        return;
+    }
+    if runs_fixed_tests() {
+        ecpairing_hint_limit_test(crypto, MAX_HINTS_PAIRS);
+        ecpairing_fixed_tests(crypto);
+    }
+
+    // Geth test vectors
+    for test in &parse_precompile_json(include_str!("../testdata/precompiles/bn256Pairing.json")) {
+        let t = parse_ecpairing_test(test);
+        if !fits_hint_budget("bn254_pairing", &t.name, t.pairs.len(), MAX_HINTS_PAIRS) {
+            continue;
+        }
+        let pairs: Vec<(&[u8], &[u8])> =
+            t.pairs.iter().map(|(g1, g2)| (g1.as_slice(), g2.as_slice())).collect();
+        let result = profile_call!(
+            bn254_pairing,
+            "ecpairing_tests",
+            &t.name,
+            crypto.bn254_pairing_check(&pairs)
+        );
+        assert!(result.is_ok(), "bn254Pairing {} should succeed", t.name);
+        assert_eq!(
+            result.clone().unwrap(),
+            t.expected,
+            "bn254Pairing {} mismatch: got {}, expected {}",
+            t.name,
+            result.unwrap(),
+            t.expected
+        );
+    }
+
+    println!("All EcPairing tests passed!");
+}
+
+/// Hand-written pairing checks
+fn ecpairing_fixed_tests(crypto: &CustomEvmCrypto) {
     // 1] 0 inputs should return true (empty pairing)
     let pairs: &[(&[u8], &[u8])] = &[];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 1 should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 1",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 1 should succeed");
     assert!(result, "Test 1: empty pairing should return true");
 
     // 2] Tests with 1 pair
@@ -46,7 +100,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "322506915963699862059245473966830598387691259163658767351233132602858049743",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.1a",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.1a: G1 (0,1) not on curve should fail");
 
     // 2.1b] Invalid G2 point (wrong y coordinate)
@@ -58,7 +117,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "322506915963699862059245473966830598387691259163658767351233132602858049743",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.1b",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.1b: G2 not on curve should fail");
 
     // 2.1c] Invalid - G1 on curve but G2 not on curve
@@ -70,21 +134,36 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "322506915963699862059245473966830598387691259163658767351233132602858049743",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.1c",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.1c: G2 not on curve should fail");
 
     // 2.1d] Invalid - G1 not on curve (1, 1)
     let g1 = build_g1_point("1", "1");
     let g2 = build_g2_point("0", "0", "0", "0");
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.1d",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.1d: G1 (1,1) not on curve should fail");
 
     // 2.1e] Invalid - G2 not on curve (1, 2, 3, 3)
     let g1 = build_g1_point("0", "0");
     let g2 = build_g2_point("1", "2", "3", "3");
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.1e",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.1e: G2 (1,2,3,3) not on curve should fail");
 
     // 2.2] Out of range tests - G1.x >= P
@@ -94,7 +173,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
     );
     let g2 = build_g2_point("0", "0", "0", "0");
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.2a",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.2a: G1.x >= P should fail");
 
     // 2.2b] G1.y >= P
@@ -104,7 +188,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
     );
     let g2 = build_g2_point("0", "0", "0", "0");
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.2b",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.2b: G1.y >= P should fail");
 
     // 2.2c] G2.x1 >= P
@@ -116,7 +205,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "0",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.2c",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.2c: G2.x1 >= P should fail");
 
     // 2.2d] G2.x2 >= P
@@ -128,7 +222,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "0",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.2d",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.2d: G2.x2 >= P should fail");
 
     // 2.2e] G2.y1 >= P
@@ -140,7 +239,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "0",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.2e",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.2e: G2.y1 >= P should fail");
 
     // 2.2f] G2.y2 >= P
@@ -152,7 +256,12 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "21888242871839275222246405745257275088696311157297823662689037894645226208583",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs);
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.2f",
+        crypto.bn254_pairing_check(pairs)
+    );
     assert!(result.is_err(), "Test 2.2f: G2.y2 >= P should fail");
 
     // 2.3] Degenerate tests: e(0,Q) = 1 or e(P,0) = 1
@@ -165,14 +274,26 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "322506915963699862059245473966830598387691259163658767351233132602858049743",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 2.3a should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.3a",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 2.3a should succeed");
     assert!(result, "Test 2.3a: e(0, Q) should be 1");
 
     // e(P, 0) = 1
     let g1 = build_g1_point("1", "2");
     let g2 = build_g2_point("0", "0", "0", "0");
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 2.3b should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.3b",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 2.3b should succeed");
     assert!(result, "Test 2.3b: e(P, 0) should be 1");
 
     // e(0, G2_generator) = 1
@@ -184,7 +305,13 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "8495653923123431417604973247489272438418190587263600148770280649306958101930",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 2.3c should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.3c",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 2.3c should succeed");
     assert!(result, "Test 2.3c: e(0, G2) should be 1");
 
     // e(0, another G2 point) = 1
@@ -196,7 +323,13 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "16129402215257578064845163124174157135534373400489420174780024516864802406908",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 2.3d should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.3d",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 2.3d should succeed");
     assert!(result, "Test 2.3d: e(0, G2) should be 1");
 
     let g1 = build_g1_point(
@@ -210,7 +343,13 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "18464139784518468940813808456787395271551658665172075242540518796021481349881",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 2.4d should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.4d",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 2.4d should succeed");
     assert!(!result, "Test 2.4d: e(G1, G2) should not be 1");
 
     let g1 = build_g1_point(
@@ -224,7 +363,13 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "12312322467441077980953049706840861745999649183180767181888229916296961272395",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1, &g2)];
-    assert!(crypto.bn254_pairing_check(pairs).is_err());
+    assert!(profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 2.4e",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .is_err());
 
     // 3] Tests with 2 pairs (12 inputs)
 
@@ -250,7 +395,13 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "16129402215257578064845163124174157135534373400489420174780024516864802406908",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1_1, &g2_1), (&g1_2, &g2_2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 3a should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 3a",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 3a should succeed");
     assert!(result, "Test 3a: Ethereum example pairing should be true");
 
     // KZG proof with one poly and one evaluation (test 1)
@@ -275,7 +426,13 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "8495653923123431417604973247489272438418190587263600148770280649306958101930",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1_1, &g2_1), (&g1_2, &g2_2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 3b should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 3b",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 3b should succeed");
     assert!(result, "Test 3b: KZG proof pairing should be true");
 
     // KZG proof with one poly and one evaluation (test 2)
@@ -300,135 +457,171 @@ pub fn ecpairing_tests(crypto: &CustomEvmCrypto) {
         "8495653923123431417604973247489272438418190587263600148770280649306958101930",
     );
     let pairs: &[(&[u8], &[u8])] = &[(&g1_1, &g2_1), (&g1_2, &g2_2)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 3c should succeed");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_tests",
+        "Test 3c",
+        crypto.bn254_pairing_check(pairs)
+    )
+    .expect("Test 3c should succeed");
     assert!(result, "Test 3c: KZG proof pairing should be true");
 
     // 4] Tests with 3 pairs (18 inputs)
-
-    // 3 pairs with last pair being (G1, 0)
-    let g1_1 = build_g1_point(
-        "7732322222446307127032679746925673403013840763103947213960757438494804067267",
-        "8619360092012773279112944586645719683585858765189162557863470404130431808723",
-    );
-    let g2_1 = build_g2_point(
-        "7754062701624777074058760614745676120554164137217320298195308357000412149840",
-        "4480687189204505779534873101802061566996023148878380905742776654135663383221",
-        "18744429014512523574338799100424477374744612401726532054975840530120472566",
-        "16667361185745910936700318129097219900413959552154798924397125501722669434888",
-    );
-    let g1_2 = build_g1_point(
-        "595801121933130257838893357109567932541713044978712091132608377833002940532",
-        "15681552092527426161541501125159206079106959026991100968107368848241580050483",
-    );
-    let g2_2 = build_g2_point(
-        "11559732032986387107991004021392285783925812861821192530917403151452391805634",
-        "10857046999023057135944570762232829481370756359578518086990519993285655852781",
-        "4082367875863433681332203403145435568316851327593401208105741076214120093531",
-        "8495653923123431417604973247489272438418190587263600148770280649306958101930",
-    );
-    let g1_3 = build_g1_point("1", "2");
-    let g2_3 = build_g2_point("0", "0", "0", "0");
-    let pairs: &[(&[u8], &[u8])] = &[(&g1_1, &g2_1), (&g1_2, &g2_2), (&g1_3, &g2_3)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 4a should succeed");
-    assert!(result, "Test 4a: 3 pairs with (G1, 0) should be true");
-
-    // Another 3-pair test
-    let g1_1 = build_g1_point(
-        "20408625067408993290064640368727791004970573998302586029702220794326757674498",
-        "16305464745216061320718924810220361252899630638785881184214175311729150579496",
-    );
-    let g2_1 = build_g2_point(
-        "19366297632879679637284621799459008574776307690134846433263569915955921902826",
-        "7402184029652592179271650707149396214555402416834379616679103713331638701004",
-        "13233069919494729038860025360853108843397419493559475327647450442468969143158",
-        "10493112377715503836766497500954305714610771526749266396762372159550562853087",
-    );
-    let g1_2 = build_g1_point(
-        "6065896804174124393372571703959114319291624137637105019419069942189555692569",
-        "1817372094771574002977021734119138264961743925299214620753363200235482672254",
-    );
-    let g2_2 = build_g2_point(
-        "19366297632879679637284621799459008574776307690134846433263569915955921902826",
-        "7402184029652592179271650707149396214555402416834379616679103713331638701004",
-        "13233069919494729038860025360853108843397419493559475327647450442468969143158",
-        "10493112377715503836766497500954305714610771526749266396762372159550562853087",
-    );
-    let g1_3 = build_g1_point(
-        "5155695327752856721154364733178772660419613502017586895566245903460009198248",
-        "17870951736543108265510715325941304521966082260796939666348236029204261385066",
-    );
-    let g2_3 = build_g2_point(
-        "11559732032986387107991004021392285783925812861821192530917403151452391805634",
-        "10857046999023057135944570762232829481370756359578518086990519993285655852781",
-        "4082367875863433681332203403145435568316851327593401208105741076214120093531",
-        "8495653923123431417604973247489272438418190587263600148770280649306958101930",
-    );
-    let pairs: &[(&[u8], &[u8])] = &[(&g1_1, &g2_1), (&g1_2, &g2_2), (&g1_3, &g2_3)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 4b should succeed");
-    assert!(result, "Test 4b: 3 pairs pairing should be true");
+    if fits_hint_budget("bn254_pairing", "Test 4", 3, MAX_HINTS_PAIRS) {
+        // 3 pairs with last pair being (G1, 0)
+        let g1_1 = build_g1_point(
+            "7732322222446307127032679746925673403013840763103947213960757438494804067267",
+            "8619360092012773279112944586645719683585858765189162557863470404130431808723",
+        );
+        let g2_1 = build_g2_point(
+            "7754062701624777074058760614745676120554164137217320298195308357000412149840",
+            "4480687189204505779534873101802061566996023148878380905742776654135663383221",
+            "18744429014512523574338799100424477374744612401726532054975840530120472566",
+            "16667361185745910936700318129097219900413959552154798924397125501722669434888",
+        );
+        let g1_2 = build_g1_point(
+            "595801121933130257838893357109567932541713044978712091132608377833002940532",
+            "15681552092527426161541501125159206079106959026991100968107368848241580050483",
+        );
+        let g2_2 = build_g2_point(
+            "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+            "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+            "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+            "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+        );
+        let g1_3 = build_g1_point("1", "2");
+        let g2_3 = build_g2_point("0", "0", "0", "0");
+        let pairs: &[(&[u8], &[u8])] = &[(&g1_1, &g2_1), (&g1_2, &g2_2), (&g1_3, &g2_3)];
+        let result = profile_call!(
+            bn254_pairing,
+            "ecpairing_tests",
+            "Test 4a",
+            crypto.bn254_pairing_check(pairs)
+        )
+        .expect("Test 4a should succeed");
+        assert!(result, "Test 4a: 3 pairs with (G1, 0) should be true");
+
+        // Another 3-pair test
+        let g1_1 = build_g1_point(
+            "20408625067408993290064640368727791004970573998302586029702220794326757674498",
+            "16305464745216061320718924810220361252899630638785881184214175311729150579496",
+        );
+        let g2_1 = build_g2_point(
+            "19366297632879679637284621799459008574776307690134846433263569915955921902826",
+            "7402184029652592179271650707149396214555402416834379616679103713331638701004",
+            "13233069919494729038860025360853108843397419493559475327647450442468969143158",
+            "10493112377715503836766497500954305714610771526749266396762372159550562853087",
+        );
+        let g1_2 = build_g1_point(
+            "6065896804174124393372571703959114319291624137637105019419069942189555692569",
+            "1817372094771574002977021734119138264961743925299214620753363200235482672254",
+        );
+        let g2_2 = build_g2_point(
+            "19366297632879679637284621799459008574776307690134846433263569915955921902826",
+            "7402184029652592179271650707149396214555402416834379616679103713331638701004",
+            "13233069919494729038860025360853108843397419493559475327647450442468969143158",
+            "10493112377715503836766497500954305714610771526749266396762372159550562853087",
+        );
+        let g1_3 = build_g1_point(
+            "5155695327752856721154364733178772660419613502017586895566245903460009198248",
+            "17870951736543108265510715325941304521966082260796939666348236029204261385066",
+        );
+        let g2_3 = build_g2_point(
+            "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+            "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+            "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+            "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+        );
+        let pairs: &[(&[u8], &[u8])] = &[(&g1_1, &g2_1), (&g1_2, &g2_2), (&g1_3, &g2_3)];
+        let result = profile_call!(
+            bn254_pairing,
+            "ecpairing_tests",
+            "Test 4b",
+            crypto.bn254_pairing_check(pairs)
+        )
+        .expect("Test 4b should succeed");
+        assert!(result, "Test 4b: 3 pairs pairing should be true");
+    }
 
     // 5] Tests with 4 pairs (24 inputs)
-    let g1_1 = build_g1_point(
-        "1153563745531144946586097928621095258348432585499389732309707300454996283289",
-        "7370404687973809887690049462468892748861464831518247317487007737601322454777",
-    );
-    let g2_1 = build_g2_point(
-        "9376055848676368316410365621777214987372973768688270899357881297879508822452",
-        "19738309004667351906306506105426292998739264612662465709107894554928292805496",
-        "285143926121120094170748007008262512509578107228129423236125884572189904421",
-        "10279962913447536422932523162364510093030414102832227875578519449385249705476",
-    );
-    let g1_2 = build_g1_point(
-        "8576791937965657966843713337336683588215881223744955532549571901036035091965",
-        "20999102966105130950411191886633074956452730563320480529699815227954081231322",
-    );
-    let g2_2 = build_g2_point(
-        "9376055848676368316410365621777214987372973768688270899357881297879508822452",
-        "19738309004667351906306506105426292998739264612662465709107894554928292805496",
-        "285143926121120094170748007008262512509578107228129423236125884572189904421",
-        "10279962913447536422932523162364510093030414102832227875578519449385249705476",
-    );
-    let g1_3 = build_g1_point(
-        "18556379486610508840908277815073629329531616761731760569700551412487192333649",
-        "17673868103043290791894327402153901008120365354485186198280340860768344163073",
-    );
-    let g2_3 = build_g2_point(
-        "11559732032986387107991004021392285783925812861821192530917403151452391805634",
-        "10857046999023057135944570762232829481370756359578518086990519993285655852781",
-        "4082367875863433681332203403145435568316851327593401208105741076214120093531",
-        "8495653923123431417604973247489272438418190587263600148770280649306958101930",
-    );
-    let g1_4 = build_g1_point(
-        "20364104435611758595377721340560864676183708759135257849771131236782155536356",
-        "6044194345605039714961350342623860353524318320217972076629496104743557530117",
+    if fits_hint_budget("bn254_pairing", "Test 5", 4, MAX_HINTS_PAIRS) {
+        let g1_1 = build_g1_point(
+            "1153563745531144946586097928621095258348432585499389732309707300454996283289",
+            "7370404687973809887690049462468892748861464831518247317487007737601322454777",
+        );
+        let g2_1 = build_g2_point(
+            "9376055848676368316410365621777214987372973768688270899357881297879508822452",
+            "19738309004667351906306506105426292998739264612662465709107894554928292805496",
+            "285143926121120094170748007008262512509578107228129423236125884572189904421",
+            "10279962913447536422932523162364510093030414102832227875578519449385249705476",
+        );
+        let g1_2 = build_g1_point(
+            "8576791937965657966843713337336683588215881223744955532549571901036035091965",
+            "20999102966105130950411191886633074956452730563320480529699815227954081231322",
+        );
+        let g2_2 = build_g2_point(
+            "9376055848676368316410365621777214987372973768688270899357881297879508822452",
+            "19738309004667351906306506105426292998739264612662465709107894554928292805496",
+            "285143926121120094170748007008262512509578107228129423236125884572189904421",
+            "10279962913447536422932523162364510093030414102832227875578519449385249705476",
+        );
+        let g1_3 = build_g1_point(
+            "18556379486610508840908277815073629329531616761731760569700551412487192333649",
+            "17673868103043290791894327402153901008120365354485186198280340860768344163073",
+        );
+        let g2_3 = build_g2_point(
+            "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+            "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+            "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+            "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+        );
+        let g1_4 = build_g1_point(
+            "20364104435611758595377721340560864676183708759135257849771131236782155536356",
+            "6044194345605039714961350342623860353524318320217972076629496104743557530117",
+        );
+        let g2_4 = build_g2_point(
+            "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+            "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+            "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+            "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+        );
+        let pairs: &[(&[u8], &[u8])] =
+            &[(&g1_1, &g2_1), (&g1_2, &g2_2), (&g1_3, &g2_3), (&g1_4, &g2_4)];
+        let result = profile_call!(
+            bn254_pairing,
+            "ecpairing_tests",
+            "Test 5",
+            crypto.bn254_pairing_check(pairs)
+        )
+        .expect("Test 5 should succeed");
+        assert!(result, "Test 5: 4 pairs pairing should be true");
+    }
+}
+
+/// e(G1, G2) * e(-G1, G2) * ... with an even number of pairs, which is always true
+fn ecpairing_hint_limit_test(crypto: &CustomEvmCrypto, size: usize) {
+    assert!(size % 2 == 0, "Pairing hint limit needs an even number of pairs, got {size}");
+    let g1 = build_g1_point("1", "2");
+    let g1_neg = build_g1_point(
+        "1",
+        "21888242871839275222246405745257275088696311157297823662689037894645226208581",
     );
-    let g2_4 = build_g2_point(
+    let g2 = build_g2_point(
         "11559732032986387107991004021392285783925812861821192530917403151452391805634",
         "10857046999023057135944570762232829481370756359578518086990519993285655852781",
         "4082367875863433681332203403145435568316851327593401208105741076214120093531",
         "8495653923123431417604973247489272438418190587263600148770280649306958101930",
     );
-    let pairs: &[(&[u8], &[u8])] =
-        &[(&g1_1, &g2_1), (&g1_2, &g2_2), (&g1_3, &g2_3), (&g1_4, &g2_4)];
-    let result = crypto.bn254_pairing_check(pairs).expect("Test 5 should succeed");
-    assert!(result, "Test 5: 4 pairs pairing should be true");
-
-    // Geth test vectors
-    for test in &parse_precompile_json(include_str!("../testdata/precompiles/bn256Pairing.json")) {
-        let t = parse_ecpairing_test(test);
-        let pairs: Vec<(&[u8], &[u8])> =
-            t.pairs.iter().map(|(g1, g2)| (g1.as_slice(), g2.as_slice())).collect();
-        let result = crypto.bn254_pairing_check(&pairs);
-        assert!(result.is_ok(), "bn254Pairing {} should succeed", t.name);
-        assert_eq!(
-            result.clone().unwrap(),
-            t.expected,
-            "bn254Pairing {} mismatch: got {}, expected {}",
-            t.name,
-            result.unwrap(),
-            t.expected
-        );
-    }
+    let pairs: Vec<(&[u8], &[u8])> = (0..size)
+        .map(|i| (if i % 2 == 0 { g1.as_slice() } else { g1_neg.as_slice() }, g2.as_slice()))
+        .collect();
 
-    println!("All EcPairing tests passed!");
+    let result = profile_call!(
+        bn254_pairing,
+        "ecpairing_hint_limit_test",
+        &format!("Hint limit {size}"),
+        crypto.bn254_pairing_check(&pairs)
+    )
+    .expect("Hint limit should succeed");
+    assert!(result, "Hint limit: alternating (G1, G2) and (-G1, G2) pairs should be true");
 }
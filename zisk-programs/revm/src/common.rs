@@ -1,5 +1,90 @@
+use std::sync::{Mutex, OnceLock};
+
 use serde::Deserialize;
 
+use crate::input::SuiteSelection;
+
+/// Input descriptor of the current run, set once by `main`
+static SELECTION: OnceLock<SuiteSelection> = OnceLock::new();
+
+/// Vectors skipped in this run for being above the hint budget of their suite
+static SKIPPED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub fn set_selection(selection: SuiteSelection) {
+    assert!(SELECTION.set(selection).is_ok(), "suite selection already set");
+}
+
+fn vector_selected(name: &str) -> bool {
+    SELECTION
+        .get()
+        .and_then(|s| s.vector_filter.as_ref())
+        .is_none_or(|filter| name.contains(filter.as_str()))
+}
+
+/// Applies the vector filter and the chunk of the input descriptor to the vectors of a fixture
+pub fn select_vectors<T>(tests: Vec<T>, name: impl Fn(&T) -> &str) -> Vec<T> {
+    let mut tests: Vec<T> = tests.into_iter().filter(|t| vector_selected(name(t))).collect();
+    let Some(chunk) = SELECTION.get().and_then(|s| s.chunk.as_ref()) else {
+        return tests;
+    };
+    assert!(chunk.index < chunk.count, "chunk {} out of {}", chunk.index, chunk.count);
+
+    let start = tests.len() * chunk.index / chunk.count;
+    let end = tests.len() * (chunk.index + 1) / chunk.count;
+    tests.truncate(end);
+    tests.drain(..start);
+    tests
+}
+
+/// Whether a vector with `size` elements (pairs, points, ...) can be run in this execution.
+///
+/// Always true without hints. With hints, vectors above the suite's per-call hint budget
+/// `limit` are skipped and recorded for `report_skipped`.
+pub fn fits_hint_budget(suite: &str, name: &str, size: usize, limit: usize) -> bool {
+    let with_hints = SELECTION.get().is_some_and(|s| s.hints_compatible_only);
+    if with_hints && size > limit {
+        println!("Skipping {suite} {name}: {size} elements exceed the hint budget of {limit}");
+        SKIPPED.lock().unwrap().push(format!("{suite} {name}: {size} > {limit}"));
+        return false;
+    }
+    true
+}
+
+/// Largest number of elements (points, ...) per call in this execution: the suite's per-call hint
+/// budget `limit` with hints, unbounded without. Suites whose calls can be split run a larger
+/// vector as several calls of at most this size instead of skipping it.
+pub fn hint_chunk_size(limit: usize) -> usize {
+    let with_hints = SELECTION.get().is_some_and(|s| s.hints_compatible_only);
+    if with_hints {
+        limit
+    } else {
+        usize::MAX
+    }
+}
+
+/// Lists the vectors skipped for the hint budget, so that a run with hints shows what it left out
+pub fn report_skipped() {
+    let skipped = SKIPPED.lock().unwrap();
+    if skipped.is_empty() {
+        return;
+    }
+    println!("{} vectors skipped above the hint budget of their suite:", skipped.len());
+    for vector in skipped.iter() {
+        println!("  - {vector}");
+    }
+}
+
+/// Whether the tests that are not fixture vectors run: only in the first chunk, so that a
+/// chunked run covers them once
+pub fn runs_fixed_tests() -> bool {
+    SELECTION.get().and_then(|s| s.chunk).is_none_or(|chunk| chunk.index == 0)
+}
+
+/// Pairs or points of the hint limit tests when this run probes the hint capacity
+pub fn hint_probe() -> Option<usize> {
+    SELECTION.get().and_then(|s| s.hint_probe)
+}
+
 #[derive(Clone)]
 pub enum ExpectedOutcome {
     Success(Vec<u8>),
@@ -43,7 +128,7 @@ struct FailJsonTest {
 
 pub fn parse_precompile_json(json_content: &str) -> Vec<PrecompileTestCase> {
     let tests: Vec<SuccessJsonTest> = serde_json::from_str(json_content).expect("valid JSON");
-    tests
+    select_vectors(tests, |t| t.name.as_str())
         .into_iter()
         .map(|t| PrecompileTestCase {
             name: t.name,
@@ -55,7 +140,7 @@ pub fn parse_precompile_json(json_content: &str) -> Vec<PrecompileTestCase> {
 
 pub fn parse_precompile_fail_json(json_content: &str) -> Vec<PrecompileTestCase> {
     let tests: Vec<FailJsonTest> = serde_json::from_str(json_content).expect("valid JSON");
-    tests
+    select_vectors(tests, |t| t.name.as_str())
         .into_iter()
         .map(|t| PrecompileTestCase {
             name: t.name,
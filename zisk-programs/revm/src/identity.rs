@@ -0,0 +1,104 @@
+use revm::precompile::identity::identity_run;
+
+use crate::common::parse_precompile_json;
+use crate::profile::profile_call;
+
+/// Base and per-word gas cost of the identity precompile
+const IDENTITY_BASE: u64 = 15;
+const IDENTITY_PER_WORD: u64 = 3;
+
+fn identity_gas(len: usize) -> u64 {
+    IDENTITY_BASE + IDENTITY_PER_WORD * len.div_ceil(32) as u64
+}
+
+/// Input used by the length sweep: byte `i` of an `n`-byte input is `(i * 7 + n) mod 256`
+fn sweep_input(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 + len) as u8).collect()
+}
+
+pub fn identity_tests() {
+    identity_json_tests();
+    identity_out_of_gas_tests();
+    identity_length_tests();
+    println!("All Identity tests passed!");
+}
+
+fn identity_json_tests() {
+    let tests = parse_precompile_json(include_str!("testdata/precompiles/identity.json"));
+    for test in &tests {
+        let result = profile_call!(
+            identity,
+            "identity_json_tests",
+            &test.name,
+            identity_run(&test.input, u64::MAX)
+        )
+        .unwrap_or_else(|e| panic!("identity {} should succeed: {e:?}", test.name));
+        assert_eq!(
+            result.bytes.as_ref(),
+            test.expected.unwrap_success().as_slice(),
+            "identity {} mismatch",
+            test.name
+        );
+        assert_eq!(
+            result.gas_used,
+            identity_gas(test.input.len()),
+            "identity {} gas mismatch",
+            test.name
+        );
+    }
+}
+
+fn identity_out_of_gas_tests() {
+    // Gas limit one below the cost must fail, exactly the cost must succeed
+    for len in [0usize, 1, 32, 33, 1024] {
+        let input = sweep_input(len);
+        let gas = identity_gas(len);
+        assert!(
+            profile_call!(
+                identity,
+                "identity_out_of_gas_tests",
+                format!("identity of {len} bytes with gas limit {gas}"),
+                identity_run(&input, gas - 1)
+            )
+            .is_err(),
+            "identity of {len} bytes with gas limit {} should fail",
+            gas - 1
+        );
+        assert!(
+            profile_call!(
+                identity,
+                "identity_out_of_gas_tests",
+                format!("identity of {len} bytes with gas limit {gas}"),
+                identity_run(&input, gas)
+            )
+            .is_ok(),
+            "identity of {len} bytes with gas limit {gas} should succeed"
+        );
+    }
+}
+
+fn identity_length_tests() {
+    // Sweep every input length in 0..=1024 and every source offset in 0..8, so that the copy
+    // goes through both the aligned and the unaligned memcpy paths
+    for len in 0..=1024 {
+        let input = sweep_input(len);
+        for offset in 0..8 {
+            let mut buf = vec![0u8; len + offset];
+            buf[offset..].copy_from_slice(&input);
+
+            let result = profile_call!(
+                identity,
+                "identity_length_tests",
+                format!("identity of {len} bytes from offset {offset}"),
+                identity_run(&buf[offset..], u64::MAX)
+            )
+            .unwrap_or_else(|e| panic!("identity of {len} bytes should succeed: {e:?}"));
+            assert_eq!(
+                result.bytes.as_ref(),
+                input.as_slice(),
+                "identity of {len} bytes from offset {offset} mismatch"
+            );
+            assert_eq!(result.gas_used, identity_gas(len), "identity of {len} bytes gas mismatch");
+        }
+    }
+}
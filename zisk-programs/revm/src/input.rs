@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Input descriptor read by the guest to select which suites and vectors to run.
+///
+/// This file is also included by `build.rs` to generate the files in `inputs/`.
+#[derive(Serialize, Deserialize, Default)]
+pub struct SuiteSelection {
+    /// Names of the suites to run, in execution order; empty runs every suite
+    pub suites: Vec<String>,
+    /// Only run the fixture vectors whose name contains this string
+    pub vector_filter: Option<String>,
+    /// Run with hints: skip the suites that do not support them, split the MSMs above the
+    /// per-call hint budget and skip the pairing checks above it
+    pub hints_compatible_only: bool,
+    /// Only run this slice of each fixture file, so large fixtures can be split across runs
+    pub chunk: Option<Chunk>,
+    /// Only run the hint limit test of the selected bounded suites, with this many pairs or
+    /// points, to measure the largest call that works with hints
+    pub hint_probe: Option<usize>,
+}
+
+/// Contiguous slice `index` out of `count` equal slices of a fixture file
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct Chunk {
+    pub index: usize,
+    pub count: usize,
+}
@@ -0,0 +1,49 @@
+//! Profiling helpers enabled by the `profile` feature.
+//!
+//! `profile_block!` wraps a block with the `ziskos` profiling report macros under a static tag.
+//! `profile_call!` does the same for a single precompile call and, right before it, prints a
+//! marker line keying the call by suite and vector name:
+//!
+//! ```text
+//! PROFILE tag=<tag> suite=<suite> vector=<vector name>
+//! ```
+//!
+//! `tools/profile-report` pairs each marker with the emulator's report for the same tag to build
+//! a per-call table of steps. Without the feature both macros expand to the wrapped code only.
+
+#[cfg(feature = "profile")]
+macro_rules! profile_block {
+    ($tag:ident, $body:block) => {{
+        ziskos::profile_report_start!($tag);
+        ziskos::profile_report_steps_start!($tag);
+        let __profile_result = $body;
+        ziskos::profile_report_steps_end!($tag);
+        ziskos::profile_report_end!($tag);
+        __profile_result
+    }};
+}
+
+#[cfg(not(feature = "profile"))]
+macro_rules! profile_block {
+    ($tag:ident, $body:block) => {
+        $body
+    };
+}
+
+#[cfg(feature = "profile")]
+macro_rules! profile_call {
+    ($tag:ident, $suite:expr, $vector:expr, $call:expr) => {{
+        println!("PROFILE tag={} suite={} vector={}", stringify!($tag), $suite, $vector);
+        $crate::profile::profile_block!($tag, { $call })
+    }};
+}
+
+#[cfg(not(feature = "profile"))]
+macro_rules! profile_call {
+    ($tag:ident, $suite:expr, $vector:expr, $call:expr) => {
+        $call
+    };
+}
+
+pub(crate) use profile_block;
+pub(crate) use profile_call;
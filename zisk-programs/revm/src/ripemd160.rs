@@ -0,0 +1,101 @@
+use guest_reth::CustomEvmCrypto;
+use revm::precompile::Crypto;
+
+use crate::common::parse_precompile_json;
+use crate::profile::profile_call;
+
+/// Helper to convert a 20-byte hex digest to the 32-byte left-padded precompile output
+fn hex_to_padded_hash(hex: &str) -> [u8; 32] {
+    let hex = hex.trim_start_matches("0x");
+    let bytes = hex::decode(hex).expect("valid hex");
+    let mut arr = [0u8; 32];
+    arr[12..].copy_from_slice(&bytes);
+    arr
+}
+
+/// Input used by the length sweep: byte `i` of an `n`-byte input is `i mod 256`
+fn sweep_input(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+pub fn ripemd160_tests(crypto: &CustomEvmCrypto) {
+    ripemd160_json_tests(crypto);
+    ripemd160_unaligned_tests(crypto);
+    ripemd160_length_tests(crypto);
+    println!("All RIPEMD-160 tests passed!");
+}
+
+fn ripemd160_json_tests(crypto: &CustomEvmCrypto) {
+    let tests = parse_precompile_json(include_str!("testdata/precompiles/ripemd160.json"));
+    for test in &tests {
+        let result = profile_call!(
+            ripemd160,
+            "ripemd160_json_tests",
+            &test.name,
+            crypto.ripemd160(&test.input)
+        );
+        assert_eq!(
+            result.as_slice(),
+            test.expected.unwrap_success().as_slice(),
+            "ripemd160 {} mismatch",
+            test.name
+        );
+    }
+}
+
+fn ripemd160_unaligned_tests(crypto: &CustomEvmCrypto) {
+    // "abc" from various alignment offsets
+    // RIPEMD160("abc") = 8eb208f7e05d987a9b044a8e98c6b087f15a0bfc
+    for offset in [1usize, 2, 3, 5, 7] {
+        let mut buf = vec![0u8; 64 + offset];
+        buf[offset..offset + 3].copy_from_slice(b"abc");
+
+        let slice = &buf[offset..offset + 3];
+        let result = profile_call!(
+            ripemd160,
+            "ripemd160_unaligned_tests",
+            format!("ripemd160('abc') from offset {offset}"),
+            crypto.ripemd160(slice)
+        );
+        assert_eq!(
+            result,
+            hex_to_padded_hash("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+            "ripemd160('abc') from offset {offset}"
+        );
+    }
+}
+
+fn ripemd160_length_tests(crypto: &CustomEvmCrypto) {
+    // Sweep every input length in 0..=1024, hashing each input both from an aligned buffer and
+    // from every offset in 1..8, and check against the digests in `ripemd160_lengths.json`
+    // (entry `n` is the digest of `sweep_input(n)`)
+    let expected: Vec<String> =
+        serde_json::from_str(include_str!("testdata/precompiles/ripemd160_lengths.json"))
+            .expect("valid JSON");
+    assert_eq!(expected.len(), 1025, "ripemd160 length sweep should cover 0..=1024");
+
+    for (len, expected_hex) in expected.iter().enumerate() {
+        let expected = hex_to_padded_hash(expected_hex);
+        let input = sweep_input(len);
+
+        let result = profile_call!(
+            ripemd160,
+            "ripemd160_length_tests",
+            format!("ripemd160 of {len} bytes"),
+            crypto.ripemd160(&input)
+        );
+        assert_eq!(result, expected, "ripemd160 of {len} bytes mismatch");
+
+        for offset in 1..8 {
+            let mut buf = vec![0u8; len + offset];
+            buf[offset..].copy_from_slice(&input);
+            let result = profile_call!(
+                ripemd160,
+                "ripemd160_length_tests",
+                format!("ripemd160 of {len} bytes from offset {offset}"),
+                crypto.ripemd160(&buf[offset..])
+            );
+            assert_eq!(result, expected, "ripemd160 of {len} bytes from offset {offset} mismatch");
+        }
+    }
+}
@@ -5,6 +5,7 @@ use guest_reth::CustomEvmCrypto;
 use revm::precompile::Crypto;
 
 use crate::common::{parse_precompile_json, ExpectedOutcome, PrecompileTestCase};
+use crate::profile::profile_call;
 
 struct EcRecoverTestCase {
     name: String,
@@ -98,7 +99,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("BC44674AD5868F642EAD3FDF94E2D9C9185EAFB7");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #100a",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #100a failed");
 
     let hash = hex_to_32("d9eba16ed0ecae432b71fe008c98cc872bb4cc214d3220a36f365326cf807d68");
@@ -108,7 +114,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("EE3FEFB38D4E5C7337818F635DEE7609F67CFDB8");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #100b",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #100b failed");
 
     // #100 first valid ecrecover_tx
@@ -119,7 +130,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("14791697260e4c9a71f18484c9f997b308e59325");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #100c",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #100c failed");
 
     // #0 valid ecrecover_tx
@@ -130,7 +146,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("bec80D04A24CD4D811876fF40F31260C339d63C2");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #0",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #0 failed");
 
     // #1 valid ecrecover_tx
@@ -141,7 +162,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("d571a2180a8647e77adfc109C49bd2137c7a71b5");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #1",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #1 failed");
 
     // #2 valid ecrecover_tx
@@ -152,7 +178,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("52855436E41c2671759d54103e71A3d5Fe27439C");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #2",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #2 failed");
 
     // #3 valid ecrecover_tx
@@ -163,7 +194,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("A4E24c3ea459D50409dd31Cf1C35A222ADA889fa");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #3",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #3 failed");
 
     // #4 valid ecrecover_tx
@@ -174,7 +210,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("2302Fdd6dB52D32b84cc7648Fb9d8978a005E382");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #4",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #4 failed");
 
     // #5 valid ecrecover_tx
@@ -185,7 +226,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("Cc665b2CaA43684c99f0EB59751DC1a8C4dBd9Ee");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #5",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #5 failed");
 
     // #6 valid ecrecover_tx
@@ -196,7 +242,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("1F34358a423C2FED839090Dd943A728Dbd711e62");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #6",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #6 failed");
 
     // #7 valid ecrecover_tx
@@ -207,7 +258,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("0C470e0895Bbe22A5cFeD06002e29e8ADCEB59E1");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #7",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #7 failed");
 
     // #8 valid ecrecover_tx
@@ -218,7 +274,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("6777D222b69b69F41ee3DBFCf6baee332999f347");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #8",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #8 failed");
 
     // #9 valid ecrecover_tx
@@ -229,7 +290,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("1f56A9F3Ca1F284978478D9363D2b345B23B198B");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #9",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #9 failed");
 
     // #10 valid ecrecover_tx
@@ -240,7 +306,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("6a7C3804E52D9B089767eA5D26232F9096F7B50e");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #10",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #10 failed");
 
     // #11 valid ecrecover_tx
@@ -251,7 +322,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("dD6F178Fa6ed5859154301C686C3cd12192CcC0A");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #11",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #11 failed");
 
     // #12 valid ecrecover_tx
@@ -262,7 +338,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("5fFd05d8565FA51BB1aE1E4c7b980e1C91d2c939");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #12",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #12 failed");
 
     // #13 valid ecrecover_tx
@@ -273,7 +354,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("F329e07AFade0Fb45a599993dF4313DFaB5f80A6");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #13",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #13 failed");
 
     // #14 valid ecrecover_tx
@@ -284,7 +370,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("411559B990Fd50F1DF20B4D3780C4185d113A9Ee");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #14",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #14 failed");
 
     // #15 valid ecrecover_tx
@@ -295,7 +386,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("B2E1a1FbfdAE0743f4539166309B72BB737716b1");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #15",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #15 failed");
 
     // #16 valid ecrecover_tx
@@ -306,7 +402,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("95DdaFE17e5550FF8ac7b2EF9D365d067aa7Ae91");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #16",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #16 failed");
 
     // #17 valid ecrecover_tx
@@ -317,7 +418,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("9D39Cfa3F2e98CA109c45F28735436798CBBDEF8");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #17",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #17 failed");
 
     // #18 valid ecrecover_tx
@@ -328,7 +434,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("4AB0c3d24dfF7E98e16Edd61269997D089E97c9f");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #18",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #18 failed");
 
     // #19 valid ecrecover_tx
@@ -339,7 +450,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("d02c6aAB18d3f40AA994A5B7F3c2be14B34EAB6e");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #19",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #19 failed");
 
     // #20 mess == change 1 bit
@@ -350,7 +466,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("34E325D8023eb901c39747338C587b098fB75dF4");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #20",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #20 failed");
 
     // #21 mess == 0
@@ -361,7 +482,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("2a558C4cD662E0b74E289d746AEA2f8cf8e54f7c");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #21",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #21 failed");
 
     // #22 mess == field (N)
@@ -372,7 +498,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("2a558C4cD662E0b74E289d746AEA2f8cf8e54f7c");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #22",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #22 failed");
 
     // #23 mess == field - 1
@@ -383,7 +514,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("c41ABa9e06fac6976618820d04D247FfD38f62FF");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #23",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #23 failed");
 
     // #24 mess == field + 1
@@ -394,7 +530,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("7a343F50dd8fAFa76406F6Ee4dA1796FF9A06109");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #24",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #24 failed");
 
     // #25 mess == 1
@@ -405,7 +546,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("7a343F50dd8fAFa76406F6Ee4dA1796FF9A06109");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #25",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #25 failed");
 
     // #26 flip v: 28 --> 27. Valid ecrecover_tx
@@ -416,7 +562,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("E077fd3C958303e36309B9EE20AE9D3D59817232");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #26",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #26 failed");
 
     ////////
@@ -429,7 +580,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1a;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #27",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #27 should fail (v < 27)");
 
     // #28 v > 28 - should fail
@@ -439,7 +595,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1d;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #28",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #28 should fail (v > 28)");
 
     // #29 r == 0 - should fail
@@ -449,7 +610,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1c;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #29",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #29 should fail (r == 0)");
 
     // #30 r == field (N) - should fail
@@ -459,7 +625,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1c;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #30",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #30 should fail (r == N)");
 
     // #31 r > field - should fail
@@ -469,7 +640,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1c;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #31",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #31 should fail (r > N)");
 
     // #32 r = field - 1 - should fail (r must be < N)
@@ -479,7 +655,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1c;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #32",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #32 should fail (r == N-1, recovery fails)");
 
     // #33 s == 0 - should fail
@@ -489,7 +670,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1c;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #33",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #33 should fail (s == 0)");
 
     // #35 s == field/2 - Valid for tx
@@ -500,7 +686,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("B29F65aA401660dfa96ecD7eB28134d87E9a618D");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #35",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #35 failed");
 
     // #37 s == field (N) - should fail
@@ -510,7 +701,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1c;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #37",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #37 should fail (s == N)");
 
     // #39 s == field + 1 - should fail
@@ -520,7 +716,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let v = 0x1c;
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #39",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert!(result.is_err(), "Test #39 should fail (s > N)");
 
     /////////
@@ -534,7 +735,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("3c8b5f2426549658f425fad7e061c500626b39a3");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #40",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #40 failed");
 
     // #41 -ECGX
@@ -545,7 +751,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("687526ad233c19b0a9b2eb8c96d1a45603f3919e");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Test #41",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Test #41 failed");
 
     // Additional edge case with 2G point
@@ -556,7 +767,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("89fea198bd4a0efb63f36b5a04c993cca3a1891d");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Edge case 2G v=27",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Edge case 2G v=27 failed");
 
     let hash = hex_to_32("3cc4cb050478c49877188e4fbd022f35ccb41cee02d9d4417194cbf7ebc1eec0");
@@ -566,7 +782,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("c613182e8da092201765f852e3d6fbec4d281432");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Edge case 2G v=28",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Edge case 2G v=28 failed");
 
     // p: (0xc6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5,0x1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a)
@@ -577,7 +798,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("1772bf1cfa310fdca361ee65825a74013eeaa17e");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Edge case 2G hash+1",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Edge case 2G hash+1 failed");
 
     // Masked ECGX tests
@@ -589,7 +815,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("9446d37b3aaedc97b2a8a9437998ba5787a2d5cb");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Masked ECGX 0xFFFF test",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Masked ECGX 0xFFFF test failed");
 
     // P2_CH_EGX = ECGX & 0x001FFFF...FFFF (248 bits)
@@ -600,7 +831,12 @@ pub fn ecrecover_tx_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("4c90563674ab8de6f7731475a01a2bd09fd7b4b1");
 
     let sig = build_sig_65(r, s, v);
-    let result = crypto.recover_signer_unchecked(&sig, &hash);
+    let result = profile_call!(
+        ecrecover_tx,
+        "ecrecover_tx_tests",
+        "Masked ECGX 248-bit test",
+        crypto.recover_signer_unchecked(&sig, &hash)
+    );
     assert_eq!(result.unwrap(), expected, "Masked ECGX 248-bit test failed");
 
     println!("All EcRecover Tx tests passed!");
@@ -616,7 +852,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("4ef445CADd8bEe8A02bc79b30A97e6Fe3AE3B7a3");
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Test #34",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     let output = result.expect("Test #34 precompile should succeed");
     let recovered_address = Address::from_slice(&output[12..]);
     assert_eq!(recovered_address, expected, "Test #34 precompile failed");
@@ -630,7 +871,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("fE706AA7fe3455F29e0F5553D9C780Be3Bd54564");
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Test #36",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     let output = result.expect("Test #36 precompile should succeed");
     let recovered_address = Address::from_slice(&output[12..]);
     assert_eq!(recovered_address, expected, "Test #36 precompile failed");
@@ -644,7 +890,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("c846e2E4Ab85A761042265B9A8d995345432A60e");
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Test #38",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     let output = result.expect("Test #38 precompile should succeed");
     let recovered_address = Address::from_slice(&output[12..]);
     assert_eq!(recovered_address, expected, "Test #38 precompile failed");
@@ -657,7 +908,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let recid = v_to_recid(v);
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Point at infinity test",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     assert!(result.is_err(), "Point at infinity test should fail");
 
     // Additional precompile tests with valid low S (should also work)
@@ -669,7 +925,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let expected = hex_to_address("BC44674AD5868F642EAD3FDF94E2D9C9185EAFB7");
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Precompile with low S",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     let output = result.expect("Precompile with low S should succeed");
     let recovered_address = Address::from_slice(&output[12..]);
     assert_eq!(recovered_address, expected, "Precompile with low S failed");
@@ -682,7 +943,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let recid = v_to_recid(v);
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Precompile r==0",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     assert!(result.is_err(), "Precompile r==0 should fail");
 
     // Precompile: s == 0 should fail
@@ -693,7 +959,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let recid = v_to_recid(v);
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Precompile s==0",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     assert!(result.is_err(), "Precompile s==0 should fail");
 
     // Precompile: r >= N should fail
@@ -704,7 +975,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let recid = v_to_recid(v);
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Precompile r>=N",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     assert!(result.is_err(), "Precompile r>=N should fail");
 
     // Precompile: s >= N should fail
@@ -715,7 +991,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
     let recid = v_to_recid(v);
 
     let sig = build_sig(r, s);
-    let result = crypto.secp256k1_ecrecover(&sig, recid, &hash);
+    let result = profile_call!(
+        ecrecover,
+        "ecrecover_precompile_tests",
+        "Precompile s>=N",
+        crypto.secp256k1_ecrecover(&sig, recid, &hash)
+    );
     assert!(result.is_err(), "Precompile s>=N should fail");
 
     // Geth test vectors
@@ -741,7 +1022,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
 
         match t.expected {
             Some(expected_padded) => {
-                let result = crypto.secp256k1_ecrecover(&sig, recid, &t.hash);
+                let result = profile_call!(
+                    ecrecover,
+                    "ecrecover_precompile_tests",
+                    &t.name,
+                    crypto.secp256k1_ecrecover(&sig, recid, &t.hash)
+                );
                 assert!(result.is_ok(), "ecRecover {} should succeed", t.name);
                 let output = result.unwrap();
                 assert_eq!(
@@ -755,7 +1041,12 @@ pub fn ecrecover_precompile_tests(crypto: &CustomEvmCrypto) {
             }
             None => {
                 // Expected to fail OR return empty
-                let result = crypto.secp256k1_ecrecover(&sig, recid, &t.hash);
+                let result = profile_call!(
+                    ecrecover,
+                    "ecrecover_precompile_tests",
+                    &t.name,
+                    crypto.secp256k1_ecrecover(&sig, recid, &t.hash)
+                );
                 assert!(
                     result.is_err(),
                     "ecRecover {} should fail but got 0x{}",
@@ -2,6 +2,7 @@ use guest_reth::CustomEvmCrypto;
 use revm::precompile::Crypto;
 
 use crate::common::{parse_precompile_json, ExpectedOutcome, PrecompileTestCase};
+use crate::profile::profile_call;
 
 struct P256VerifyTestCase {
     name: String,
@@ -35,7 +36,12 @@ pub fn p256_verify_tests(crypto: &CustomEvmCrypto) {
     let tests = parse_precompile_json(include_str!("testdata/precompiles/p256Verify.json"));
     for test in &tests {
         let t = parse_p256_verify_test(test);
-        let result = crypto.secp256r1_verify_signature(&t.msg, &t.sig, &t.pk);
+        let result = profile_call!(
+            p256_verify,
+            "p256_verify_tests",
+            &t.name,
+            crypto.secp256r1_verify_signature(&t.msg, &t.sig, &t.pk)
+        );
         assert_eq!(
             result, t.expected,
             "p256Verify {} mismatch: got {}, expected {}",
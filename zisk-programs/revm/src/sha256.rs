@@ -1,6 +1,8 @@
 use guest_reth::CustomEvmCrypto;
 use revm::precompile::Crypto;
 
+use crate::profile::profile_call;
+
 /// Helper to convert hex string to fixed 32-byte array
 fn hex_to_hash(hex: &str) -> [u8; 32] {
     let hex = hex.trim_start_matches("0x");
@@ -21,7 +23,7 @@ pub fn sha256_tests(crypto: &CustomEvmCrypto) {
 fn sha256_basic_tests(crypto: &CustomEvmCrypto) {
     // Single character
     // SHA256("a") = ca978112ca1bbdcafac231b39a23dc4da786eff8147c4e72b9807785afee48bb
-    let result = crypto.sha256(b"a");
+    let result = profile_call!(sha256, "sha256_basic_tests", "sha256('a')", crypto.sha256(b"a"));
     assert_eq!(
         result,
         hex_to_hash("ca978112ca1bbdcafac231b39a23dc4da786eff8147c4e72b9807785afee48bb"),
@@ -30,7 +32,8 @@ fn sha256_basic_tests(crypto: &CustomEvmCrypto) {
 
     // "abc" - NIST test vector
     // SHA256("abc") = ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad
-    let result = crypto.sha256(b"abc");
+    let result =
+        profile_call!(sha256, "sha256_basic_tests", "sha256('abc')", crypto.sha256(b"abc"));
     assert_eq!(
         result,
         hex_to_hash("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
@@ -39,7 +42,12 @@ fn sha256_basic_tests(crypto: &CustomEvmCrypto) {
 
     // "hello world"
     // SHA256("hello world") = b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9
-    let result = crypto.sha256(b"hello world");
+    let result = profile_call!(
+        sha256,
+        "sha256_basic_tests",
+        "sha256('hello world')",
+        crypto.sha256(b"hello world")
+    );
     assert_eq!(
         result,
         hex_to_hash("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"),
@@ -48,7 +56,12 @@ fn sha256_basic_tests(crypto: &CustomEvmCrypto) {
 
     // Longer string
     // SHA256("The quick brown fox jumps over the lazy dog")
-    let result = crypto.sha256(b"The quick brown fox jumps over the lazy dog");
+    let result = profile_call!(
+        sha256,
+        "sha256_basic_tests",
+        "sha256('The quick brown fox...')",
+        crypto.sha256(b"The quick brown fox jumps over the lazy dog")
+    );
     assert_eq!(
         result,
         hex_to_hash("d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"),
@@ -57,7 +70,12 @@ fn sha256_basic_tests(crypto: &CustomEvmCrypto) {
 
     // With period at end (different hash!)
     // SHA256("The quick brown fox jumps over the lazy dog.")
-    let result = crypto.sha256(b"The quick brown fox jumps over the lazy dog.");
+    let result = profile_call!(
+        sha256,
+        "sha256_basic_tests",
+        "sha256('The quick brown fox....')",
+        crypto.sha256(b"The quick brown fox jumps over the lazy dog.")
+    );
     assert_eq!(
         result,
         hex_to_hash("ef537f25c895bfa782526529a9b63d97aa631564d5d789c2b765448c8635fb6c"),
@@ -65,7 +83,12 @@ fn sha256_basic_tests(crypto: &CustomEvmCrypto) {
     );
 
     // With numbers
-    let result = crypto.sha256(b"hello world 1234");
+    let result = profile_call!(
+        sha256,
+        "sha256_basic_tests",
+        "sha256('hello world 1234')",
+        crypto.sha256(b"hello world 1234")
+    );
     assert_eq!(
         result,
         hex_to_hash("87472796b6bd3ab3651bd9a5f7306d84397eb2c6cf99e477fa9c37e9d7c6d6bb"),
@@ -77,7 +100,8 @@ fn sha256_nist_tests(crypto: &CustomEvmCrypto) {
     // Official NIST test vectors from FIPS 180-4
 
     // NIST Short Message Test: "abc"
-    let result = crypto.sha256(b"abc");
+    let result =
+        profile_call!(sha256, "sha256_nist_tests", "NIST: sha256('abc')", crypto.sha256(b"abc"));
     assert_eq!(
         result,
         hex_to_hash("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
@@ -85,7 +109,7 @@ fn sha256_nist_tests(crypto: &CustomEvmCrypto) {
     );
 
     // NIST Short Message Test: "" (empty)
-    let result = crypto.sha256(b"");
+    let result = profile_call!(sha256, "sha256_nist_tests", "NIST: sha256('')", crypto.sha256(b""));
     assert_eq!(
         result,
         hex_to_hash("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
@@ -93,7 +117,12 @@ fn sha256_nist_tests(crypto: &CustomEvmCrypto) {
     );
 
     // NIST Long Message Test: "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq" (448 bits)
-    let result = crypto.sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq");
+    let result = profile_call!(
+        sha256,
+        "sha256_nist_tests",
+        "NIST: sha256('abcdbcdecdefdefg...')",
+        crypto.sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+    );
     assert_eq!(
         result,
         hex_to_hash("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"),
@@ -101,7 +130,7 @@ fn sha256_nist_tests(crypto: &CustomEvmCrypto) {
     );
 
     // NIST Long Message Test: "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu" (896 bits)
-    let result = crypto.sha256(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu");
+    let result = profile_call!(sha256, "sha256_nist_tests", "NIST: sha256('abcdefghbcdefghi...')", crypto.sha256(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu"));
     assert_eq!(
         result,
         hex_to_hash("cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1"),
@@ -114,7 +143,8 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
     // SHA256 uses 64-byte (512-bit) blocks
 
     // Exactly 1 byte
-    let result = crypto.sha256(&[0x00]);
+    let result =
+        profile_call!(sha256, "sha256_length_tests", "sha256(0x00)", crypto.sha256(&[0x00]));
     assert_eq!(
         result,
         hex_to_hash("6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d"),
@@ -123,7 +153,8 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 31 bytes (just under 32)
     let input: Vec<u8> = (0..31).collect();
-    let result = crypto.sha256(&input);
+    let result =
+        profile_call!(sha256, "sha256_length_tests", "sha256(0x00..0x1e)", crypto.sha256(&input));
     assert_eq!(
         result,
         hex_to_hash("4f23c2ca8c5c962e50cd31e221bfb6d0adca19111dca8e0c62598ff146dd19c4"),
@@ -132,7 +163,8 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 32 bytes (common hash size)
     let input: Vec<u8> = (0..32).collect();
-    let result = crypto.sha256(&input);
+    let result =
+        profile_call!(sha256, "sha256_length_tests", "sha256(0x00..0x1f)", crypto.sha256(&input));
     assert_eq!(
         result,
         hex_to_hash("630dcd2966c4336691125448bbb25b4ff412a49c732db2c8abc1b8581bd710dd"),
@@ -141,7 +173,12 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 55 bytes (max message length that fits in one block with padding)
     let input: Vec<u8> = vec![0xAB; 55];
-    let result = crypto.sha256(&input);
+    let result = profile_call!(
+        sha256,
+        "sha256_length_tests",
+        "sha256(55 bytes of 0xAB)",
+        crypto.sha256(&input)
+    );
     assert_eq!(
         result,
         hex_to_hash("48d76eab30e51201f4f03ec7a85dab8510fb3409ccd15b54767f9b4435c9f54d"),
@@ -150,7 +187,12 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 56 bytes (requires two blocks due to padding)
     let input: Vec<u8> = vec![0xAB; 56];
-    let result = crypto.sha256(&input);
+    let result = profile_call!(
+        sha256,
+        "sha256_length_tests",
+        "sha256(56 bytes of 0xAB)",
+        crypto.sha256(&input)
+    );
     assert_eq!(
         result,
         hex_to_hash("a8c9906ade2a2eff868fd8f97a570bbc01a13cddc32c3dfdc9a18f0618d69e55"),
@@ -159,7 +201,12 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 63 bytes (one less than block size)
     let input: Vec<u8> = vec![0xAB; 63];
-    let result = crypto.sha256(&input);
+    let result = profile_call!(
+        sha256,
+        "sha256_length_tests",
+        "sha256(63 bytes of 0xAB)",
+        crypto.sha256(&input)
+    );
     assert_eq!(
         result,
         hex_to_hash("d1036ba30d050c74b1a5ab301fa29ff0c607a27cc55af3412577f7e06dbd190b"),
@@ -168,7 +215,12 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 64 bytes (exactly one block)
     let input: Vec<u8> = vec![0xAB; 64];
-    let result = crypto.sha256(&input);
+    let result = profile_call!(
+        sha256,
+        "sha256_length_tests",
+        "sha256(64 bytes of 0xAB)",
+        crypto.sha256(&input)
+    );
     assert_eq!(
         result,
         hex_to_hash("ec65c8798ecf95902413c40f7b9e6d4b0068885f5f324aba1f9ba1c8e14aea61"),
@@ -177,7 +229,12 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 65 bytes (one more than block size)
     let input: Vec<u8> = vec![0xAB; 65];
-    let result = crypto.sha256(&input);
+    let result = profile_call!(
+        sha256,
+        "sha256_length_tests",
+        "sha256(65 bytes of 0xAB)",
+        crypto.sha256(&input)
+    );
     assert_eq!(
         result,
         hex_to_hash("39cd843414d5125dd308568ace26d04e60b7fa6d2b1a901fb5184fa2eae0598b"),
@@ -186,7 +243,12 @@ fn sha256_length_tests(crypto: &CustomEvmCrypto) {
 
     // 128 bytes (exactly two blocks)
     let input: Vec<u8> = vec![0xCD; 128];
-    let result = crypto.sha256(&input);
+    let result = profile_call!(
+        sha256,
+        "sha256_length_tests",
+        "sha256(128 bytes of 0xCD)",
+        crypto.sha256(&input)
+    );
     assert_eq!(
         result,
         hex_to_hash("885ef0783ff857466de0f1464fa28cf1fd9a9abaed41a330068966c6e3525505"),
@@ -206,7 +268,12 @@ fn sha256_unaligned_tests(crypto: &CustomEvmCrypto) {
     ];
 
     let unaligned_slice = &aligned_buffer[1..4];
-    let result = crypto.sha256(unaligned_slice);
+    let result = profile_call!(
+        sha256,
+        "sha256_unaligned_tests",
+        "sha256('abc') from unaligned offset 1",
+        crypto.sha256(unaligned_slice)
+    );
     assert_eq!(
         result,
         hex_to_hash("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
@@ -222,7 +289,12 @@ fn sha256_unaligned_tests(crypto: &CustomEvmCrypto) {
     buffer[5] = b'o';
 
     let unaligned_hello = &buffer[1..6];
-    let result = crypto.sha256(unaligned_hello);
+    let result = profile_call!(
+        sha256,
+        "sha256_unaligned_tests",
+        "sha256('hello') from unaligned offset",
+        crypto.sha256(unaligned_hello)
+    );
     assert_eq!(
         result,
         hex_to_hash("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"),
@@ -238,7 +310,12 @@ fn sha256_unaligned_tests(crypto: &CustomEvmCrypto) {
         buf[offset + 2] = b'c';
 
         let slice = &buf[offset..offset + 3];
-        let result = crypto.sha256(slice);
+        let result = profile_call!(
+            sha256,
+            "sha256_unaligned_tests",
+            format!("sha256('abc') from offset {offset}"),
+            crypto.sha256(slice)
+        );
         assert_eq!(
             result,
             hex_to_hash("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
@@ -254,7 +331,12 @@ fn sha256_unaligned_tests(crypto: &CustomEvmCrypto) {
 
     // Hash 64 bytes from unaligned offset 3
     let unaligned_64 = &large_buf[3..67];
-    let result = crypto.sha256(unaligned_64);
+    let result = profile_call!(
+        sha256,
+        "sha256_unaligned_tests",
+        "sha256(0..64 bytes) from unaligned offset 3",
+        crypto.sha256(unaligned_64)
+    );
     // This should match sha256(0x00, 0x01, 0x02, ..., 0x3f)
     assert_eq!(
         result,
@@ -0,0 +1,197 @@
+use alloy_consensus::crypto::CryptoProvider;
+use alloy_consensus::{Transaction, TxEnvelope};
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::{Address, U256};
+use serde::Deserialize;
+
+use guest_reth::CustomEvmCrypto;
+
+use crate::common::select_vectors;
+use crate::profile::profile_call;
+
+/// Half the secp256k1 group order; signatures with a larger s are malleable (EIP-2)
+const SECP256K1N_HALF: U256 = U256::from_limbs([
+    0xdfe92f46681b20a0,
+    0x5d576e7357a4501d,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+]);
+
+/// Why a fixture transaction must be rejected
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+enum Rejection {
+    /// The envelope does not decode (e.g. invalid v or y-parity)
+    Decode,
+    /// The envelope decodes but the sender cannot be recovered (e.g. zero r)
+    Recover,
+    /// The sender is recoverable but s > n/2, so the checked path must reject it (EIP-2)
+    HighS,
+}
+
+#[derive(Deserialize)]
+struct SignedTxTest {
+    #[serde(rename = "Name")]
+    name: String,
+    /// EIP-2718 encoding of the signed transaction
+    #[serde(rename = "Rlp")]
+    rlp: String,
+    /// Address recovered without the low-s check, `null` when recovery itself must fail
+    #[serde(rename = "Sender")]
+    sender: Option<String>,
+    #[serde(rename = "ExpectedError")]
+    expected_error: Option<Rejection>,
+    /// Authority of each EIP-7702 authorization, `null` when its recovery must fail
+    #[serde(rename = "Authorities", default)]
+    authorities: Vec<Option<String>>,
+    /// Indices of the authorizations whose authority is only recoverable without the low-s check
+    #[serde(rename = "HighSAuthorities", default)]
+    high_s_authorities: Vec<usize>,
+}
+
+/// Helper to build 65-byte signature (r || s || y_parity) for recover_signer_unchecked
+fn build_sig_65(r: U256, s: U256, y_parity: u8) -> [u8; 65] {
+    let mut sig = [0u8; 65];
+    sig[..32].copy_from_slice(&r.to_be_bytes::<32>());
+    sig[32..64].copy_from_slice(&s.to_be_bytes::<32>());
+    sig[64] = y_parity;
+    sig
+}
+
+fn parse_address(hex: &str) -> Address {
+    hex.parse().expect("valid address")
+}
+
+/// Checked recovery as done for senders and EIP-7702 authorities: the unchecked result,
+/// rejected when s is in the upper half of the group order
+fn recover_checked(unchecked: Option<Address>, s: U256) -> Option<Address> {
+    unchecked.filter(|_| s <= SECP256K1N_HALF)
+}
+
+// ============================================================
+// Signed transaction tests: decode real EIP-2718 envelopes and
+// recover their senders and EIP-7702 authorities
+// ============================================================
+pub fn signed_tx_tests(crypto: &CustomEvmCrypto) {
+    let tests: Vec<SignedTxTest> =
+        serde_json::from_str(include_str!("testdata/signed_txs.json")).expect("valid JSON");
+
+    for test in select_vectors(tests, |t| t.name.as_str()) {
+        let rlp = hex::decode(&test.rlp).expect("valid hex");
+        let tx = match TxEnvelope::decode_2718(&mut rlp.as_slice()) {
+            Ok(tx) => tx,
+            Err(e) => {
+                assert_eq!(
+                    test.expected_error,
+                    Some(Rejection::Decode),
+                    "Tx {} should decode: {}",
+                    test.name,
+                    e
+                );
+                continue;
+            }
+        };
+        assert_ne!(
+            test.expected_error,
+            Some(Rejection::Decode),
+            "Tx {} should fail to decode",
+            test.name
+        );
+
+        // Sender
+        let signature = tx.signature();
+        let sig = build_sig_65(signature.r(), signature.s(), signature.v() as u8);
+        let hash = tx.signature_hash();
+        let result = profile_call!(
+            ecrecover_tx,
+            "signed_tx_tests",
+            &test.name,
+            crypto.recover_signer_unchecked(&sig, &hash.0)
+        );
+        let unchecked = result.ok();
+        let checked = recover_checked(unchecked, signature.s());
+        match test.expected_error {
+            Some(Rejection::Recover) => {
+                assert!(
+                    test.sender.is_none(),
+                    "Tx {} rejected on recovery has a sender",
+                    test.name
+                );
+                assert!(unchecked.is_none(), "Tx {} sender recovery should fail", test.name);
+            }
+            rejection => {
+                let sender = parse_address(test.sender.as_deref().expect("fixture sender"));
+                assert_eq!(
+                    unchecked,
+                    Some(sender),
+                    "Tx {} unchecked sender recovery mismatch",
+                    test.name
+                );
+                if rejection == Some(Rejection::HighS) {
+                    assert!(checked.is_none(), "Tx {} high-s sender should be rejected", test.name);
+                } else {
+                    assert_eq!(checked, Some(sender), "Tx {} sender mismatch", test.name);
+                }
+            }
+        }
+
+        // EIP-7702 authorities
+        let authorizations = tx.authorization_list().unwrap_or_default();
+        assert_eq!(
+            authorizations.len(),
+            test.authorities.len(),
+            "Tx {} authorization count mismatch",
+            test.name
+        );
+        for (i, (auth, authority)) in authorizations.iter().zip(&test.authorities).enumerate() {
+            let sig = build_sig_65(auth.r(), auth.s(), auth.y_parity());
+            let hash = auth.signature_hash();
+            let result = profile_call!(
+                ecrecover_tx,
+                "signed_tx_tests",
+                format!("{} auth {}", test.name, i),
+                crypto.recover_signer_unchecked(&sig, &hash.0)
+            );
+            let unchecked = result.ok();
+            let checked = recover_checked(unchecked, auth.s());
+            match authority {
+                Some(authority) => {
+                    let authority = parse_address(authority);
+                    assert_eq!(
+                        unchecked,
+                        Some(authority),
+                        "Tx {} authority {} unchecked recovery mismatch",
+                        test.name,
+                        i
+                    );
+                    if test.high_s_authorities.contains(&i) {
+                        assert!(
+                            checked.is_none(),
+                            "Tx {} high-s authority {} should be rejected",
+                            test.name,
+                            i
+                        );
+                    } else {
+                        assert_eq!(
+                            checked,
+                            Some(authority),
+                            "Tx {} authority {} mismatch",
+                            test.name,
+                            i
+                        );
+                    }
+                }
+                None => {
+                    assert!(
+                        unchecked.is_none(),
+                        "Tx {} authority {} recovery should fail",
+                        test.name,
+                        i
+                    );
+                }
+            }
+        }
+    }
+
+    println!("All signed transaction tests passed!");
+}
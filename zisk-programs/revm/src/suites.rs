@@ -0,0 +1,113 @@
+use guest_reth::CustomEvmCrypto;
+
+use crate::blake2f::blake2f_tests;
+use crate::bls12_381::{
+    bls12_381_g1_add_tests, bls12_381_g1_msm_tests, bls12_381_g1_mul_tests, bls12_381_g2_add_tests,
+    bls12_381_g2_msm_tests, bls12_381_g2_mul_tests, bls12_381_map_fp2_to_g2_tests,
+    bls12_381_map_fp_to_g1_tests, bls12_381_pairing_tests, bls12_381_point_evaluation_tests,
+};
+use crate::bn254::{ecadd_tests, ecmul_tests, ecpairing_tests};
+use crate::identity::identity_tests;
+use crate::input::SuiteSelection;
+use crate::keccak256::keccak256_tests;
+use crate::modexp::modexp_tests;
+use crate::ripemd160::ripemd160_tests;
+use crate::secp256k1::{ecrecover_precompile_tests, ecrecover_tx_tests};
+use crate::secp256r1::p256_verify_tests;
+use crate::sha256::sha256_tests;
+use crate::signed_tx::signed_tx_tests;
+use crate::u256::{add_tests, div_tests, modular_tests, mul_tests, pow_tests};
+
+/// Whether a suite can run when the guest is executed with hints
+#[derive(Clone, Copy, PartialEq)]
+pub enum HintSupport {
+    Supported,
+    NotImplemented,
+    /// Runs with hints, splitting or skipping the vectors above the suite's per-call hint budget
+    Bounded,
+}
+
+pub struct Suite {
+    pub name: &'static str,
+    pub run: fn(&CustomEvmCrypto),
+    pub hints: HintSupport,
+}
+
+/// Every suite of the guest, in execution order
+pub const SUITES: &[Suite] = &[
+    // U256
+    Suite { name: "u256_add", run: |_| add_tests(), hints: HintSupport::NotImplemented },
+    Suite { name: "u256_div", run: |_| div_tests(), hints: HintSupport::NotImplemented },
+    Suite { name: "u256_modular", run: |_| modular_tests(), hints: HintSupport::NotImplemented },
+    Suite { name: "u256_mul", run: |_| mul_tests(), hints: HintSupport::NotImplemented },
+    Suite { name: "u256_pow", run: |_| pow_tests(), hints: HintSupport::NotImplemented },
+    // Hashes
+    Suite { name: "blake2f", run: blake2f_tests, hints: HintSupport::Supported },
+    Suite { name: "sha256", run: sha256_tests, hints: HintSupport::Supported },
+    Suite { name: "keccak256", run: |_| keccak256_tests(), hints: HintSupport::Supported },
+    Suite { name: "ripemd160", run: ripemd160_tests, hints: HintSupport::Supported },
+    // Identity
+    Suite { name: "identity", run: |_| identity_tests(), hints: HintSupport::Supported },
+    // Modular exponentiation
+    Suite { name: "modexp", run: modexp_tests, hints: HintSupport::Supported },
+    // Secp256k1
+    Suite { name: "ecrecover_tx", run: ecrecover_tx_tests, hints: HintSupport::Supported },
+    Suite {
+        name: "ecrecover_precompile",
+        run: ecrecover_precompile_tests,
+        hints: HintSupport::Supported,
+    },
+    Suite { name: "signed_tx", run: signed_tx_tests, hints: HintSupport::Supported },
+    // Secp256r1
+    Suite { name: "p256_verify", run: p256_verify_tests, hints: HintSupport::Supported },
+    // BN254
+    Suite { name: "bn254_add", run: ecadd_tests, hints: HintSupport::Supported },
+    Suite { name: "bn254_mul", run: ecmul_tests, hints: HintSupport::Supported },
+    Suite { name: "bn254_pairing", run: ecpairing_tests, hints: HintSupport::Bounded },
+    // BLS12-381
+    Suite { name: "bls12_381_g1_add", run: bls12_381_g1_add_tests, hints: HintSupport::Supported },
+    Suite { name: "bls12_381_g1_mul", run: bls12_381_g1_mul_tests, hints: HintSupport::Supported },
+    Suite { name: "bls12_381_g1_msm", run: bls12_381_g1_msm_tests, hints: HintSupport::Bounded },
+    Suite { name: "bls12_381_g2_add", run: bls12_381_g2_add_tests, hints: HintSupport::Supported },
+    Suite { name: "bls12_381_g2_mul", run: bls12_381_g2_mul_tests, hints: HintSupport::Supported },
+    Suite { name: "bls12_381_g2_msm", run: bls12_381_g2_msm_tests, hints: HintSupport::Bounded },
+    Suite {
+        name: "bls12_381_map_fp_to_g1",
+        run: bls12_381_map_fp_to_g1_tests,
+        hints: HintSupport::Supported,
+    },
+    Suite {
+        name: "bls12_381_map_fp2_to_g2",
+        run: bls12_381_map_fp2_to_g2_tests,
+        hints: HintSupport::Supported,
+    },
+    Suite { name: "bls12_381_pairing", run: bls12_381_pairing_tests, hints: HintSupport::Bounded },
+    Suite {
+        name: "bls12_381_point_evaluation",
+        run: bls12_381_point_evaluation_tests,
+        hints: HintSupport::Supported,
+    },
+];
+
+/// Resolves the suites to run from the input descriptor, keeping the execution order of `SUITES`
+pub fn select_suites(selection: &SuiteSelection) -> Vec<&'static Suite> {
+    for name in &selection.suites {
+        assert!(
+            SUITES.iter().any(|s| s.name == name),
+            "Unknown suite {name}, expected one of: {}",
+            SUITES.iter().map(|s| s.name).collect::<Vec<_>>().join(", ")
+        );
+    }
+
+    SUITES
+        .iter()
+        .filter(|s| selection.suites.is_empty() || selection.suites.iter().any(|n| n == s.name))
+        .filter(|s| {
+            let skip = selection.hints_compatible_only && s.hints == HintSupport::NotImplemented;
+            if skip {
+                println!("Skipping {} suite: does not work with hints [not implemented]", s.name);
+            }
+            !skip
+        })
+        .collect()
+}
@@ -1,23 +1,4 @@
-#[cfg(feature = "profile")]
-macro_rules! profile_block {
-    ($tag:ident, $body:block) => {{
-        ziskos::profile_report_start!($tag);
-        ziskos::profile_report_steps_start!($tag);
-        let __profile_result = $body;
-        ziskos::profile_report_steps_end!($tag);
-        ziskos::profile_report_end!($tag);
-        __profile_result
-    }};
-}
-
-#[cfg(not(feature = "profile"))]
-macro_rules! profile_block {
-    ($tag:ident, $body:block) => {
-        $body
-    };
-}
-
-pub(crate) use profile_block;
+pub(crate) use crate::profile::profile_block;
 
 pub(crate) const ZERO: [u64; 4] = [0, 0, 0, 0];
 
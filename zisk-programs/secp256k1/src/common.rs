@@ -0,0 +1,35 @@
+use ziskos::zisklib::{add_fp_secp256k1, mul_fp_secp256k1, sqrt_fp_secp256k1, square_fp_secp256k1};
+
+use crate::constants::{N, P};
+
+/// Little-endian limbs of a 32-byte big-endian integer
+pub fn limbs(bytes: &[u8]) -> [u64; 4] {
+    let mut limbs = [0; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+pub fn lt(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+/// Whether x is in [1, n - 1]
+pub fn is_scalar(x: &[u64; 4]) -> bool {
+    *x != [0; 4] && lt(x, &N)
+}
+
+pub fn hex_to_vec(hex: &str) -> Vec<u8> {
+    hex::decode(hex).expect("valid hex")
+}
+
+/// Point (x, y) of the curve with y of the given parity, if x < p and x³ + 7 is a square
+pub fn lift_x(x: &[u64; 4], parity: u64) -> Option<[u64; 8]> {
+    if !lt(x, &P) {
+        return None;
+    }
+    let y2 = add_fp_secp256k1(&mul_fp_secp256k1(&square_fp_secp256k1(x), x), &[7, 0, 0, 0]);
+    let (y, is_quadratic) = sqrt_fp_secp256k1(&y2, parity);
+    is_quadratic.then_some([x[0], x[1], x[2], x[3], y[0], y[1], y[2], y[3]])
+}
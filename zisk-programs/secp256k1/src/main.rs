@@ -1,16 +1,23 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
+mod common;
 mod constants;
 mod curve;
 mod ecdsa;
 mod fp;
+mod recovery;
 mod scalar;
+mod schnorr;
+mod wycheproof;
 
 use curve::curve_tests;
 use ecdsa::ecdsa_tests;
 use fp::fp_tests;
+use recovery::recovery_tests;
 use scalar::scalar_tests;
+use schnorr::schnorr_tests;
+use wycheproof::wycheproof_tests;
 
 fn main() {
     // Fp
@@ -24,4 +31,13 @@ fn main() {
 
     // ECDSA
     ecdsa_tests();
+
+    // ECDSA, Wycheproof vectors
+    wycheproof_tests();
+
+    // ECDSA public key recovery
+    recovery_tests();
+
+    // BIP-340 Schnorr
+    schnorr_tests();
 }
@@ -0,0 +1,72 @@
+use serde::Deserialize;
+use ziskos::zisklib::{
+    double_scalar_mul_with_g_secp256k1, inv_fn_secp256k1, mul_fn_secp256k1, neg_fn_secp256k1,
+    reduce_fn_secp256k1,
+};
+
+use crate::common::{hex_to_vec, is_scalar, lift_x, limbs};
+use crate::constants::N;
+
+// ECDSA public key recovery vectors. Each test has a 32-byte message hash, a signature r || s, a
+// recovery id and the uncompressed public key it recovers, or null if recovery must fail
+
+#[derive(Deserialize)]
+struct TestFile {
+    tests: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+struct TestCase {
+    comment: String,
+    hash: String,
+    sig: String,
+    recid: u8,
+    public_key: Option<String>,
+}
+
+pub fn recovery_tests() {
+    let file: TestFile =
+        serde_json::from_str(include_str!("testdata/ecdsa_recovery.json")).expect("valid JSON");
+
+    for test in &file.tests {
+        let sig = hex_to_vec(&test.sig);
+        let z = limbs(&hex_to_vec(&test.hash));
+        let res = recover(&z, &limbs(&sig[..32]), &limbs(&sig[32..]), test.recid);
+
+        let res_exp = test.public_key.as_ref().map(|pk| {
+            let pk = hex_to_vec(pk);
+            let (x, y) = (limbs(&pk[1..33]), limbs(&pk[33..]));
+            [x[0], x[1], x[2], x[3], y[0], y[1], y[2], y[3]]
+        });
+        assert_eq!(res, res_exp, "{}", test.comment);
+    }
+}
+
+/// Public key Q = r⁻¹·(s·R - z·G) of the signature (r, s) of the hash z, where R is the nonce
+/// point given by the recovery id: bit 0 is the parity of its y-coordinate, and bit 1 whether its
+/// x-coordinate is r + n instead of r, which can only happen while r + n < p
+fn recover(z: &[u64; 4], r: &[u64; 4], s: &[u64; 4], recid: u8) -> Option<[u64; 8]> {
+    if recid > 3 || !is_scalar(r) || !is_scalar(s) {
+        return None;
+    }
+
+    let x = if recid & 2 == 0 { *r } else { add_n(r)? };
+    let nonce_point = lift_x(&x, (recid & 1) as u64)?;
+
+    let r_inv = inv_fn_secp256k1(r);
+    let u1 = neg_fn_secp256k1(&mul_fn_secp256k1(&reduce_fn_secp256k1(z), &r_inv));
+    let u2 = mul_fn_secp256k1(s, &r_inv);
+    double_scalar_mul_with_g_secp256k1(&u1, &u2, &nonce_point)
+}
+
+/// r + n, unless it overflows 256 bits
+fn add_n(r: &[u64; 4]) -> Option<[u64; 4]> {
+    let mut res = [0; 4];
+    let mut carry = 0;
+    for ((res, r), n) in res.iter_mut().zip(r).zip(N) {
+        let sum = *r as u128 + n as u128 + carry;
+        *res = sum as u64;
+        carry = sum >> 64;
+    }
+    (carry == 0).then_some(res)
+}
@@ -0,0 +1,62 @@
+use sha2::{Digest, Sha256};
+use ziskos::zisklib::{double_scalar_mul_with_g_secp256k1, neg_fn_secp256k1, reduce_fn_secp256k1};
+
+use crate::common::{hex_to_vec, lift_x, limbs, lt};
+use crate::constants::{N, P};
+
+// BIP-340 test vectors, in the layout of the BIP's test-vectors.csv: index, secret key, public
+// key, aux_rand, message, signature, verification result, comment. Only verification is checked
+
+pub fn schnorr_tests() {
+    let csv = include_str!("testdata/bip340_test_vectors.csv");
+
+    for line in csv.lines().skip(1) {
+        let fields: Vec<&str> = line.splitn(8, ',').collect();
+        let [index, _, public_key, _, message, signature, result, comment] = fields[..] else {
+            panic!("Malformed test vector: {line}");
+        };
+
+        let res_exp = match result {
+            "TRUE" => true,
+            "FALSE" => false,
+            result => panic!("Vector {index}: unknown result {result}"),
+        };
+        let res = verify(&hex_to_vec(public_key), &hex_to_vec(message), &hex_to_vec(signature));
+        assert_eq!(res, res_exp, "Vector {index} ({comment})");
+    }
+}
+
+/// BIP-340 verification of a signature r || s of msg under the x-only public key pk:
+/// R = s·G - e·P must have an even y-coordinate and x-coordinate r, where
+/// e = hash_challenge(r || pk || msg) mod n and P is the point of x-coordinate pk with even y
+fn verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> bool {
+    if pk.len() != 32 || sig.len() != 64 {
+        return false;
+    }
+    let Some(p) = lift_x(&limbs(pk), 0) else {
+        return false;
+    };
+    let (r, s) = (limbs(&sig[..32]), limbs(&sig[32..]));
+    if !lt(&r, &P) || !lt(&s, &N) {
+        return false;
+    }
+
+    let e = reduce_fn_secp256k1(&limbs(&tagged_hash("BIP0340/challenge", &[&sig[..32], pk, msg])));
+    let Some(point) = double_scalar_mul_with_g_secp256k1(&s, &neg_fn_secp256k1(&e), &p) else {
+        // R is the point at infinity
+        return false;
+    };
+    point[4] & 1 == 0 && point[..4] == r
+}
+
+/// SHA-256(SHA-256(tag) || SHA-256(tag) || data)
+fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag);
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for chunk in data {
+        hasher.update(chunk);
+    }
+    hasher.finalize().into()
+}
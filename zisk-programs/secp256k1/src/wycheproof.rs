@@ -0,0 +1,114 @@
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use ziskos::zisklib::{ecdsa_verify_secp256k1, is_on_curve_secp256k1};
+
+use crate::common::{hex_to_vec, is_scalar, limbs, lt};
+use crate::constants::P;
+
+// ECDSA verification vectors in the Wycheproof schema (EcdsaP1363Verify, SHA-256). Each group has
+// one public key, and each test a message, a signature r || s and the expected verdict. The
+// upstream Wycheproof ecdsa_secp256k1_sha256_p1363_test.json is not in testdata yet; it goes in
+// this list as is, ahead of our own cases
+const FIXTURES: [(&str, &str); 1] = [
+    // Cases of our own, generated locally
+    (
+        "ecdsa_secp256k1_sha256_p1363_extra.json",
+        include_str!("testdata/ecdsa_secp256k1_sha256_p1363_extra.json"),
+    ),
+];
+
+#[derive(Deserialize)]
+struct TestFile {
+    #[serde(rename = "numberOfTests")]
+    number_of_tests: usize,
+    #[serde(rename = "testGroups")]
+    test_groups: Vec<TestGroup>,
+}
+
+#[derive(Deserialize)]
+struct TestGroup {
+    #[serde(rename = "publicKey")]
+    public_key: PublicKey,
+    sha: String,
+    tests: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+struct PublicKey {
+    uncompressed: String,
+}
+
+#[derive(Deserialize)]
+struct TestCase {
+    #[serde(rename = "tcId")]
+    tc_id: u64,
+    comment: String,
+    flags: Vec<String>,
+    msg: String,
+    sig: String,
+    result: String,
+}
+
+pub fn wycheproof_tests() {
+    for (name, json) in FIXTURES {
+        let file: TestFile = serde_json::from_str(json).expect("valid JSON");
+
+        let count: usize = file.test_groups.iter().map(|g| g.tests.len()).sum();
+        assert_eq!(
+            count, file.number_of_tests,
+            "{name}: numberOfTests does not match the test cases"
+        );
+
+        for group in &file.test_groups {
+            assert_eq!(group.sha, "SHA-256", "{name}: unsupported hash function {}", group.sha);
+            let pk = parse_public_key(&hex_to_vec(&group.public_key.uncompressed));
+
+            for test in &group.tests {
+                let res = verify(pk.as_ref(), &hex_to_vec(&test.msg), &hex_to_vec(&test.sig));
+                let case = format!(
+                    "{name} tcId {} ({}, flags {:?})",
+                    test.tc_id, test.comment, test.flags
+                );
+                match test.result.as_str() {
+                    "valid" => assert!(res, "{case} should verify"),
+                    "invalid" => assert!(!res, "{case} should not verify"),
+                    // Either verdict is allowed
+                    "acceptable" => {}
+                    result => panic!("{case}: unknown result {result}"),
+                }
+            }
+        }
+    }
+}
+
+/// Public key from its SEC1 uncompressed encoding 0x04 || x || y, if it is a point of the curve
+fn parse_public_key(bytes: &[u8]) -> Option<[u64; 8]> {
+    if bytes.len() != 65 || bytes[0] != 0x04 {
+        return None;
+    }
+    let (x, y) = (limbs(&bytes[1..33]), limbs(&bytes[33..]));
+    if !lt(&x, &P) || !lt(&y, &P) {
+        return None;
+    }
+
+    let pk = [x[0], x[1], x[2], x[3], y[0], y[1], y[2], y[3]];
+    is_on_curve_secp256k1(&pk).then_some(pk)
+}
+
+/// ECDSA verification of a signature r || s, with r and s checked to be in [1, n - 1] before
+/// calling `ecdsa_verify_secp256k1`
+fn verify(pk: Option<&[u64; 8]>, msg: &[u8], sig: &[u8]) -> bool {
+    let Some(pk) = pk else {
+        return false;
+    };
+    if sig.len() != 64 {
+        return false;
+    }
+    let (r, s) = (limbs(&sig[..32]), limbs(&sig[32..]));
+    if !is_scalar(&r) || !is_scalar(&s) {
+        return false;
+    }
+
+    let z = limbs(&Sha256::digest(msg));
+    ecdsa_verify_secp256k1(pk, &z, &r, &s)
+}
@@ -1,7 +1,6 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
-mod constants;
 mod ecdsa;
 
 use ecdsa::ecdsa_tests;
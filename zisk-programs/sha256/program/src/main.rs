@@ -1,39 +1,29 @@
 #![no_main]
 ziskos::entrypoint!(main);
 
+mod sha256;
+
 use ziskos::syscalls::{syscall_sha256_f, SyscallSha256Params};
 
 #[allow(deprecated)]
 use generic_array::{typenum::U64, GenericArray};
+use hash_input::{HashInput, Iteration};
+use rand::rngs::StdRng;
 use rand::Rng;
 use sha2::compress256;
 
-const ACTIVATE_CONSISTENCY_TEST: bool = false;
+use crate::sha256::sha256_apply;
 
 fn main() {
-    // Get the input from ziskos
-    let num_sha256fs: u64 = ziskos::io::read();
-
-    let mut rng = rand::thread_rng();
+    // Get the mode, the PRNG seed and the iterations to run from ziskos
+    let input: HashInput = ziskos::io::read();
 
-    if ACTIVATE_CONSISTENCY_TEST {
-        println!("Running SHA256F consistency test for {} times", num_sha256fs);
-    } else {
-        println!("Running SHA256F random tests for {} times", num_sha256fs);
-    }
-
-    for _ in 0..num_sha256fs {
-        if ACTIVATE_CONSISTENCY_TEST {
-            run_consistency_test();
-        } else {
-            sha256f_apply(&mut rng);
-        }
-    }
+    input.run("SHA256F", run_consistency_test, sha256f_apply, Some(sha256_apply));
 }
 
 // Take any number and apply the sha256f function
 #[allow(deprecated)]
-fn sha256f_apply(rng: &mut rand::rngs::ThreadRng) {
+fn sha256f_apply(rng: &mut StdRng, iteration: &Iteration) {
     let mut state_u32 = [0u32; 8];
     for i in 0..8 {
         state_u32[i] = rng.gen();
@@ -51,6 +41,11 @@ fn sha256f_apply(rng: &mut rand::rngs::ThreadRng) {
     let mut params = SyscallSha256Params { state, input };
     syscall_sha256_f(&mut params);
 
+    // Benchmark runs only measure the syscall
+    if !iteration.check {
+        return;
+    }
+
     // Compare against an audited sha256f implementation
     let input_ga: GenericArray<u8, U64> = GenericArray::clone_from_slice(&input_u8);
     compress256(&mut state_u32_copy, &[input_ga]);
@@ -58,7 +53,9 @@ fn sha256f_apply(rng: &mut rand::rngs::ThreadRng) {
 
     assert!(
         state == expected_result,
-        "SHA256F state mismatch: \n  expected: {:x?}\n     found: {:x?}",
+        "SHA256F state mismatch at iteration {} of seed {}: \n  expected: {:x?}\n     found: {:x?}",
+        iteration.index,
+        iteration.seed,
         expected_result,
         state
     );
@@ -93,4 +90,6 @@ fn run_consistency_test() {
         expected_result,
         state
     );
+
+    sha256::run_consistency_test();
 }
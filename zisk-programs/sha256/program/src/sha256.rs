@@ -0,0 +1,75 @@
+use hash_input::{Iteration, Message};
+use rand::rngs::StdRng;
+use sha2::{Digest, Sha256};
+
+unsafe extern "C" {
+    // This gets linked to the ziskos sha256 implementation
+    fn sha256_c(bytes: *const u8, len: usize, output: *mut u8);
+}
+
+fn sha256(bytes: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    unsafe { sha256_c(bytes.as_ptr(), bytes.len(), output.as_mut_ptr()) };
+    output
+}
+
+// Hash a random message at a random offset and compare against sha2
+pub fn sha256_apply(rng: &mut StdRng, iteration: &Iteration) {
+    let message = Message::random(rng);
+    let hash = sha256(message.bytes());
+    let expected: [u8; 32] = Sha256::digest(message.bytes()).into();
+
+    assert!(
+        hash == expected,
+        "SHA256 mismatch on {} bytes at offset {} at iteration {} of seed {}: \n  expected: {}\n     found: {}",
+        message.bytes().len(),
+        message.offset(),
+        iteration.index,
+        iteration.seed,
+        hex::encode(expected),
+        hex::encode(hash)
+    );
+}
+
+pub fn run_consistency_test() {
+    // FIPS 180-4 short and long messages
+    const VECTORS: [(&[u8], &str); 4] = [
+        (b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+        (b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+        (
+            b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1",
+        ),
+    ];
+    for (message, expected) in VECTORS {
+        let hash = sha256(message);
+        assert!(
+            hex::encode(hash) == expected,
+            "SHA256 mismatch on {:?}: \n  expected: {}\n     found: {}",
+            String::from_utf8_lossy(message),
+            expected,
+            hex::encode(hash)
+        );
+    }
+
+    // Lengths around the block size, where the padding spills into another block, read at every
+    // alignment
+    let buffer: Vec<u8> = (0..200).map(|i| i as u8).collect();
+    for len in [55, 56, 63, 64, 65, 119, 120, 128, 183, 184] {
+        for offset in 0..8 {
+            let message = &buffer[offset..offset + len];
+            let hash = sha256(message);
+            let expected: [u8; 32] = Sha256::digest(message).into();
+            assert!(
+                hash == expected,
+                "SHA256 mismatch on {len} bytes at offset {offset}: \n  expected: {}\n     found: {}",
+                hex::encode(expected),
+                hex::encode(hash)
+            );
+        }
+    }
+}
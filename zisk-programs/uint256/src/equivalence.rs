@@ -0,0 +1,167 @@
+use std::fmt::Debug;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use ziskos::zisklib::{
+    add_mod256, checked_add256, checked_div256, checked_mul256, checked_neg256, checked_pow256,
+    checked_rem256, checked_square256, checked_sub256, div_ceil256, div_rem256, inv256, inv_mod256,
+    mul_mod256, overflowing_add256, overflowing_mul256, overflowing_neg256, overflowing_pow256,
+    overflowing_square256, overflowing_sub256, pow_mod256, reduce_mod256, saturating_add256,
+    saturating_mul256, saturating_pow256, saturating_square256, saturating_sub256, square_mod256,
+    wrapping_add256, wrapping_div256, wrapping_mul256, wrapping_neg256, wrapping_pow256,
+    wrapping_rem256, wrapping_square256, wrapping_sub256,
+};
+
+type RU256 = ruint::Uint<256, 4>;
+
+/// Random operand biased towards the boundaries: 0, 1, MAX, 2^k, 2^k ± 1, or a random value of
+/// random bit length
+fn random_operand(rng: &mut StdRng) -> RU256 {
+    let k: usize = rng.gen_range(0..256);
+    match rng.gen_range(0..8) {
+        0 => RU256::ZERO,
+        1 => RU256::from(1u64),
+        2 => RU256::MAX,
+        3 => RU256::from(1u64) << k,
+        4 => (RU256::from(1u64) << k).wrapping_add(RU256::from(1u64)),
+        5 => (RU256::from(1u64) << k).wrapping_sub(RU256::from(1u64)),
+        _ => RU256::from_limbs(rng.gen()) >> k,
+    }
+}
+
+/// Fails on the first zisklib function whose result differs from ruint
+fn check<T: PartialEq + Debug>(name: &str, i: u64, operands: &[&RU256], zisk: T, ruint: T) {
+    assert!(
+        zisk == ruint,
+        "{name} differs from ruint at iteration {i} on {operands:?}\n  zisklib: {zisk:x?}\n    ruint: {ruint:x?}"
+    );
+}
+
+fn limbs(v: RU256) -> [u64; 4] {
+    *v.as_limbs()
+}
+
+fn limbs_flag((v, flag): (RU256, bool)) -> ([u64; 4], bool) {
+    (limbs(v), flag)
+}
+
+pub fn equivalence_tests(rng: &mut StdRng, iterations: u64) {
+    for i in 0..iterations {
+        let (a, b, m) = (random_operand(rng), random_operand(rng), random_operand(rng));
+        add_equivalence(i, &a, &b);
+        mul_equivalence(i, &a, &b);
+        div_equivalence(i, &a, &b);
+        pow_equivalence(i, &a, &b);
+        modular_equivalence(i, &a, &b, &m);
+    }
+    println!("  - ruint equivalence tests passed.");
+}
+
+fn add_equivalence(i: u64, a: &RU256, b: &RU256) {
+    let (al, bl) = (a.as_limbs(), b.as_limbs());
+    let ab = &[a, b];
+
+    let expected = a.checked_add(*b).map(limbs);
+    check("checked_add256", i, ab, checked_add256(al, bl), expected);
+    let expected = a.checked_sub(*b).map(limbs);
+    check("checked_sub256", i, ab, checked_sub256(al, bl), expected);
+    check("checked_neg256", i, &[a], checked_neg256(al), a.checked_neg().map(limbs));
+
+    let expected = limbs_flag(a.overflowing_add(*b));
+    check("overflowing_add256", i, ab, overflowing_add256(al, bl), expected);
+    let expected = limbs_flag(a.overflowing_sub(*b));
+    check("overflowing_sub256", i, ab, overflowing_sub256(al, bl), expected);
+    let expected = limbs_flag(a.overflowing_neg());
+    check("overflowing_neg256", i, &[a], overflowing_neg256(al), expected);
+
+    let expected = limbs(a.saturating_add(*b));
+    check("saturating_add256", i, ab, saturating_add256(al, bl), expected);
+    let expected = limbs(a.saturating_sub(*b));
+    check("saturating_sub256", i, ab, saturating_sub256(al, bl), expected);
+
+    let expected = limbs(a.wrapping_add(*b));
+    check("wrapping_add256", i, ab, wrapping_add256(al, bl), expected);
+    let expected = limbs(a.wrapping_sub(*b));
+    check("wrapping_sub256", i, ab, wrapping_sub256(al, bl), expected);
+    check("wrapping_neg256", i, &[a], wrapping_neg256(al), limbs(a.wrapping_neg()));
+}
+
+fn mul_equivalence(i: u64, a: &RU256, b: &RU256) {
+    let (al, bl) = (a.as_limbs(), b.as_limbs());
+    let ab = &[a, b];
+
+    let expected = a.checked_mul(*b).map(limbs);
+    check("checked_mul256", i, ab, checked_mul256(al, bl), expected);
+    let expected = a.checked_mul(*a).map(limbs);
+    check("checked_square256", i, &[a], checked_square256(al), expected);
+
+    let expected = limbs_flag(a.overflowing_mul(*b));
+    check("overflowing_mul256", i, ab, overflowing_mul256(al, bl), expected);
+    let expected = limbs_flag(a.overflowing_mul(*a));
+    check("overflowing_square256", i, &[a], overflowing_square256(al), expected);
+
+    let expected = limbs(a.saturating_mul(*b));
+    check("saturating_mul256", i, ab, saturating_mul256(al, bl), expected);
+    let expected = limbs(a.saturating_mul(*a));
+    check("saturating_square256", i, &[a], saturating_square256(al), expected);
+
+    let expected = limbs(a.wrapping_mul(*b));
+    check("wrapping_mul256", i, ab, wrapping_mul256(al, bl), expected);
+    let expected = limbs(a.wrapping_mul(*a));
+    check("wrapping_square256", i, &[a], wrapping_square256(al), expected);
+
+    check("inv256", i, &[a], inv256(al), a.inv_ring().map(limbs));
+}
+
+fn div_equivalence(i: u64, a: &RU256, b: &RU256) {
+    let (al, bl) = (a.as_limbs(), b.as_limbs());
+    let ab = &[a, b];
+
+    let expected = a.checked_div(*b).map(limbs);
+    check("checked_div256", i, ab, checked_div256(al, bl), expected);
+    let expected = a.checked_rem(*b).map(limbs);
+    check("checked_rem256", i, ab, checked_rem256(al, bl), expected);
+
+    // ruint panics on a zero divisor, which the fixed tests cover
+    if b.is_zero() {
+        return;
+    }
+    let (q, r) = a.div_rem(*b);
+    check("div_rem256", i, ab, div_rem256(al, bl), (limbs(q), limbs(r)));
+    check("div_ceil256", i, ab, div_ceil256(al, bl), limbs(a.div_ceil(*b)));
+    check("wrapping_div256", i, ab, wrapping_div256(al, bl), limbs(a.wrapping_div(*b)));
+    check("wrapping_rem256", i, ab, wrapping_rem256(al, bl), limbs(a.wrapping_rem(*b)));
+}
+
+fn pow_equivalence(i: u64, a: &RU256, b: &RU256) {
+    let (al, bl) = (a.as_limbs(), b.as_limbs());
+    let ab = &[a, b];
+
+    let expected = a.checked_pow(*b).map(limbs);
+    check("checked_pow256", i, ab, checked_pow256(al, bl), expected);
+    let expected = limbs_flag(a.overflowing_pow(*b));
+    check("overflowing_pow256", i, ab, overflowing_pow256(al, bl), expected);
+    let expected = limbs(a.saturating_pow(*b));
+    check("saturating_pow256", i, ab, saturating_pow256(al, bl), expected);
+    let expected = limbs(a.wrapping_pow(*b));
+    check("wrapping_pow256", i, ab, wrapping_pow256(al, bl), expected);
+}
+
+fn modular_equivalence(i: u64, a: &RU256, b: &RU256, m: &RU256) {
+    let (al, bl, ml) = (a.as_limbs(), b.as_limbs(), m.as_limbs());
+    let abm = &[a, b, m];
+
+    let expected = limbs(a.reduce_mod(*m));
+    check("reduce_mod256", i, &[a, m], reduce_mod256(al, ml), expected);
+    let expected = limbs(a.add_mod(*b, *m));
+    check("add_mod256", i, abm, add_mod256(al, bl, ml), expected);
+    let expected = limbs(a.mul_mod(*b, *m));
+    check("mul_mod256", i, abm, mul_mod256(al, bl, ml), expected);
+    let expected = limbs(a.mul_mod(*a, *m));
+    check("square_mod256", i, &[a, m], square_mod256(al, ml), expected);
+    let expected = limbs(a.pow_mod(*b, *m));
+    check("pow_mod256", i, abm, pow_mod256(al, bl, ml), expected);
+    let expected = a.inv_mod(*m).map(limbs);
+    check("inv_mod256", i, &[a, m], inv_mod256(al, ml), expected);
+}
@@ -5,20 +5,38 @@ mod constants;
 
 mod add;
 mod div;
+mod equivalence;
 mod modular;
 mod mul;
 mod pow;
+mod signed;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 use add::add_tests;
 use div::div_tests;
+use equivalence::equivalence_tests;
 use modular::modular_tests;
 use mul::mul_tests;
 use pow::pow_tests;
+use signed::signed_tests;
 
 fn main() {
+    // Get the PRNG seed and the number of random operand sets from ziskos
+    let (seed, iterations): (u64, u64) = ziskos::io::read();
+
     add_tests();
     div_tests();
     modular_tests();
     mul_tests();
     pow_tests();
+
+    // Two's complement SDIV, SMOD, SAR, SIGNEXTEND, SLT and SGT over the unsigned functions
+    signed_tests();
+
+    // Every function on boundary-biased operands, checked against ruint
+    println!("Running ruint equivalence tests with seed {seed} for {iterations} iterations");
+    let mut rng = StdRng::seed_from_u64(seed);
+    equivalence_tests(&mut rng, iterations);
 }
@@ -0,0 +1,297 @@
+use serde::Deserialize;
+use ziskos::zisklib::{
+    overflowing_sub256, wrapping_div256, wrapping_neg256, wrapping_pow256, wrapping_rem256,
+    wrapping_sub256,
+};
+
+use crate::constants::*;
+
+/// -2^255, the two's complement value without a positive counterpart
+const MIN: [u64; 4] = [0, 0, 0, 1 << 63];
+
+/// 2^255 - 1
+const SIGNED_MAX: [u64; 4] = [u64::MAX, u64::MAX, u64::MAX, u64::MAX >> 1];
+
+type SignedOp = fn(&[u64; 4], &[u64; 4]) -> [u64; 4];
+
+/// go-ethereum's instruction vectors (core/vm/testdata), in the EVM stack order: the operation
+/// takes `Y` as its first operand and `X` as its second
+const VECTORS: [(&str, SignedOp, &str); 6] = [
+    ("SDIV", sdiv256, include_str!("../../revm/src/testdata/testcases_sdiv.json")),
+    ("SMOD", smod256, include_str!("../../revm/src/testdata/testcases_smod.json")),
+    ("SAR", sar256, include_str!("../../revm/src/testdata/testcases_sar.json")),
+    ("SIGNEXTEND", signextend256, include_str!("../../revm/src/testdata/testcases_signext.json")),
+    (
+        "SLT",
+        |a, b| from_bool(slt256(a, b)),
+        include_str!("../../revm/src/testdata/testcases_slt.json"),
+    ),
+    (
+        "SGT",
+        |a, b| from_bool(sgt256(a, b)),
+        include_str!("../../revm/src/testdata/testcases_sgt.json"),
+    ),
+];
+
+#[derive(Deserialize)]
+struct TwoOperandTest {
+    #[serde(rename = "X")]
+    x: String,
+    #[serde(rename = "Y")]
+    y: String,
+    #[serde(rename = "Expected")]
+    expected: String,
+}
+
+fn is_negative(a: &[u64; 4]) -> bool {
+    a[3] >> 63 == 1
+}
+
+fn abs(a: &[u64; 4]) -> [u64; 4] {
+    if is_negative(a) {
+        wrapping_neg256(a)
+    } else {
+        *a
+    }
+}
+
+fn not(a: &[u64; 4]) -> [u64; 4] {
+    a.map(|limb| !limb)
+}
+
+/// 2^n, for n < 256
+fn pow2(n: u64) -> [u64; 4] {
+    wrapping_pow256(&TWO, &[n, 0, 0, 0])
+}
+
+fn from_bool(b: bool) -> [u64; 4] {
+    if b {
+        ONE
+    } else {
+        ZERO
+    }
+}
+
+/// The value of an operand that fits in a u64, if it does
+fn small(a: &[u64; 4]) -> Option<u64> {
+    (a[1..] == [0, 0, 0]).then_some(a[0])
+}
+
+/// SDIV: the quotient truncated towards zero, 0 for a zero divisor, MIN / -1 wrapping to MIN
+fn sdiv256(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    if *b == ZERO {
+        return ZERO;
+    }
+    let quotient = wrapping_div256(&abs(a), &abs(b));
+    if is_negative(a) != is_negative(b) {
+        wrapping_neg256(&quotient)
+    } else {
+        quotient
+    }
+}
+
+/// SMOD: the remainder with the sign of the dividend, 0 for a zero divisor
+fn smod256(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    if *b == ZERO {
+        return ZERO;
+    }
+    let remainder = wrapping_rem256(&abs(a), &abs(b));
+    if is_negative(a) {
+        wrapping_neg256(&remainder)
+    } else {
+        remainder
+    }
+}
+
+/// SAR: `value` shifted right by `shift` bits, filling with its sign bit. A negative value is
+/// floor-divided as the complement of its complement divided
+fn sar256(shift: &[u64; 4], value: &[u64; 4]) -> [u64; 4] {
+    let negative = is_negative(value);
+    match small(shift).filter(|&s| s < 256) {
+        Some(s) if negative => not(&wrapping_div256(&not(value), &pow2(s))),
+        Some(s) => wrapping_div256(value, &pow2(s)),
+        None if negative => MAX,
+        None => ZERO,
+    }
+}
+
+/// SIGNEXTEND: `value` sign-extended from its byte `byte` (0 being the least significant one),
+/// unchanged from byte 31 on
+fn signextend256(byte: &[u64; 4], value: &[u64; 4]) -> [u64; 4] {
+    let Some(byte) = small(byte).filter(|&b| b < 31) else {
+        return *value;
+    };
+    let bit = 8 * byte + 7;
+    let mask = wrapping_sub256(&pow2(bit + 1), &ONE);
+    let negative = (value[bit as usize / 64] >> (bit % 64)) & 1 == 1;
+    std::array::from_fn(|i| if negative { value[i] | !mask[i] } else { value[i] & mask[i] })
+}
+
+/// SLT: different signs are ordered by the sign, equal ones by the unsigned borrow of a - b
+fn slt256(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    if is_negative(a) != is_negative(b) {
+        is_negative(a)
+    } else {
+        overflowing_sub256(a, b).1
+    }
+}
+
+fn sgt256(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    slt256(b, a)
+}
+
+fn from_be_hex(hex: &str) -> [u64; 4] {
+    assert_eq!(hex.len(), 64, "expected a 32-byte word, got {hex}");
+    let mut limbs = [0; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let end = 64 - 16 * i;
+        *limb = u64::from_str_radix(&hex[end - 16..end], 16).expect("valid hex");
+    }
+    limbs
+}
+
+fn to_le_bytes(a: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(a) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+fn from_le_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+fn bit(a: &[u64; 4], i: usize) -> bool {
+    (a[i / 64] >> (i % 64)) & 1 == 1
+}
+
+pub fn signed_tests() {
+    let neg = |v: u64| wrapping_neg256(&[v, 0, 0, 0]);
+    let pos = |v: u64| [v, 0, 0, 0];
+
+    // ── sdiv256 ───────────────────────────────────────────────────────────────
+    // MIN / -1 overflows back to MIN
+    assert_eq!(sdiv256(&MIN, &MAX), MIN);
+    assert_eq!(sdiv256(&MIN, &ONE), MIN);
+    assert_eq!(sdiv256(&MIN, &MIN), ONE);
+    assert_eq!(sdiv256(&SIGNED_MAX, &MIN), ZERO);
+    assert_eq!(sdiv256(&MIN, &SIGNED_MAX), MAX);
+    // x / 0 == 0, whatever the sign of x
+    assert_eq!(sdiv256(&MIN, &ZERO), ZERO);
+    assert_eq!(sdiv256(&SIGNED_MAX, &ZERO), ZERO);
+    // truncation towards zero
+    assert_eq!(sdiv256(&neg(7), &pos(2)), neg(3));
+    assert_eq!(sdiv256(&pos(7), &neg(2)), neg(3));
+    assert_eq!(sdiv256(&neg(7), &neg(2)), pos(3));
+    // a zero quotient of operands of different signs is 0, not -0
+    assert_eq!(sdiv256(&ZERO, &MAX), ZERO);
+    assert_eq!(sdiv256(&MAX, &TWO), ZERO);
+    assert_eq!(sdiv256(&pos(1), &MIN), ZERO);
+
+    // ── smod256 ───────────────────────────────────────────────────────────────
+    // MIN % -1 == 0
+    assert_eq!(smod256(&MIN, &MAX), ZERO);
+    assert_eq!(smod256(&MIN, &MIN), ZERO);
+    assert_eq!(smod256(&MIN, &SIGNED_MAX), MAX);
+    assert_eq!(smod256(&SIGNED_MAX, &MIN), SIGNED_MAX);
+    // x % 0 == 0, whatever the sign of x
+    assert_eq!(smod256(&MIN, &ZERO), ZERO);
+    assert_eq!(smod256(&MAX, &ZERO), ZERO);
+    // the remainder takes the sign of the dividend
+    assert_eq!(smod256(&neg(7), &pos(3)), neg(1));
+    assert_eq!(smod256(&pos(7), &neg(3)), pos(1));
+    assert_eq!(smod256(&neg(7), &neg(3)), neg(1));
+    // a zero remainder of a negative dividend is 0, not -0
+    assert_eq!(smod256(&neg(6), &pos(3)), ZERO);
+    assert_eq!(smod256(&neg(6), &neg(3)), ZERO);
+
+    // ── sar256 ────────────────────────────────────────────────────────────────
+    assert_eq!(sar256(&ZERO, &MIN), MIN);
+    assert_eq!(sar256(&pos(255), &MIN), MAX);
+    assert_eq!(sar256(&pos(254), &MIN), neg(2));
+    assert_eq!(sar256(&pos(254), &SIGNED_MAX), ONE);
+    // -1 stays -1, and shifting a negative value never reaches -0
+    assert_eq!(sar256(&pos(100), &MAX), MAX);
+    assert_eq!(sar256(&pos(1), &neg(1)), neg(1));
+    assert_eq!(sar256(&pos(1), &neg(3)), neg(2));
+    // shifts of 256 bits and more, also above 64 bits, leave the sign only
+    for shift in [pos(256), pos(257), pos(u64::MAX), [0, 1, 0, 0], MIN, MAX] {
+        assert_eq!(sar256(&shift, &MIN), MAX);
+        assert_eq!(sar256(&shift, &MAX), MAX);
+        assert_eq!(sar256(&shift, &SIGNED_MAX), ZERO);
+        assert_eq!(sar256(&shift, &ONE), ZERO);
+    }
+    // every shift, checked bit by bit
+    let patterns = [
+        [0x0123456789abcdef_u64, 0xfedcba9876543210, 0x0f1e2d3c4b5a6978, 0x8796a5b4c3d2e1f0],
+        [0x0123456789abcdef_u64, 0xfedcba9876543210, 0x0f1e2d3c4b5a6978, 0x0796a5b4c3d2e1f0],
+    ];
+    for value in &patterns {
+        for shift in 0..256 {
+            let result = sar256(&pos(shift as u64), value);
+            for i in 0..256 {
+                let expected =
+                    if i + shift < 256 { bit(value, i + shift) } else { bit(value, 255) };
+                assert_eq!(bit(&result, i), expected, "SAR {shift} of {value:x?}, bit {i}");
+            }
+        }
+    }
+
+    // ── signextend256 ─────────────────────────────────────────────────────────
+    assert_eq!(signextend256(&ZERO, &pos(0x80)), neg(0x80));
+    assert_eq!(signextend256(&ZERO, &pos(0x7f)), pos(0x7f));
+    assert_eq!(signextend256(&ZERO, &pos(0xff)), MAX);
+    assert_eq!(signextend256(&pos(30), &MIN), ZERO);
+    assert_eq!(signextend256(&pos(30), &SIGNED_MAX), MAX);
+    // from byte 31 on, including indices above 64 bits, the value is left as is
+    for byte in [pos(31), pos(32), pos(u64::MAX), [0, 1, 0, 0], MAX] {
+        assert_eq!(signextend256(&byte, &MIN), MIN);
+        assert_eq!(signextend256(&byte, &SIGNED_MAX), SIGNED_MAX);
+    }
+    // every byte index, with a negative and a positive sign byte, against a byte-level extension
+    for byte in 0..32 {
+        for sign_byte in [0x80, 0xa5, 0x7f, 0x5a] {
+            let mut bytes = [0x3c; 32];
+            bytes[byte] = sign_byte;
+            let value = from_le_bytes(&bytes);
+            let fill = if sign_byte & 0x80 != 0 { 0xff } else { 0x00 };
+            let mut expected = bytes;
+            expected[byte + 1..].fill(fill);
+            let result = signextend256(&pos(byte as u64), &value);
+            assert_eq!(to_le_bytes(&result), expected, "SIGNEXTEND {byte} of {value:x?}");
+        }
+    }
+
+    // ── slt256 / sgt256 ───────────────────────────────────────────────────────
+    assert!(slt256(&MIN, &SIGNED_MAX));
+    assert!(slt256(&MIN, &MAX));
+    assert!(slt256(&MAX, &ZERO));
+    assert!(slt256(&neg(2), &MAX));
+    assert!(!slt256(&ZERO, &MAX));
+    assert!(!slt256(&MIN, &MIN));
+    assert!(sgt256(&SIGNED_MAX, &MIN));
+    assert!(sgt256(&ZERO, &MAX));
+    assert!(sgt256(&ONE, &ZERO));
+    assert!(!sgt256(&MAX, &MAX));
+    assert!(!sgt256(&MIN, &ONE));
+
+    // ── go-ethereum vectors ───────────────────────────────────────────────────
+    for (name, op, json) in VECTORS {
+        let tests: Vec<TwoOperandTest> = serde_json::from_str(json).expect("valid JSON");
+        for test in &tests {
+            let (x, y) = (from_be_hex(&test.x), from_be_hex(&test.y));
+            assert_eq!(
+                op(&y, &x),
+                from_be_hex(&test.expected),
+                "{name} mismatch for X = {}, Y = {}",
+                test.x,
+                test.y
+            );
+        }
+    }
+}